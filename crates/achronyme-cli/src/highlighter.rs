@@ -17,7 +17,7 @@ fn is_builtin_function(name: &str) -> bool {
         // Rounding
         "floor" | "ceil" | "round" | "abs" |
         // Higher-order functions
-        "map" | "reduce" | "filter" | "fold" |
+        "map" | "reduce" | "scan" | "filter" | "fold" |
         // Calculus
         "diff" | "integral" | "solve" | "derivative" |
         // Linear algebra
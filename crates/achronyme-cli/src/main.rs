@@ -361,6 +361,7 @@ fn format_value(value: &achronyme_types::value::Value) -> String {
 
     match value {
         Value::Number(n) => format!("{}", n),
+        Value::Integer(n) => format!("{}", n),
         Value::Boolean(b) => format!("{}", b),
         Value::String(s) => format!("\"{}\"", s),
         Value::Complex(c) => {
@@ -24,7 +24,7 @@ impl ReplHelper {
             // Rounding
             "floor", "ceil", "round", "abs",
             // Higher-order functions
-            "map", "reduce", "filter", "fold",
+            "map", "reduce", "scan", "filter", "fold",
             // Calculus
             "diff", "integral", "solve", "derivative",
             // Linear algebra
@@ -13,6 +13,7 @@ use crate::errors::{EnvError, Result};
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum SerializedValue {
     Number(f64),
+    Integer(i64),
     Boolean(bool),
     String(String),
     Complex(f64, f64),  // (re, im)
@@ -33,6 +34,7 @@ impl SerializedValue {
     pub fn from_value(value: &Value) -> Self {
         match value {
             Value::Number(n) => SerializedValue::Number(*n),
+            Value::Integer(n) => SerializedValue::Integer(*n),
             Value::Boolean(b) => SerializedValue::Boolean(*b),
             Value::String(s) => SerializedValue::String(s.clone()),
 
@@ -134,6 +136,7 @@ impl SerializedValue {
     pub fn to_value(&self) -> Result<Value> {
         match self {
             SerializedValue::Number(n) => Ok(Value::Number(*n)),
+            SerializedValue::Integer(n) => Ok(Value::Integer(*n)),
             SerializedValue::Boolean(b) => Ok(Value::Boolean(*b)),
             SerializedValue::String(s) => Ok(Value::String(s.clone())),
 
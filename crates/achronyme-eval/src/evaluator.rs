@@ -380,8 +380,10 @@ impl Evaluator {
                 match func_value {
                     Value::Function(ref func) => {
                         match func {
-                            achronyme_types::function::Function::UserDefined { .. } => {
-                                // User-defined lambda - evaluate args and apply
+                            achronyme_types::function::Function::UserDefined { .. }
+                            | achronyme_types::function::Function::Partial { .. } => {
+                                // User-defined lambda (or a curried partial application) -
+                                // evaluate args and apply
                                 let mut arg_values = Vec::new();
                                 for arg in args {
                                     arg_values.push(self.evaluate(arg)?);
@@ -398,6 +400,9 @@ impl Evaluator {
                     _ => Err("CallExpression requires a function, got non-function value".to_string()),
                 }
             }
+            AstNode::Pipe { left, right } => {
+                self.evaluate(&desugar_pipe(left, right))
+            }
             AstNode::Lambda { params, body } => {
                 handlers::functions::evaluate_lambda(self, params, body)
             }
@@ -584,6 +589,35 @@ impl LambdaEvaluator for Evaluator {
             _ => Err("Lambda function must return a number".to_string()),
         }
     }
+
+    fn eval_dual_at(&mut self, func: &Function, x: achronyme_types::dual::Dual) -> Result<achronyme_types::dual::Dual, String> {
+        let result = self.apply_lambda(func, vec![Value::Dual(x)])?;
+        match result {
+            Value::Dual(d) => Ok(d),
+            Value::Number(n) => Ok(achronyme_types::dual::Dual::constant(n)),
+            _ => Err("Lambda function must return a number for automatic differentiation".to_string()),
+        }
+    }
+
+    fn eval_ty_at(&mut self, func: &Function, t: f64, y: &[f64]) -> Result<Vec<f64>, String> {
+        let y_arg = if y.len() == 1 {
+            Value::Number(y[0])
+        } else {
+            Value::Vector(y.iter().map(|&n| Value::Number(n)).collect())
+        };
+        let result = self.apply_lambda(func, vec![Value::Number(t), y_arg])?;
+        match result {
+            Value::Number(n) => Ok(vec![n]),
+            Value::Vector(v) => v
+                .into_iter()
+                .map(|element| match element {
+                    Value::Number(n) => Ok(n),
+                    _ => Err("ODE function must return a vector of numbers".to_string()),
+                })
+                .collect(),
+            _ => Err("ODE function must return a number or vector".to_string()),
+        }
+    }
 }
 
 impl Default for Evaluator {
@@ -591,3 +625,26 @@ impl Default for Evaluator {
         Self::new()
     }
 }
+
+/// Rewrite `left |> right` into an ordinary call: `g(args...)` becomes
+/// `g(left, args...)`, and a bare callee becomes a one-argument call.
+fn desugar_pipe(left: &AstNode, right: &AstNode) -> AstNode {
+    match right {
+        AstNode::FunctionCall { name, args } => {
+            let mut piped_args = Vec::with_capacity(args.len() + 1);
+            piped_args.push(left.clone());
+            piped_args.extend_from_slice(args);
+            AstNode::FunctionCall { name: name.clone(), args: piped_args }
+        }
+        AstNode::CallExpression { callee, args } => {
+            let mut piped_args = Vec::with_capacity(args.len() + 1);
+            piped_args.push(left.clone());
+            piped_args.extend_from_slice(args);
+            AstNode::CallExpression { callee: callee.clone(), args: piped_args }
+        }
+        _ => AstNode::CallExpression {
+            callee: Box::new(right.clone()),
+            args: vec![left.clone()],
+        },
+    }
+}
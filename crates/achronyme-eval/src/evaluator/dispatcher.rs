@@ -44,6 +44,7 @@ impl Evaluator {
         match node {
             // Literals
             AstNode::Number(n) => handlers::literals::evaluate_number(*n),
+            AstNode::Integer(n) => handlers::literals::evaluate_integer(*n),
             AstNode::Boolean(b) => handlers::literals::evaluate_boolean(*b),
             AstNode::StringLiteral(s) => handlers::literals::evaluate_string(s),
             AstNode::ComplexLiteral { re, im } => handlers::literals::evaluate_complex(*re, *im),
@@ -134,6 +135,9 @@ impl Evaluator {
             AstNode::CallExpression { callee, args } => {
                 self.evaluate_call_expression(callee, args)
             }
+            AstNode::Pipe { left, right } => {
+                self.evaluate(&desugar_pipe(left, right))
+            }
             AstNode::Lambda { params, body, return_type } => {
                 handlers::functions::evaluate_lambda_with_return_type(self, params, return_type.clone(), body)
             }
@@ -316,8 +320,10 @@ impl Evaluator {
         match func_value {
             Value::Function(ref func) => {
                 match func {
-                    achronyme_types::function::Function::UserDefined { .. } => {
-                        // User-defined lambda - evaluate args and apply
+                    achronyme_types::function::Function::UserDefined { .. }
+                    | achronyme_types::function::Function::Partial { .. } => {
+                        // User-defined lambda (or a curried partial application) -
+                        // evaluate args and apply
                         let mut arg_values = Vec::new();
                         for arg in args {
                             arg_values.push(self.evaluate(arg)?);
@@ -469,3 +475,26 @@ impl Evaluator {
         Ok(Value::Boolean(true))
     }
 }
+
+/// Rewrite `left |> right` into an ordinary call: `g(args...)` becomes
+/// `g(left, args...)`, and a bare callee becomes a one-argument call.
+fn desugar_pipe(left: &AstNode, right: &AstNode) -> AstNode {
+    match right {
+        AstNode::FunctionCall { name, args } => {
+            let mut piped_args = Vec::with_capacity(args.len() + 1);
+            piped_args.push(left.clone());
+            piped_args.extend_from_slice(args);
+            AstNode::FunctionCall { name: name.clone(), args: piped_args }
+        }
+        AstNode::CallExpression { callee, args } => {
+            let mut piped_args = Vec::with_capacity(args.len() + 1);
+            piped_args.push(left.clone());
+            piped_args.extend_from_slice(args);
+            AstNode::CallExpression { callee: callee.clone(), args: piped_args }
+        }
+        _ => AstNode::CallExpression {
+            callee: Box::new(right.clone()),
+            args: vec![left.clone()],
+        },
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use achronyme_types::value::Value;
+
+use super::Evaluator;
+
+/// A host-registered native function: a boxed Rust closure plus its arity
+/// (-1 = variadic), mirroring the `(BuiltinFunction, i32)` pairs the module
+/// registry uses for ordinary built-ins. Boxed rather than stored as a bare
+/// `fn` pointer so embedders can capture state (e.g. a database handle) in
+/// the closure they register.
+type NativeFunction = (Rc<dyn Fn(&[Value]) -> Result<Value, String>>, i32);
+
+/// Host embedding methods for Evaluator
+///
+/// These let an embedding program pre-seed variables and register its own
+/// Rust-backed functions before evaluating an expression, without forking
+/// `FunctionRegistry`/`ModuleRegistry`. Native functions resolve and apply
+/// through the same `Function::Builtin` path as ordinary built-ins, so they
+/// participate in the same arity checking and can be passed to HOFs like
+/// `map`.
+impl Evaluator {
+    /// Pre-seed named variables into the evaluator's global scope.
+    ///
+    /// Builder-style: consumes and returns `self` so it can be chained onto
+    /// `Evaluator::new()` before evaluating an expression, e.g.
+    /// `Evaluator::new().with_variables(vars)`.
+    pub fn with_variables(mut self, variables: HashMap<String, Value>) -> Self {
+        for (name, value) in variables {
+            // Host-supplied bindings are infallible to define at this point:
+            // the environment is fresh/global, so the only failure mode
+            // `define` has (redefining an existing immutable binding) can't
+            // occur here.
+            let _ = self.env.define(name, value);
+        }
+        self
+    }
+
+    /// Register a Rust-backed function under `name` so expressions can call
+    /// it like any other built-in (`say_hello()`) or pass it to a
+    /// higher-order function (`map(my_native_fn, xs)`).
+    ///
+    /// `arity` follows the same convention as `FunctionRegistry::register`:
+    /// a non-negative count enforces exact arity, `-1` means variadic.
+    pub fn register_native_fn<F>(&mut self, name: &str, arity: i32, func: F)
+    where
+        F: Fn(&[Value]) -> Result<Value, String> + 'static,
+    {
+        self.native_functions.insert(name.to_string(), (Rc::new(func), arity));
+    }
+
+    /// Check whether `name` resolves to a host-registered native function.
+    pub(crate) fn has_native_function(&self, name: &str) -> bool {
+        self.native_functions.contains_key(name)
+    }
+
+    /// Look up a host-registered native function by name.
+    pub(crate) fn native_function(&self, name: &str) -> Option<NativeFunction> {
+        self.native_functions.get(name).cloned()
+    }
+}
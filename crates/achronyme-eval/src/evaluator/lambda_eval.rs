@@ -1,3 +1,4 @@
+use achronyme_types::dual::Dual;
 use achronyme_types::function::Function;
 use achronyme_types::value::Value;
 use achronyme_types::LambdaEvaluator;
@@ -36,6 +37,35 @@ impl LambdaEvaluator for Evaluator {
             _ => Err("Lambda function must return a number".to_string()),
         }
     }
+
+    fn eval_dual_at(&mut self, func: &Function, x: Dual) -> Result<Dual, String> {
+        let result = self.apply_lambda(func, vec![Value::Dual(x)])?;
+        match result {
+            Value::Dual(d) => Ok(d),
+            Value::Number(n) => Ok(Dual::constant(n)),
+            _ => Err("Lambda function must return a number for automatic differentiation".to_string()),
+        }
+    }
+
+    fn eval_ty_at(&mut self, func: &Function, t: f64, y: &[f64]) -> Result<Vec<f64>, String> {
+        let y_arg = if y.len() == 1 {
+            Value::Number(y[0])
+        } else {
+            Value::Vector(y.iter().map(|&n| Value::Number(n)).collect())
+        };
+        let result = self.apply_lambda(func, vec![Value::Number(t), y_arg])?;
+        match result {
+            Value::Number(n) => Ok(vec![n]),
+            Value::Vector(v) => v
+                .into_iter()
+                .map(|element| match element {
+                    Value::Number(n) => Ok(n),
+                    _ => Err("ODE function must return a vector of numbers".to_string()),
+                })
+                .collect(),
+            _ => Err("ODE function must return a number or vector".to_string()),
+        }
+    }
 }
 
 /// Lambda application methods
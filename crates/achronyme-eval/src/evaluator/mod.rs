@@ -33,6 +33,7 @@ mod state;
 mod modules;
 mod lambda_eval;
 mod dispatcher;
+mod host;
 
 /// Evaluator
 ///
@@ -61,6 +62,9 @@ pub struct Evaluator {
     /// Type registry for storing type aliases
     /// Format: alias_name -> type_definition
     pub(crate) type_registry: HashMap<String, TypeAnnotation>,
+    /// Host-registered native functions (embedder callbacks), keyed by name
+    /// Format: name -> (function, arity), see `host::NativeFunction`
+    pub(crate) native_functions: HashMap<String, (std::rc::Rc<dyn Fn(&[Value]) -> Result<Value, String>>, i32)>,
 }
 
 impl Evaluator {
@@ -77,6 +81,7 @@ impl Evaluator {
             current_file_dir: None,
             tco_mode: false,
             type_registry: HashMap::new(),
+            native_functions: HashMap::new(),
         }
     }
 
@@ -81,12 +81,14 @@ pub fn range(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
     // Extract start
     let start = match &args[0] {
         Value::Number(n) => *n,
+        Value::Integer(n) => *n as f64,
         _ => return Err("range() start must be a number".to_string()),
     };
 
     // Extract end
     let end = match &args[1] {
         Value::Number(n) => *n,
+        Value::Integer(n) => *n as f64,
         _ => return Err("range() end must be a number".to_string()),
     };
 
@@ -99,6 +101,12 @@ pub fn range(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
                 }
                 *n
             }
+            Value::Integer(n) => {
+                if *n == 0 {
+                    return Err("range() step cannot be zero".to_string());
+                }
+                *n as f64
+            }
             _ => return Err("range() step must be a number".to_string()),
         }
     } else {
@@ -36,16 +36,17 @@ pub fn contains(args: &[Value], _env: &mut Environment) -> Result<Value, String>
         }
         Value::Tensor(tensor) => {
             // Only works if searching for a number
-            if let Value::Number(n) = search_value {
-                for &val in tensor.data() {
-                    if (val - n).abs() < f64::EPSILON {
-                        return Ok(Value::Boolean(true));
-                    }
+            let n = match search_value {
+                Value::Number(n) => *n,
+                Value::Integer(n) => *n as f64,
+                _ => return Err("contains() on tensor requires numeric search value".to_string()),
+            };
+            for &val in tensor.data() {
+                if (val - n).abs() < f64::EPSILON {
+                    return Ok(Value::Boolean(true));
                 }
-                Ok(Value::Boolean(false))
-            } else {
-                Err("contains() on tensor requires numeric search value".to_string())
             }
+            Ok(Value::Boolean(false))
         }
         Value::String(s) => {
             // Bonus: string contains substring
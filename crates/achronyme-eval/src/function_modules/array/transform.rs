@@ -59,6 +59,12 @@ pub fn flatten(args: &[Value], _env: &mut Environment) -> Result<Value, String>
                 }
                 *n as usize
             }
+            Value::Integer(n) => {
+                if *n < 0 {
+                    return Err("flatten() depth must be a non-negative integer".to_string());
+                }
+                *n as usize
+            }
             _ => return Err("flatten() depth must be a number".to_string()),
         }
     } else {
@@ -124,6 +130,12 @@ pub fn take(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
             }
             *num as usize
         }
+        Value::Integer(num) => {
+            if *num < 0 {
+                return Err("take() count must be a non-negative integer".to_string());
+            }
+            *num as usize
+        }
         _ => return Err("take() count must be a number".to_string()),
     };
 
@@ -153,6 +165,12 @@ pub fn drop(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
             }
             *num as usize
         }
+        Value::Integer(num) => {
+            if *num < 0 {
+                return Err("drop() count must be a non-negative integer".to_string());
+            }
+            *num as usize
+        }
         _ => return Err("drop() count must be a number".to_string()),
     };
 
@@ -190,6 +208,12 @@ pub fn slice(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
             }
             *n as usize
         }
+        Value::Integer(n) => {
+            if *n < 0 {
+                return Err("slice() start index must be a non-negative integer".to_string());
+            }
+            *n as usize
+        }
         _ => return Err("slice() start index must be a number".to_string()),
     };
 
@@ -201,6 +225,12 @@ pub fn slice(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
                 }
                 *n as usize
             }
+            Value::Integer(n) => {
+                if *n < 0 {
+                    return Err("slice() end index must be a non-negative integer".to_string());
+                }
+                *n as usize
+            }
             _ => return Err("slice() end index must be a number".to_string()),
         }
     } else {
@@ -269,6 +299,12 @@ pub fn chunk(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
             }
             *n as usize
         }
+        Value::Integer(n) => {
+            if *n <= 0 {
+                return Err("chunk() size must be a positive integer".to_string());
+            }
+            *n as usize
+        }
         _ => return Err("chunk() size must be a number".to_string()),
     };
 
@@ -4,6 +4,8 @@ macro_rules! unary_math_fn {
     ($name:expr, $f:expr, $arg:expr) => {
         match $arg {
             achronyme_types::value::Value::Number(x) => Ok(achronyme_types::value::Value::Number($f(*x))),
+            // Integer literals widen to Number here: these functions aren't closed over the integers.
+            achronyme_types::value::Value::Integer(x) => Ok(achronyme_types::value::Value::Number($f(*x as f64))),
 
             // Tensor support (optimized path)
             achronyme_types::value::Value::Tensor(t) => {
@@ -17,10 +19,14 @@ macro_rules! unary_math_fn {
             achronyme_types::value::Value::Vector(v) => {
                 let mut result = Vec::new();
                 for val in v {
-                    if let achronyme_types::value::Value::Number(n) = val {
-                        result.push(achronyme_types::value::Value::Number($f(*n)));
-                    } else {
-                        return Err(format!("{}() can only be applied to numeric vectors", $name));
+                    match val {
+                        achronyme_types::value::Value::Number(n) => {
+                            result.push(achronyme_types::value::Value::Number($f(*n)));
+                        }
+                        achronyme_types::value::Value::Integer(n) => {
+                            result.push(achronyme_types::value::Value::Number($f(*n as f64)));
+                        }
+                        _ => return Err(format!("{}() can only be applied to numeric vectors", $name)),
                     }
                 }
                 Ok(achronyme_types::value::Value::Vector(result))
@@ -4,10 +4,15 @@ use achronyme_types::Environment;
 
 pub fn register_functions(registry: &mut FunctionRegistry) {
     registry.register("complex", complex, 2);
+    registry.register("parseComplex", parse_complex, 1);
     registry.register("real", real, 1);
     registry.register("imag", imag, 1);
     registry.register("conj", conj, 1);
     registry.register("arg", arg, 1);
+    registry.register("nthRoots", nth_roots, 2);
+    registry.register("isNaN", is_nan, 1);
+    registry.register("isInfinite", is_infinite, 1);
+    registry.register("isFinite", is_finite, 1);
 }
 
 // Implementations
@@ -21,6 +26,16 @@ fn complex(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
     }
 }
 
+fn parse_complex(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
+    match &args[0] {
+        Value::String(s) => s
+            .parse::<achronyme_types::complex::Complex>()
+            .map(Value::Complex)
+            .map_err(|e| format!("parseComplex(): {}", e)),
+        _ => Err("parseComplex() requires a string".to_string()),
+    }
+}
+
 fn real(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
     match &args[0] {
         Value::Number(x) => Ok(Value::Number(*x)),
@@ -120,3 +135,103 @@ fn arg(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
         _ => Err("arg() requires a number or complex number".to_string()),
     }
 }
+
+fn nth_roots(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
+    let z = match &args[0] {
+        Value::Number(x) => achronyme_types::complex::Complex::from_real(*x),
+        Value::Integer(x) => achronyme_types::complex::Complex::from_real(*x as f64),
+        Value::Complex(c) => *c,
+        _ => return Err("nthRoots() requires a number or complex number".to_string()),
+    };
+    let n = match &args[1] {
+        Value::Number(n) if *n >= 0.0 && n.fract() == 0.0 => *n as u32,
+        Value::Integer(n) if *n >= 0 => *n as u32,
+        _ => return Err("nthRoots() requires a non-negative integer n".to_string()),
+    };
+
+    z.roots(n)
+        .map(|roots| Value::Vector(roots.into_iter().map(Value::Complex).collect()))
+        .map_err(|e| format!("nthRoots(): {}", e))
+}
+
+fn is_nan(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
+    match &args[0] {
+        Value::Number(x) => Ok(Value::Boolean(x.is_nan())),
+        Value::Complex(c) => Ok(Value::Boolean(c.is_nan())),
+        _ => Err("isNaN() requires a number or complex number".to_string()),
+    }
+}
+
+fn is_infinite(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
+    match &args[0] {
+        Value::Number(x) => Ok(Value::Boolean(x.is_infinite())),
+        Value::Complex(c) => Ok(Value::Boolean(c.is_infinite())),
+        _ => Err("isInfinite() requires a number or complex number".to_string()),
+    }
+}
+
+fn is_finite(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
+    match &args[0] {
+        Value::Number(x) => Ok(Value::Boolean(x.is_finite())),
+        Value::Complex(c) => Ok(Value::Boolean(c.is_finite())),
+        _ => Err("isFinite() requires a number or complex number".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_complex() {
+        let mut env = Environment::new();
+        let args = vec![Value::String("2+3i".to_string())];
+        let result = parse_complex(&args, &mut env).unwrap();
+        assert_eq!(result, Value::Complex(achronyme_types::complex::Complex::new(2.0, 3.0)));
+    }
+
+    #[test]
+    fn test_parse_complex_rejects_malformed_input() {
+        let mut env = Environment::new();
+        let args = vec![Value::String("not a complex number".to_string())];
+        assert!(parse_complex(&args, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_parse_complex_requires_a_string() {
+        let mut env = Environment::new();
+        let args = vec![Value::Number(42.0)];
+        assert!(parse_complex(&args, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_nth_roots_of_negative_eight() {
+        let mut env = Environment::new();
+        let args = vec![Value::Number(-8.0), Value::Number(3.0)];
+        let result = nth_roots(&args, &mut env).unwrap();
+        match result {
+            Value::Vector(roots) => assert_eq!(roots.len(), 3),
+            _ => panic!("expected a vector"),
+        }
+    }
+
+    #[test]
+    fn test_nth_roots_rejects_n_zero() {
+        let mut env = Environment::new();
+        let args = vec![Value::Number(1.0), Value::Number(0.0)];
+        assert!(nth_roots(&args, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_is_nan_is_infinite_is_finite() {
+        let mut env = Environment::new();
+        let nan = vec![Value::Complex(achronyme_types::complex::Complex::new(f64::NAN, 0.0))];
+        let inf = vec![Value::Complex(achronyme_types::complex::Complex::new(f64::INFINITY, 0.0))];
+        let finite = vec![Value::Complex(achronyme_types::complex::Complex::new(1.0, 2.0))];
+
+        assert_eq!(is_nan(&nan, &mut env).unwrap(), Value::Boolean(true));
+        assert_eq!(is_infinite(&inf, &mut env).unwrap(), Value::Boolean(true));
+        assert_eq!(is_finite(&finite, &mut env).unwrap(), Value::Boolean(true));
+        assert_eq!(is_finite(&inf, &mut env).unwrap(), Value::Boolean(false));
+    }
+}
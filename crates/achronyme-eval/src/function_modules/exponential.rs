@@ -17,10 +17,16 @@ pub fn register_functions(registry: &mut FunctionRegistry) {
 // Implementations
 
 fn exp(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
+    if let Value::Dual(d) = &args[0] {
+        return Ok(Value::Dual(d.exp()));
+    }
     unary_math_fn!("exp", f64::exp, &args[0])
 }
 
 fn ln(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
+    if let Value::Dual(d) = &args[0] {
+        return Ok(Value::Dual(d.ln()));
+    }
     unary_math_fn!("ln", f64::ln, &args[0])
 }
 
@@ -33,6 +39,9 @@ fn log2(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
 }
 
 fn sqrt(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
+    if let Value::Dual(d) = &args[0] {
+        return Ok(Value::Dual(d.sqrt()));
+    }
     unary_math_fn!("sqrt", f64::sqrt, &args[0])
 }
 
@@ -43,6 +52,11 @@ fn cbrt(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
 fn pow(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
     match (&args[0], &args[1]) {
         (Value::Number(x), Value::Number(y)) => Ok(Value::Number(x.powf(*y))),
+        (Value::Dual(x), Value::Number(y)) => Ok(Value::Dual(x.powf(*y))),
+        // Integer arguments widen to Number, as in the unary math functions.
+        (Value::Integer(x), Value::Integer(y)) => Ok(Value::Number((*x as f64).powf(*y as f64))),
+        (Value::Integer(x), Value::Number(y)) => Ok(Value::Number((*x as f64).powf(*y))),
+        (Value::Number(x), Value::Integer(y)) => Ok(Value::Number(x.powf(*y as f64))),
         _ => Err("pow() requires two numbers".to_string()),
     }
 }
@@ -33,6 +33,8 @@ fn round(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
 fn abs(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
     match &args[0] {
         Value::Number(x) => Ok(Value::Number(x.abs())),
+        // abs() is closed over the integers, unlike the unary_math_fn! functions.
+        Value::Integer(n) => Ok(Value::Integer(n.abs())),
         Value::Complex(c) => {
             // For complex numbers, abs returns the magnitude as a real number
             Ok(Value::Number(c.norm()))
@@ -59,6 +61,7 @@ fn abs(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
             for val in v {
                 match val {
                     Value::Number(n) => result.push(Value::Number(n.abs())),
+                    Value::Integer(n) => result.push(Value::Integer(n.abs())),
                     Value::Complex(c) => result.push(Value::Number(c.norm())),
                     _ => return Err("abs() can only be applied to numeric vectors".to_string()),
                 }
@@ -109,41 +112,59 @@ fn min(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
                 if v.is_empty() {
                     return Err("min() requires a non-empty vector".to_string());
                 }
+                let mut all_integer = true;
                 let mut min_val = match &v[0] {
-                    Value::Number(n) => *n,
+                    Value::Number(n) => {
+                        all_integer = false;
+                        *n
+                    }
+                    Value::Integer(n) => *n as f64,
                     _ => return Err("min() on a vector requires numeric values".to_string()),
                 };
                 for val in v.iter().skip(1) {
-                    if let Value::Number(n) = val {
-                        if *n < min_val {
-                            min_val = *n;
+                    let n = match val {
+                        Value::Number(n) => {
+                            all_integer = false;
+                            *n
                         }
-                    } else {
-                        return Err("min() on a vector requires numeric values".to_string());
+                        Value::Integer(n) => *n as f64,
+                        _ => return Err("min() on a vector requires numeric values".to_string()),
+                    };
+                    if n < min_val {
+                        min_val = n;
                     }
                 }
-                return Ok(Value::Number(min_val));
+                return Ok(if all_integer { Value::Integer(min_val as i64) } else { Value::Number(min_val) });
             }
             _ => {}
         }
     }
 
-    // Multiple arguments case - find min across arguments
+    // Multiple arguments case - find min across arguments. Stays Integer
+    // only if every argument is, mirroring the arithmetic operators.
+    let mut all_integer = true;
     let mut result = match &args[0] {
-        Value::Number(x) => *x,
+        Value::Number(x) => {
+            all_integer = false;
+            *x
+        }
+        Value::Integer(n) => *n as f64,
         _ => return Err("min() requires numbers".to_string()),
     };
     for arg in &args[1..] {
-        match arg {
+        let x = match arg {
             Value::Number(x) => {
-                if *x < result {
-                    result = *x;
-                }
+                all_integer = false;
+                *x
             }
+            Value::Integer(n) => *n as f64,
             _ => return Err("min() requires numbers".to_string()),
+        };
+        if x < result {
+            result = x;
         }
     }
-    Ok(Value::Number(result))
+    Ok(if all_integer { Value::Integer(result as i64) } else { Value::Number(result) })
 }
 
 fn max(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
@@ -161,39 +182,57 @@ fn max(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
                 if v.is_empty() {
                     return Err("max() requires a non-empty vector".to_string());
                 }
+                let mut all_integer = true;
                 let mut max_val = match &v[0] {
-                    Value::Number(n) => *n,
+                    Value::Number(n) => {
+                        all_integer = false;
+                        *n
+                    }
+                    Value::Integer(n) => *n as f64,
                     _ => return Err("max() on a vector requires numeric values".to_string()),
                 };
                 for val in v.iter().skip(1) {
-                    if let Value::Number(n) = val {
-                        if *n > max_val {
-                            max_val = *n;
+                    let n = match val {
+                        Value::Number(n) => {
+                            all_integer = false;
+                            *n
                         }
-                    } else {
-                        return Err("max() on a vector requires numeric values".to_string());
+                        Value::Integer(n) => *n as f64,
+                        _ => return Err("max() on a vector requires numeric values".to_string()),
+                    };
+                    if n > max_val {
+                        max_val = n;
                     }
                 }
-                return Ok(Value::Number(max_val));
+                return Ok(if all_integer { Value::Integer(max_val as i64) } else { Value::Number(max_val) });
             }
             _ => {}
         }
     }
 
-    // Multiple arguments case - find max across arguments
+    // Multiple arguments case - find max across arguments. Stays Integer
+    // only if every argument is, mirroring the arithmetic operators.
+    let mut all_integer = true;
     let mut result = match &args[0] {
-        Value::Number(x) => *x,
+        Value::Number(x) => {
+            all_integer = false;
+            *x
+        }
+        Value::Integer(n) => *n as f64,
         _ => return Err("max() requires numbers".to_string()),
     };
     for arg in &args[1..] {
-        match arg {
+        let x = match arg {
             Value::Number(x) => {
-                if *x > result {
-                    result = *x;
-                }
+                all_integer = false;
+                *x
             }
+            Value::Integer(n) => *n as f64,
             _ => return Err("max() requires numbers".to_string()),
+        };
+        if x > result {
+            result = x;
         }
     }
-    Ok(Value::Number(result))
+    Ok(if all_integer { Value::Integer(result as i64) } else { Value::Number(result) })
 }
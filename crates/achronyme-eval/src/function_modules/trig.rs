@@ -8,6 +8,8 @@ macro_rules! unary_math_fn {
     ($name:expr, $f:expr, $arg:expr) => {
         match $arg {
             Value::Number(x) => Ok(Value::Number($f(*x))),
+            // Integer literals widen to Number here: trig functions aren't closed over the integers.
+            Value::Integer(x) => Ok(Value::Number($f(*x as f64))),
             Value::Vector(v) => {
                 let result: Vec<f64> = v.data().iter().map(|&x| $f(x)).collect();
                 Ok(Value::Vector(Vector::new(result)))
@@ -17,6 +19,17 @@ macro_rules! unary_math_fn {
     };
 }
 
+/// Helper macro for unary functions that additionally support `Complex`,
+/// dispatching to the matching `Complex` method for the complex case.
+macro_rules! unary_math_fn_complex {
+    ($name:expr, $f:expr, $cf:ident, $arg:expr) => {
+        match $arg {
+            Value::Complex(c) => Ok(Value::Complex(c.$cf())),
+            other => unary_math_fn!($name, $f, other),
+        }
+    };
+}
+
 /// Register all trigonometric functions
 pub fn register_functions(registry: &mut FunctionRegistry) {
     // Basic trigonometric functions
@@ -34,6 +47,11 @@ pub fn register_functions(registry: &mut FunctionRegistry) {
     registry.register("sinh", sinh, 1);
     registry.register("cosh", cosh, 1);
     registry.register("tanh", tanh, 1);
+
+    // Inverse hyperbolic functions
+    registry.register("asinh", asinh, 1);
+    registry.register("acosh", acosh, 1);
+    registry.register("atanh", atanh, 1);
 }
 
 // ============================================================================
@@ -41,27 +59,36 @@ pub fn register_functions(registry: &mut FunctionRegistry) {
 // ============================================================================
 
 fn sin(args: &[Value]) -> Result<Value, String> {
-    unary_math_fn!("sin", f64::sin, &args[0])
+    if let Value::Dual(d) = &args[0] {
+        return Ok(Value::Dual(d.sin()));
+    }
+    unary_math_fn_complex!("sin", f64::sin, sin, &args[0])
 }
 
 fn cos(args: &[Value]) -> Result<Value, String> {
-    unary_math_fn!("cos", f64::cos, &args[0])
+    if let Value::Dual(d) = &args[0] {
+        return Ok(Value::Dual(d.cos()));
+    }
+    unary_math_fn_complex!("cos", f64::cos, cos, &args[0])
 }
 
 fn tan(args: &[Value]) -> Result<Value, String> {
-    unary_math_fn!("tan", f64::tan, &args[0])
+    if let Value::Dual(d) = &args[0] {
+        return Ok(Value::Dual(d.tan()));
+    }
+    unary_math_fn_complex!("tan", f64::tan, tan, &args[0])
 }
 
 fn asin(args: &[Value]) -> Result<Value, String> {
-    unary_math_fn!("asin", f64::asin, &args[0])
+    unary_math_fn_complex!("asin", f64::asin, asin, &args[0])
 }
 
 fn acos(args: &[Value]) -> Result<Value, String> {
-    unary_math_fn!("acos", f64::acos, &args[0])
+    unary_math_fn_complex!("acos", f64::acos, acos, &args[0])
 }
 
 fn atan(args: &[Value]) -> Result<Value, String> {
-    unary_math_fn!("atan", f64::atan, &args[0])
+    unary_math_fn_complex!("atan", f64::atan, atan, &args[0])
 }
 
 fn atan2(args: &[Value]) -> Result<Value, String> {
@@ -72,13 +99,25 @@ fn atan2(args: &[Value]) -> Result<Value, String> {
 }
 
 fn sinh(args: &[Value]) -> Result<Value, String> {
-    unary_math_fn!("sinh", f64::sinh, &args[0])
+    unary_math_fn_complex!("sinh", f64::sinh, sinh, &args[0])
 }
 
 fn cosh(args: &[Value]) -> Result<Value, String> {
-    unary_math_fn!("cosh", f64::cosh, &args[0])
+    unary_math_fn_complex!("cosh", f64::cosh, cosh, &args[0])
 }
 
 fn tanh(args: &[Value]) -> Result<Value, String> {
-    unary_math_fn!("tanh", f64::tanh, &args[0])
+    unary_math_fn_complex!("tanh", f64::tanh, tanh, &args[0])
+}
+
+fn asinh(args: &[Value]) -> Result<Value, String> {
+    unary_math_fn_complex!("asinh", f64::asinh, asinh, &args[0])
+}
+
+fn acosh(args: &[Value]) -> Result<Value, String> {
+    unary_math_fn_complex!("acosh", f64::acosh, acosh, &args[0])
+}
+
+fn atanh(args: &[Value]) -> Result<Value, String> {
+    unary_math_fn_complex!("atanh", f64::atanh, atanh, &args[0])
 }
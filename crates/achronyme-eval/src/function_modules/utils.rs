@@ -18,6 +18,7 @@ pub fn register_functions(registry: &mut FunctionRegistry) {
 
     // String conversion
     registry.register("str", to_string, 1);
+    registry.register("format", format_fn, 2);
 }
 
 // ============================================================================
@@ -60,7 +61,8 @@ fn print(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
 /// Returns a string describing the type of the value.
 ///
 /// Examples:
-/// - typeof(42) => "Number"
+/// - typeof(42) => "Integer"
+/// - typeof(4.2) => "Number"
 /// - typeof("hello") => "String"
 /// - typeof([1, 2, 3]) => "Tensor"
 /// - typeof(true) => "Boolean"
@@ -73,6 +75,7 @@ fn type_of(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
 fn get_type_name(value: &Value) -> String {
     match value {
         Value::Number(_) => "Number".to_string(),
+        Value::Integer(_) => "Integer".to_string(),
         Value::Boolean(_) => "Boolean".to_string(),
         Value::Complex(_) => "Complex".to_string(),
         Value::String(_) => "String".to_string(),
@@ -83,6 +86,7 @@ fn get_type_name(value: &Value) -> String {
         Value::Record(_) => "Record".to_string(),
         Value::Edge { .. } => "Edge".to_string(),
         Value::TailCall(_) => "TailCall".to_string(),
+        Value::Dual(_) => "Dual".to_string(),
         Value::EarlyReturn(_) => "EarlyReturn".to_string(),
         Value::MutableRef(rc) => {
             // For mutable refs, show the type of the inner value
@@ -113,6 +117,145 @@ fn to_string(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
     Ok(Value::String(format_value(&args[0])))
 }
 
+/// Convert a value to a string using an explicit numeric format spec,
+/// applied consistently to `Number`, both parts of `Complex`, and the
+/// elements of `Vector` (other value kinds fall back to `str()`'s default).
+///
+/// The spec is either:
+/// - a number of fixed decimal places: `format(23.456, 2)` => `"23.46"`
+/// - `"<n>s"` for `n` significant digits: `format(1234.5, "3s")` => `"1230"`
+/// - `",<n>"` for `n` decimal places with thousands separators:
+///   `format(1234567.891, ",2")` => `"1,234,567.89"`
+///
+/// Examples:
+/// - format(23.456, 2) => "23.46"
+/// - format(2 + 3.14159i, 2) => "2.00+3.14i"
+/// - format([1.2345, 2.3456], 1) => "[1.2, 2.3]"
+fn format_fn(args: &[Value], _env: &mut Environment) -> Result<Value, String> {
+    let spec = parse_format_spec(&args[1])?;
+    Ok(Value::String(format_value_with_spec(&args[0], &spec)))
+}
+
+/// A numeric formatting spec understood by `format()`.
+enum FormatSpec {
+    /// Fixed number of digits after the decimal point.
+    Fixed(usize),
+    /// Fixed number of significant digits.
+    Significant(usize),
+    /// Fixed decimal places, grouped with thousands separators.
+    Thousands(usize),
+}
+
+fn parse_format_spec(spec: &Value) -> Result<FormatSpec, String> {
+    match spec {
+        Value::Number(n) if *n >= 0.0 && n.fract() == 0.0 => Ok(FormatSpec::Fixed(*n as usize)),
+        Value::Integer(n) if *n >= 0 => Ok(FormatSpec::Fixed(*n as usize)),
+        Value::String(s) => {
+            if let Some(digits) = s.strip_suffix('s') {
+                digits
+                    .parse::<usize>()
+                    .map(FormatSpec::Significant)
+                    .map_err(|_| format!("format(): invalid significant-digits spec '{}'", s))
+            } else if let Some(rest) = s.strip_prefix(',') {
+                if rest.is_empty() {
+                    Ok(FormatSpec::Thousands(0))
+                } else {
+                    rest.parse::<usize>()
+                        .map(FormatSpec::Thousands)
+                        .map_err(|_| format!("format(): invalid thousands-separator spec '{}'", s))
+                }
+            } else {
+                Err(format!("format(): unrecognized format spec '{}'", s))
+            }
+        }
+        _ => Err("format() requires a non-negative integer or a format-spec string for its second argument".to_string()),
+    }
+}
+
+fn format_value_with_spec(value: &Value, spec: &FormatSpec) -> String {
+    match value {
+        Value::Number(n) => format_number_with_spec(*n, spec),
+        Value::Integer(n) => format_number_with_spec(*n as f64, spec),
+        Value::Complex(c) => format_complex_with_spec(c, spec),
+        Value::Vector(vec) => {
+            let elements: Vec<String> = vec.iter().map(|v| format_value_with_spec(v, spec)).collect();
+            format!("[{}]", elements.join(", "))
+        }
+        other => format_value(other),
+    }
+}
+
+fn format_complex_with_spec(c: &achronyme_types::complex::Complex, spec: &FormatSpec) -> String {
+    if c.im == 0.0 {
+        return format_number_with_spec(c.re, spec);
+    }
+
+    let im_part = format_number_with_spec(c.im.abs(), spec);
+    if c.re == 0.0 {
+        let sign = if c.im.is_sign_negative() { "-" } else { "" };
+        format!("{}{}i", sign, im_part)
+    } else {
+        let sign = if c.im.is_sign_negative() { "-" } else { "+" };
+        format!("{}{}{}i", format_number_with_spec(c.re, spec), sign, im_part)
+    }
+}
+
+fn format_number_with_spec(n: f64, spec: &FormatSpec) -> String {
+    match spec {
+        FormatSpec::Fixed(precision) => format!("{:.*}", precision, n),
+        FormatSpec::Significant(digits) => format_significant(n, *digits),
+        FormatSpec::Thousands(precision) => format_thousands(n, *precision),
+    }
+}
+
+/// Round `n` to `digits` significant figures (e.g. `1234.5` with 3 digits
+/// becomes `"1230"`).
+fn format_significant(n: f64, digits: usize) -> String {
+    if n == 0.0 || digits == 0 {
+        return "0".to_string();
+    }
+    let magnitude = n.abs().log10().floor() as i32;
+    let decimals = digits as i32 - 1 - magnitude;
+    if decimals >= 0 {
+        format!("{:.*}", decimals as usize, n)
+    } else {
+        // magnitude >= digits: decimals would be negative, i.e. the
+        // requested precision falls to the left of the decimal point.
+        // Round to the nearest 10^(-decimals) and format as a whole
+        // number, so the trailing digits come out as zeros rather than
+        // being left unrounded.
+        let scale = 10f64.powi(-decimals);
+        let rounded = (n / scale).round() * scale;
+        format!("{:.0}", rounded)
+    }
+}
+
+/// Format `n` with `precision` decimal places and thousands separators
+/// in the integer part, e.g. `1234567.891` with precision 2 becomes
+/// `"1,234,567.89"`.
+fn format_thousands(n: f64, precision: usize) -> String {
+    let formatted = format!("{:.*}", precision, n.abs());
+    let (int_part, frac_part) = match formatted.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (formatted.as_str(), None),
+    };
+
+    let mut grouped = String::new();
+    let digits = int_part.as_bytes();
+    for (i, digit) in digits.iter().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(*digit as char);
+    }
+
+    let sign = if n.is_sign_negative() && n != 0.0 { "-" } else { "" };
+    match frac_part {
+        Some(frac) => format!("{}{}.{}", sign, grouped, frac),
+        None => format!("{}{}", sign, grouped),
+    }
+}
+
 /// Format a value for display
 fn format_value(value: &Value) -> String {
     match value {
@@ -124,6 +267,7 @@ fn format_value(value: &Value) -> String {
                 format!("{}", n)
             }
         }
+        Value::Integer(n) => format!("{}", n),
         Value::Boolean(b) => format!("{}", b),
         Value::Complex(c) => {
             // Format: a+bi or a-bi
@@ -203,6 +347,7 @@ fn format_value(value: &Value) -> String {
             }
         }
         Value::TailCall(_) => "<tail-call>".to_string(),
+        Value::Dual(_) => "<dual>".to_string(),
         Value::EarlyReturn(_) => "<early-return>".to_string(),
         Value::MutableRef(rc) => {
             match rc.try_borrow() {
@@ -305,4 +450,57 @@ mod tests {
         let value = Value::Number(3.14);
         assert_eq!(format_value(&value), "3.14");
     }
+
+    #[test]
+    fn test_format_fixed_precision_rounds() {
+        let mut env = Environment::new();
+        let args = vec![Value::Number(23.456), Value::Number(2.0)];
+        let result = format_fn(&args, &mut env).unwrap();
+        assert_eq!(result, Value::String("23.46".to_string()));
+    }
+
+    #[test]
+    fn test_format_significant_digits() {
+        let mut env = Environment::new();
+        let args = vec![Value::Number(1234.5), Value::String("3s".to_string())];
+        let result = format_fn(&args, &mut env).unwrap();
+        assert_eq!(result, Value::String("1230".to_string()));
+    }
+
+    #[test]
+    fn test_format_thousands_separator() {
+        let mut env = Environment::new();
+        let args = vec![Value::Number(1234567.891), Value::String(",2".to_string())];
+        let result = format_fn(&args, &mut env).unwrap();
+        assert_eq!(result, Value::String("1,234,567.89".to_string()));
+    }
+
+    #[test]
+    fn test_format_complex_part_precision() {
+        let mut env = Environment::new();
+        let args = vec![
+            Value::Complex(achronyme_types::complex::Complex::new(2.0, 3.14159)),
+            Value::Number(2.0),
+        ];
+        let result = format_fn(&args, &mut env).unwrap();
+        assert_eq!(result, Value::String("2.00+3.14i".to_string()));
+    }
+
+    #[test]
+    fn test_format_vector_elements() {
+        let mut env = Environment::new();
+        let args = vec![
+            Value::Vector(vec![Value::Number(1.2345), Value::Number(2.3456)]),
+            Value::Number(1.0),
+        ];
+        let result = format_fn(&args, &mut env).unwrap();
+        assert_eq!(result, Value::String("[1.2, 2.3]".to_string()));
+    }
+
+    #[test]
+    fn test_format_rejects_unrecognized_spec() {
+        let mut env = Environment::new();
+        let args = vec![Value::Number(1.0), Value::String("bogus".to_string())];
+        assert!(format_fn(&args, &mut env).is_err());
+    }
 }
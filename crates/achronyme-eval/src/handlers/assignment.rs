@@ -23,15 +23,16 @@ pub fn evaluate_assignment(
     value_node: &AstNode,
 ) -> Result<Value, String> {
     // Evaluate the new value first
-    let new_value = evaluator.evaluate(value_node)?;
+    let mut new_value = evaluator.evaluate(value_node)?;
 
     // Dispatch based on target type
     match target {
         // Simple variable: x = 20
         AstNode::VariableRef(name) => {
-            // Check type annotation before assignment (if one exists)
+            // Check type annotation before assignment (if one exists), coercing
+            // so e.g. `z = 3` on a `mut z: Complex` stores an actual Complex.
             if let Some(expected_type) = evaluator.environment().get_type_annotation(name) {
-                type_checker::check_type(&new_value, &expected_type).map_err(|_| {
+                new_value = type_checker::check_type_coercing(&new_value, &expected_type).map_err(|_| {
                     format!(
                         "Type error: cannot assign {} to variable '{}' of type {}",
                         type_checker::infer_type(&new_value).to_string(),
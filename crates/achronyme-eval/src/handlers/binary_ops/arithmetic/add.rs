@@ -1,4 +1,5 @@
 use achronyme_types::complex::Complex;
+use achronyme_types::dual::Dual;
 use achronyme_types::value::Value;
 use crate::handlers::binary_ops::utils::value_to_string;
 
@@ -7,6 +8,22 @@ pub fn apply_add(left: Value, right: Value) -> Result<Value, String> {
         (Value::Number(a), Value::Number(b)) => Ok(Value::Number(a + b)),
         (Value::Complex(a), Value::Complex(b)) => Ok(Value::Complex(a + b)),
 
+        // Integer + Integer stays Integer; mixed with Number promotes to Number.
+        // Overflow falls back to Number rather than panicking (checked_add).
+        (Value::Integer(a), Value::Integer(b)) => match a.checked_add(b) {
+            Some(sum) => Ok(Value::Integer(sum)),
+            None => Ok(Value::Number(a as f64 + b as f64)),
+        },
+        (Value::Integer(a), Value::Number(b)) => Ok(Value::Number(a as f64 + b)),
+        (Value::Number(a), Value::Integer(b)) => Ok(Value::Number(a + b as f64)),
+        (Value::Integer(a), Value::Complex(b)) => Ok(Value::Complex(Complex::from_real(a as f64) + b)),
+        (Value::Complex(a), Value::Integer(b)) => Ok(Value::Complex(a + Complex::from_real(b as f64))),
+
+        // Dual numbers (automatic differentiation)
+        (Value::Dual(a), Value::Dual(b)) => Ok(Value::Dual(a + b)),
+        (Value::Dual(a), Value::Number(b)) => Ok(Value::Dual(a + Dual::constant(b))),
+        (Value::Number(a), Value::Dual(b)) => Ok(Value::Dual(Dual::constant(a) + b)),
+
         // Tensor support (optimized path)
         (Value::Tensor(a), Value::Tensor(b)) => {
             a.add(&b).map(Value::Tensor).map_err(|e| e.to_string())
@@ -54,6 +71,7 @@ pub fn apply_add(left: Value, right: Value) -> Result<Value, String> {
             if Value::is_numeric_vector(vec) {
                 let result: Vec<Value> = vec.iter().map(|v| match v {
                     Value::Number(n) => Value::Number(n + scalar),
+                    Value::Integer(n) => Value::Number(*n as f64 + scalar),
                     Value::Complex(c) => Value::Complex(*c + Complex::from_real(scalar)),
                     _ => unreachable!(),
                 }).collect();
@@ -66,6 +84,7 @@ pub fn apply_add(left: Value, right: Value) -> Result<Value, String> {
             if Value::is_numeric_vector(vec) {
                 let result: Vec<Value> = vec.iter().map(|v| match v {
                     Value::Number(n) => Value::Number(n + scalar),
+                    Value::Integer(n) => Value::Number(*n as f64 + scalar),
                     Value::Complex(c) => Value::Complex(*c + Complex::from_real(scalar)),
                     _ => unreachable!(),
                 }).collect();
@@ -75,11 +94,46 @@ pub fn apply_add(left: Value, right: Value) -> Result<Value, String> {
             }
         }
 
+        // Broadcasting: Scalar + Vector (Integer scalar)
+        (Value::Integer(scalar), Value::Vector(ref vec)) => {
+            if Value::is_numeric_vector(vec) {
+                let result: Vec<Value> = vec.iter().map(|v| match v {
+                    Value::Number(n) => Value::Number(scalar as f64 + n),
+                    Value::Integer(n) => match scalar.checked_add(*n) {
+                        Some(sum) => Value::Integer(sum),
+                        None => Value::Number(scalar as f64 + *n as f64),
+                    },
+                    Value::Complex(c) => Value::Complex(Complex::from_real(scalar as f64) + *c),
+                    _ => unreachable!(),
+                }).collect();
+                Ok(Value::Vector(result))
+            } else {
+                Err("Broadcasting requires numeric vector".to_string())
+            }
+        }
+        (Value::Vector(ref vec), Value::Integer(scalar)) => {
+            if Value::is_numeric_vector(vec) {
+                let result: Vec<Value> = vec.iter().map(|v| match v {
+                    Value::Number(n) => Value::Number(n + scalar as f64),
+                    Value::Integer(n) => match n.checked_add(scalar) {
+                        Some(sum) => Value::Integer(sum),
+                        None => Value::Number(*n as f64 + scalar as f64),
+                    },
+                    Value::Complex(c) => Value::Complex(*c + Complex::from_real(scalar as f64)),
+                    _ => unreachable!(),
+                }).collect();
+                Ok(Value::Vector(result))
+            } else {
+                Err("Broadcasting requires numeric vector".to_string())
+            }
+        }
+
         // Broadcasting: Complex + Vector
         (Value::Complex(c), Value::Vector(ref vec)) => {
             if Value::is_numeric_vector(vec) {
                 let result: Vec<Value> = vec.iter().map(|v| match v {
                     Value::Number(n) => Value::Complex(Complex::from_real(*n) + c),
+                    Value::Integer(n) => Value::Complex(Complex::from_real(*n as f64) + c),
                     Value::Complex(cv) => Value::Complex(*cv + c),
                     _ => unreachable!(),
                 }).collect();
@@ -92,6 +146,7 @@ pub fn apply_add(left: Value, right: Value) -> Result<Value, String> {
             if Value::is_numeric_vector(vec) {
                 let result: Vec<Value> = vec.iter().map(|v| match v {
                     Value::Number(n) => Value::Complex(Complex::from_real(*n) + c),
+                    Value::Integer(n) => Value::Complex(Complex::from_real(*n as f64) + c),
                     Value::Complex(cv) => Value::Complex(*cv + c),
                     _ => unreachable!(),
                 }).collect();
@@ -109,6 +164,14 @@ pub fn apply_add(left: Value, right: Value) -> Result<Value, String> {
             Ok(Value::Tensor(t.add_scalar(scalar)))
         }
 
+        // Broadcasting: Tensor + Scalar (Integer scalar)
+        (Value::Tensor(t), Value::Integer(scalar)) => {
+            Ok(Value::Tensor(t.add_scalar(scalar as f64)))
+        }
+        (Value::Integer(scalar), Value::Tensor(t)) => {
+            Ok(Value::Tensor(t.add_scalar(scalar as f64)))
+        }
+
         // Broadcasting: Tensor + Complex
         (Value::Tensor(t), Value::Complex(c)) => {
             // Convert real tensor to complex tensor, then add
@@ -129,6 +192,14 @@ pub fn apply_add(left: Value, right: Value) -> Result<Value, String> {
             Ok(Value::ComplexTensor(ct.add_scalar(Complex::from_real(scalar))))
         }
 
+        // Broadcasting: ComplexTensor + Scalar (Integer scalar)
+        (Value::ComplexTensor(ct), Value::Integer(scalar)) => {
+            Ok(Value::ComplexTensor(ct.add_scalar(Complex::from_real(scalar as f64))))
+        }
+        (Value::Integer(scalar), Value::ComplexTensor(ct)) => {
+            Ok(Value::ComplexTensor(ct.add_scalar(Complex::from_real(scalar as f64))))
+        }
+
         // Broadcasting: ComplexTensor + Complex
         (Value::ComplexTensor(ct), Value::Complex(c)) => {
             Ok(Value::ComplexTensor(ct.add_scalar(c)))
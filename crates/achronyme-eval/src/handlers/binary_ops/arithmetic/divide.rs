@@ -1,4 +1,5 @@
 use achronyme_types::complex::Complex;
+use achronyme_types::dual::Dual;
 use achronyme_types::value::Value;
 use achronyme_types::tensor::{RealTensor, ComplexTensor};
 
@@ -13,6 +14,36 @@ pub fn apply_divide(left: Value, right: Value) -> Result<Value, String> {
         }
         (Value::Complex(a), Value::Complex(b)) => Ok(Value::Complex(a / b)),
 
+        // Integer / Integer always promotes to Number: division isn't closed over the integers.
+        (Value::Integer(a), Value::Integer(b)) => {
+            if b == 0 {
+                Err("Division by zero".to_string())
+            } else {
+                Ok(Value::Number(a as f64 / b as f64))
+            }
+        }
+        (Value::Integer(a), Value::Number(b)) => {
+            if b == 0.0 {
+                Err("Division by zero".to_string())
+            } else {
+                Ok(Value::Number(a as f64 / b))
+            }
+        }
+        (Value::Number(a), Value::Integer(b)) => {
+            if b == 0 {
+                Err("Division by zero".to_string())
+            } else {
+                Ok(Value::Number(a / b as f64))
+            }
+        }
+        (Value::Integer(a), Value::Complex(b)) => Ok(Value::Complex(Complex::from_real(a as f64) / b)),
+        (Value::Complex(a), Value::Integer(b)) => Ok(Value::Complex(a / Complex::from_real(b as f64))),
+
+        // Dual numbers (automatic differentiation)
+        (Value::Dual(a), Value::Dual(b)) => Ok(Value::Dual(a / b)),
+        (Value::Dual(a), Value::Number(b)) => Ok(Value::Dual(a / Dual::constant(b))),
+        (Value::Number(a), Value::Dual(b)) => Ok(Value::Dual(Dual::constant(a) / b)),
+
         // Tensor support (optimized path)
         (Value::Tensor(a), Value::Tensor(b)) => {
             a.div(&b).map(Value::Tensor).map_err(|e| e.to_string())
@@ -60,6 +91,7 @@ pub fn apply_divide(left: Value, right: Value) -> Result<Value, String> {
             if Value::is_numeric_vector(vec) {
                 let result: Result<Vec<Value>, String> = vec.iter().map(|v| match v {
                     Value::Number(n) => if *n == 0.0 { Err("Division by zero".to_string()) } else { Ok(Value::Number(scalar / n)) },
+                    Value::Integer(n) => if *n == 0 { Err("Division by zero".to_string()) } else { Ok(Value::Number(scalar / *n as f64)) },
                     Value::Complex(c) => if c.re == 0.0 && c.im == 0.0 { Err("Division by zero".to_string()) } else { Ok(Value::Complex(Complex::from_real(scalar) / *c)) },
                     _ => unreachable!(),
                 }).collect();
@@ -75,6 +107,7 @@ pub fn apply_divide(left: Value, right: Value) -> Result<Value, String> {
             if Value::is_numeric_vector(vec) {
                 let result: Vec<Value> = vec.iter().map(|v| match v {
                     Value::Number(n) => Value::Number(n / scalar),
+                    Value::Integer(n) => Value::Number(*n as f64 / scalar),
                     Value::Complex(c) => Value::Complex(*c / Complex::from_real(scalar)),
                     _ => unreachable!(),
                 }).collect();
@@ -84,11 +117,43 @@ pub fn apply_divide(left: Value, right: Value) -> Result<Value, String> {
             }
         }
 
+        // Broadcasting: Scalar / Vector (Integer scalar)
+        (Value::Integer(scalar), Value::Vector(ref vec)) => {
+            if Value::is_numeric_vector(vec) {
+                let result: Result<Vec<Value>, String> = vec.iter().map(|v| match v {
+                    Value::Number(n) => if *n == 0.0 { Err("Division by zero".to_string()) } else { Ok(Value::Number(scalar as f64 / n)) },
+                    Value::Integer(n) => if *n == 0 { Err("Division by zero".to_string()) } else { Ok(Value::Number(scalar as f64 / *n as f64)) },
+                    Value::Complex(c) => if c.re == 0.0 && c.im == 0.0 { Err("Division by zero".to_string()) } else { Ok(Value::Complex(Complex::from_real(scalar as f64) / *c)) },
+                    _ => unreachable!(),
+                }).collect();
+                result.map(Value::Vector)
+            } else {
+                Err("Broadcasting requires numeric vector".to_string())
+            }
+        }
+        (Value::Vector(ref vec), Value::Integer(scalar)) => {
+            if scalar == 0 {
+                return Err("Division by zero".to_string());
+            }
+            if Value::is_numeric_vector(vec) {
+                let result: Vec<Value> = vec.iter().map(|v| match v {
+                    Value::Number(n) => Value::Number(n / scalar as f64),
+                    Value::Integer(n) => Value::Number(*n as f64 / scalar as f64),
+                    Value::Complex(c) => Value::Complex(*c / Complex::from_real(scalar as f64)),
+                    _ => unreachable!(),
+                }).collect();
+                Ok(Value::Vector(result))
+            } else {
+                Err("Broadcasting requires numeric vector".to_string())
+            }
+        }
+
         // Broadcasting: Complex / Vector
         (Value::Complex(c), Value::Vector(ref vec)) => {
             if Value::is_numeric_vector(vec) {
                 let result: Result<Vec<Value>, String> = vec.iter().map(|v| match v {
                     Value::Number(n) => if *n == 0.0 { Err("Division by zero".to_string()) } else { Ok(Value::Complex(c / Complex::from_real(*n))) },
+                    Value::Integer(n) => if *n == 0 { Err("Division by zero".to_string()) } else { Ok(Value::Complex(c / Complex::from_real(*n as f64))) },
                     Value::Complex(cv) => if cv.re == 0.0 && cv.im == 0.0 { Err("Division by zero".to_string()) } else { Ok(Value::Complex(c / *cv)) },
                     _ => unreachable!(),
                 }).collect();
@@ -104,6 +169,7 @@ pub fn apply_divide(left: Value, right: Value) -> Result<Value, String> {
             if Value::is_numeric_vector(vec) {
                 let result: Vec<Value> = vec.iter().map(|v| match v {
                     Value::Number(n) => Value::Complex(Complex::from_real(*n) / c),
+                    Value::Integer(n) => Value::Complex(Complex::from_real(*n as f64) / c),
                     Value::Complex(cv) => Value::Complex(*cv / c),
                     _ => unreachable!(),
                 }).collect();
@@ -132,6 +198,25 @@ pub fn apply_divide(left: Value, right: Value) -> Result<Value, String> {
                 .map_err(|e| e.to_string())
         }
 
+        // Broadcasting: Tensor / Scalar (Integer scalar)
+        (Value::Tensor(t), Value::Integer(scalar)) => {
+            t.div_scalar(scalar as f64).map(Value::Tensor)
+        }
+        (Value::Integer(scalar), Value::Tensor(t)) => {
+            // scalar / tensor = scalar * (1 / tensor)
+            let scalar = scalar as f64;
+            let data: Vec<f64> = t.data().iter().map(|&x| {
+                if x == 0.0 {
+                    f64::INFINITY // Or could return error
+                } else {
+                    scalar / x
+                }
+            }).collect();
+            RealTensor::new(data, t.shape().to_vec())
+                .map(Value::Tensor)
+                .map_err(|e| e.to_string())
+        }
+
         // Broadcasting: Tensor / Complex
         (Value::Tensor(t), Value::Complex(c)) => {
             let ct = t.to_complex();
@@ -169,6 +254,25 @@ pub fn apply_divide(left: Value, right: Value) -> Result<Value, String> {
                 .map_err(|e| e.to_string())
         }
 
+        // Broadcasting: ComplexTensor / Scalar (Integer scalar)
+        (Value::ComplexTensor(ct), Value::Integer(scalar)) => {
+            ct.div_scalar(Complex::from_real(scalar as f64)).map(Value::ComplexTensor)
+        }
+        (Value::Integer(scalar), Value::ComplexTensor(ct)) => {
+            // scalar / tensor: element-wise scalar / each_element
+            let scalar = Complex::from_real(scalar as f64);
+            let data: Vec<Complex> = ct.data().iter().map(|c| {
+                if c.re == 0.0 && c.im == 0.0 {
+                    Complex::new(f64::INFINITY, 0.0)
+                } else {
+                    scalar / *c
+                }
+            }).collect();
+            ComplexTensor::new(data, ct.shape().to_vec())
+                .map(Value::ComplexTensor)
+                .map_err(|e| e.to_string())
+        }
+
         // Broadcasting: ComplexTensor / Complex
         (Value::ComplexTensor(ct), Value::Complex(c)) => {
             ct.div_scalar(c).map(Value::ComplexTensor)
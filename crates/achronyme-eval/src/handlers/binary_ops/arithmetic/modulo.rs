@@ -9,6 +9,30 @@ pub fn apply_modulo(left: Value, right: Value) -> Result<Value, String> {
                 Ok(Value::Number(a % b))
             }
         }
+
+        // Integer % Integer stays Integer; mixed with Number promotes to Number.
+        (Value::Integer(a), Value::Integer(b)) => {
+            if b == 0 {
+                Err("Modulo by zero".to_string())
+            } else {
+                Ok(Value::Integer(a % b))
+            }
+        }
+        (Value::Integer(a), Value::Number(b)) => {
+            if b == 0.0 {
+                Err("Modulo by zero".to_string())
+            } else {
+                Ok(Value::Number(a as f64 % b))
+            }
+        }
+        (Value::Number(a), Value::Integer(b)) => {
+            if b == 0 {
+                Err("Modulo by zero".to_string())
+            } else {
+                Ok(Value::Number(a % b as f64))
+            }
+        }
+
         _ => Err("Modulo operator currently only supports numbers".to_string()),
     }
 }
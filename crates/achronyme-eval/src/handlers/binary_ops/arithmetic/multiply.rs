@@ -1,4 +1,5 @@
 use achronyme_types::complex::Complex;
+use achronyme_types::dual::Dual;
 use achronyme_types::value::Value;
 
 pub fn apply_multiply(left: Value, right: Value) -> Result<Value, String> {
@@ -6,6 +7,22 @@ pub fn apply_multiply(left: Value, right: Value) -> Result<Value, String> {
         (Value::Number(a), Value::Number(b)) => Ok(Value::Number(a * b)),
         (Value::Complex(a), Value::Complex(b)) => Ok(Value::Complex(a * b)),
 
+        // Integer * Integer stays Integer; mixed with Number promotes to Number.
+        // Overflow falls back to Number rather than panicking (checked_mul).
+        (Value::Integer(a), Value::Integer(b)) => match a.checked_mul(b) {
+            Some(product) => Ok(Value::Integer(product)),
+            None => Ok(Value::Number(a as f64 * b as f64)),
+        },
+        (Value::Integer(a), Value::Number(b)) => Ok(Value::Number(a as f64 * b)),
+        (Value::Number(a), Value::Integer(b)) => Ok(Value::Number(a * b as f64)),
+        (Value::Integer(a), Value::Complex(b)) => Ok(Value::Complex(Complex::from_real(a as f64) * b)),
+        (Value::Complex(a), Value::Integer(b)) => Ok(Value::Complex(a * Complex::from_real(b as f64))),
+
+        // Dual numbers (automatic differentiation)
+        (Value::Dual(a), Value::Dual(b)) => Ok(Value::Dual(a * b)),
+        (Value::Dual(a), Value::Number(b)) => Ok(Value::Dual(a * Dual::constant(b))),
+        (Value::Number(a), Value::Dual(b)) => Ok(Value::Dual(Dual::constant(a) * b)),
+
         // Tensor support (optimized path)
         (Value::Tensor(a), Value::Tensor(b)) => {
             // If both are matrices, do matrix multiplication
@@ -63,6 +80,7 @@ pub fn apply_multiply(left: Value, right: Value) -> Result<Value, String> {
             if Value::is_numeric_vector(vec) {
                 let result: Vec<Value> = vec.iter().map(|v| match v {
                     Value::Number(n) => Value::Number(n * scalar),
+                    Value::Integer(n) => Value::Number(*n as f64 * scalar),
                     Value::Complex(c) => Value::Complex(*c * Complex::from_real(scalar)),
                     _ => unreachable!(),
                 }).collect();
@@ -75,6 +93,7 @@ pub fn apply_multiply(left: Value, right: Value) -> Result<Value, String> {
             if Value::is_numeric_vector(vec) {
                 let result: Vec<Value> = vec.iter().map(|v| match v {
                     Value::Number(n) => Value::Number(n * scalar),
+                    Value::Integer(n) => Value::Number(*n as f64 * scalar),
                     Value::Complex(c) => Value::Complex(*c * Complex::from_real(scalar)),
                     _ => unreachable!(),
                 }).collect();
@@ -84,11 +103,46 @@ pub fn apply_multiply(left: Value, right: Value) -> Result<Value, String> {
             }
         }
 
+        // Broadcasting: Scalar * Vector (Integer scalar)
+        (Value::Integer(scalar), Value::Vector(ref vec)) => {
+            if Value::is_numeric_vector(vec) {
+                let result: Vec<Value> = vec.iter().map(|v| match v {
+                    Value::Number(n) => Value::Number(scalar as f64 * n),
+                    Value::Integer(n) => match scalar.checked_mul(*n) {
+                        Some(product) => Value::Integer(product),
+                        None => Value::Number(scalar as f64 * *n as f64),
+                    },
+                    Value::Complex(c) => Value::Complex(Complex::from_real(scalar as f64) * *c),
+                    _ => unreachable!(),
+                }).collect();
+                Ok(Value::Vector(result))
+            } else {
+                Err("Broadcasting requires numeric vector".to_string())
+            }
+        }
+        (Value::Vector(ref vec), Value::Integer(scalar)) => {
+            if Value::is_numeric_vector(vec) {
+                let result: Vec<Value> = vec.iter().map(|v| match v {
+                    Value::Number(n) => Value::Number(n * scalar as f64),
+                    Value::Integer(n) => match n.checked_mul(scalar) {
+                        Some(product) => Value::Integer(product),
+                        None => Value::Number(*n as f64 * scalar as f64),
+                    },
+                    Value::Complex(c) => Value::Complex(*c * Complex::from_real(scalar as f64)),
+                    _ => unreachable!(),
+                }).collect();
+                Ok(Value::Vector(result))
+            } else {
+                Err("Broadcasting requires numeric vector".to_string())
+            }
+        }
+
         // Broadcasting: Complex * Vector
         (Value::Complex(c), Value::Vector(ref vec)) => {
             if Value::is_numeric_vector(vec) {
                 let result: Vec<Value> = vec.iter().map(|v| match v {
                     Value::Number(n) => Value::Complex(Complex::from_real(*n) * c),
+                    Value::Integer(n) => Value::Complex(Complex::from_real(*n as f64) * c),
                     Value::Complex(cv) => Value::Complex(*cv * c),
                     _ => unreachable!(),
                 }).collect();
@@ -101,6 +155,7 @@ pub fn apply_multiply(left: Value, right: Value) -> Result<Value, String> {
             if Value::is_numeric_vector(vec) {
                 let result: Vec<Value> = vec.iter().map(|v| match v {
                     Value::Number(n) => Value::Complex(Complex::from_real(*n) * c),
+                    Value::Integer(n) => Value::Complex(Complex::from_real(*n as f64) * c),
                     Value::Complex(cv) => Value::Complex(*cv * c),
                     _ => unreachable!(),
                 }).collect();
@@ -118,6 +173,14 @@ pub fn apply_multiply(left: Value, right: Value) -> Result<Value, String> {
             Ok(Value::Tensor(t.mul_scalar(scalar)))
         }
 
+        // Broadcasting: Tensor * Scalar (Integer scalar)
+        (Value::Tensor(t), Value::Integer(scalar)) => {
+            Ok(Value::Tensor(t.mul_scalar(scalar as f64)))
+        }
+        (Value::Integer(scalar), Value::Tensor(t)) => {
+            Ok(Value::Tensor(t.mul_scalar(scalar as f64)))
+        }
+
         // Broadcasting: Tensor * Complex
         (Value::Tensor(t), Value::Complex(c)) => {
             let ct = t.to_complex();
@@ -136,6 +199,14 @@ pub fn apply_multiply(left: Value, right: Value) -> Result<Value, String> {
             Ok(Value::ComplexTensor(ct.mul_scalar(Complex::from_real(scalar))))
         }
 
+        // Broadcasting: ComplexTensor * Scalar (Integer scalar)
+        (Value::ComplexTensor(ct), Value::Integer(scalar)) => {
+            Ok(Value::ComplexTensor(ct.mul_scalar(Complex::from_real(scalar as f64))))
+        }
+        (Value::Integer(scalar), Value::ComplexTensor(ct)) => {
+            Ok(Value::ComplexTensor(ct.mul_scalar(Complex::from_real(scalar as f64))))
+        }
+
         // Broadcasting: ComplexTensor * Complex
         (Value::ComplexTensor(ct), Value::Complex(c)) => {
             Ok(Value::ComplexTensor(ct.mul_scalar(c)))
@@ -166,6 +237,22 @@ pub fn apply_multiply(left: Value, right: Value) -> Result<Value, String> {
             }
         }
 
+        // String broadcasting: String * Integer (repetition)
+        (Value::String(s), Value::Integer(n)) => {
+            if n < 0 {
+                Err("String repetition count must be non-negative".to_string())
+            } else {
+                Ok(Value::String(s.repeat(n as usize)))
+            }
+        }
+        (Value::Integer(n), Value::String(s)) => {
+            if n < 0 {
+                Err("String repetition count must be non-negative".to_string())
+            } else {
+                Ok(Value::String(s.repeat(n as usize)))
+            }
+        }
+
         _ => Err("Incompatible types for multiplication".to_string()),
     }
 }
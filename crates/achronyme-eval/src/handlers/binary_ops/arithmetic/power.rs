@@ -10,11 +10,37 @@ pub fn apply_power(left: Value, right: Value) -> Result<Value, String> {
             Ok(Value::Complex(Complex::from_real(a).pow_complex(&b)))
         }
 
+        // Integer ^ Integer stays Integer for non-negative exponents (closed over the
+        // integers); a negative exponent promotes to Number since the result isn't whole.
+        // An exponent too large to fit a u32, or a result that overflows i64, falls back
+        // to Number instead of panicking (checked_pow).
+        (Value::Integer(a), Value::Integer(b)) => {
+            if b >= 0 {
+                match u32::try_from(b).ok().and_then(|exp| a.checked_pow(exp)) {
+                    Some(result) => Ok(Value::Integer(result)),
+                    None => Ok(Value::Number((a as f64).powf(b as f64))),
+                }
+            } else {
+                Ok(Value::Number((a as f64).powf(b as f64)))
+            }
+        }
+        (Value::Integer(a), Value::Number(b)) => Ok(Value::Number((a as f64).powf(b))),
+        (Value::Number(a), Value::Integer(b)) => Ok(Value::Number(a.powf(b as f64))),
+        (Value::Complex(a), Value::Integer(b)) => Ok(Value::Complex(a.pow(b as f64))),
+        (Value::Integer(a), Value::Complex(b)) => {
+            Ok(Value::Complex(Complex::from_real(a as f64).pow_complex(&b)))
+        }
+
+        // Dual numbers (automatic differentiation): only a constant exponent is
+        // supported, since d/dx[f(x)^g(x)] needs a second chain-rule term we don't track.
+        (Value::Dual(a), Value::Number(b)) => Ok(Value::Dual(a.powf(b))),
+
         // Broadcasting: Vector ^ Scalar
         (Value::Vector(ref vec), Value::Number(scalar)) => {
             if Value::is_numeric_vector(vec) {
                 let result: Vec<Value> = vec.iter().map(|v| match v {
                     Value::Number(n) => Value::Number(n.powf(scalar)),
+                    Value::Integer(n) => Value::Number((*n as f64).powf(scalar)),
                     Value::Complex(c) => Value::Complex(c.pow(scalar)),
                     _ => unreachable!(),
                 }).collect();
@@ -27,6 +53,7 @@ pub fn apply_power(left: Value, right: Value) -> Result<Value, String> {
             if Value::is_numeric_vector(vec) {
                 let result: Vec<Value> = vec.iter().map(|v| match v {
                     Value::Number(n) => Value::Number(scalar.powf(*n)),
+                    Value::Integer(n) => Value::Number(scalar.powf(*n as f64)),
                     Value::Complex(c) => Value::Complex(Complex::from_real(scalar).pow_complex(c)),
                     _ => unreachable!(),
                 }).collect();
@@ -36,11 +63,40 @@ pub fn apply_power(left: Value, right: Value) -> Result<Value, String> {
             }
         }
 
+        // Broadcasting: Vector ^ Scalar (Integer scalar)
+        (Value::Vector(ref vec), Value::Integer(scalar)) => {
+            if Value::is_numeric_vector(vec) {
+                let result: Vec<Value> = vec.iter().map(|v| match v {
+                    Value::Number(n) => Value::Number(n.powf(scalar as f64)),
+                    Value::Integer(n) => Value::Number((*n as f64).powf(scalar as f64)),
+                    Value::Complex(c) => Value::Complex(c.pow(scalar as f64)),
+                    _ => unreachable!(),
+                }).collect();
+                Ok(Value::Vector(result))
+            } else {
+                Err("Broadcasting requires numeric vector".to_string())
+            }
+        }
+        (Value::Integer(scalar), Value::Vector(ref vec)) => {
+            if Value::is_numeric_vector(vec) {
+                let result: Vec<Value> = vec.iter().map(|v| match v {
+                    Value::Number(n) => Value::Number((scalar as f64).powf(*n)),
+                    Value::Integer(n) => Value::Number((scalar as f64).powf(*n as f64)),
+                    Value::Complex(c) => Value::Complex(Complex::from_real(scalar as f64).pow_complex(c)),
+                    _ => unreachable!(),
+                }).collect();
+                Ok(Value::Vector(result))
+            } else {
+                Err("Broadcasting requires numeric vector".to_string())
+            }
+        }
+
         // Broadcasting: Vector ^ Complex
         (Value::Vector(ref vec), Value::Complex(c)) => {
             if Value::is_numeric_vector(vec) {
                 let result: Vec<Value> = vec.iter().map(|v| match v {
                     Value::Number(n) => Value::Complex(Complex::from_real(*n).pow_complex(&c)),
+                    Value::Integer(n) => Value::Complex(Complex::from_real(*n as f64).pow_complex(&c)),
                     Value::Complex(cv) => Value::Complex(cv.pow_complex(&c)),
                     _ => unreachable!(),
                 }).collect();
@@ -53,6 +109,7 @@ pub fn apply_power(left: Value, right: Value) -> Result<Value, String> {
             if Value::is_numeric_vector(vec) {
                 let result: Vec<Value> = vec.iter().map(|v| match v {
                     Value::Number(n) => Value::Complex(c.pow(*n)),
+                    Value::Integer(n) => Value::Complex(c.pow(*n as f64)),
                     Value::Complex(cv) => Value::Complex(c.pow_complex(cv)),
                     _ => unreachable!(),
                 }).collect();
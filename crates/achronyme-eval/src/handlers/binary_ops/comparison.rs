@@ -1,30 +1,40 @@
 use achronyme_types::value::Value;
 
+/// Widen `Number`/`Integer` to `f64` for ordering comparisons; anything else
+/// is left for the caller's `_` arm.
+fn as_ordered_f64(v: &Value) -> Option<f64> {
+    match v {
+        Value::Number(n) => Some(*n),
+        Value::Integer(n) => Some(*n as f64),
+        _ => None,
+    }
+}
+
 // Comparison operators (return boolean values)
 pub fn apply_gt(left: Value, right: Value) -> Result<Value, String> {
-    match (left, right) {
-        (Value::Number(a), Value::Number(b)) => Ok(Value::Boolean(a > b)),
+    match (as_ordered_f64(&left), as_ordered_f64(&right)) {
+        (Some(a), Some(b)) => Ok(Value::Boolean(a > b)),
         _ => Err("Comparison operators currently only support numbers".to_string()),
     }
 }
 
 pub fn apply_lt(left: Value, right: Value) -> Result<Value, String> {
-    match (left, right) {
-        (Value::Number(a), Value::Number(b)) => Ok(Value::Boolean(a < b)),
+    match (as_ordered_f64(&left), as_ordered_f64(&right)) {
+        (Some(a), Some(b)) => Ok(Value::Boolean(a < b)),
         _ => Err("Comparison operators currently only support numbers".to_string()),
     }
 }
 
 pub fn apply_gte(left: Value, right: Value) -> Result<Value, String> {
-    match (left, right) {
-        (Value::Number(a), Value::Number(b)) => Ok(Value::Boolean(a >= b)),
+    match (as_ordered_f64(&left), as_ordered_f64(&right)) {
+        (Some(a), Some(b)) => Ok(Value::Boolean(a >= b)),
         _ => Err("Comparison operators currently only support numbers".to_string()),
     }
 }
 
 pub fn apply_lte(left: Value, right: Value) -> Result<Value, String> {
-    match (left, right) {
-        (Value::Number(a), Value::Number(b)) => Ok(Value::Boolean(a <= b)),
+    match (as_ordered_f64(&left), as_ordered_f64(&right)) {
+        (Some(a), Some(b)) => Ok(Value::Boolean(a <= b)),
         _ => Err("Comparison operators currently only support numbers".to_string()),
     }
 }
@@ -32,6 +42,10 @@ pub fn apply_lte(left: Value, right: Value) -> Result<Value, String> {
 pub fn apply_eq(left: Value, right: Value) -> Result<Value, String> {
     match (left, right) {
         (Value::Number(a), Value::Number(b)) => Ok(Value::Boolean(a == b)),
+        (Value::Integer(a), Value::Integer(b)) => Ok(Value::Boolean(a == b)),
+        (Value::Integer(a), Value::Number(b)) | (Value::Number(b), Value::Integer(a)) => {
+            Ok(Value::Boolean(a as f64 == b))
+        }
         (Value::Boolean(a), Value::Boolean(b)) => Ok(Value::Boolean(a == b)),
         (Value::String(a), Value::String(b)) => Ok(Value::Boolean(a == b)),
         _ => Err("Comparison operators support numbers, booleans, and strings".to_string()),
@@ -41,6 +55,10 @@ pub fn apply_eq(left: Value, right: Value) -> Result<Value, String> {
 pub fn apply_neq(left: Value, right: Value) -> Result<Value, String> {
     match (left, right) {
         (Value::Number(a), Value::Number(b)) => Ok(Value::Boolean(a != b)),
+        (Value::Integer(a), Value::Integer(b)) => Ok(Value::Boolean(a != b)),
+        (Value::Integer(a), Value::Number(b)) | (Value::Number(b), Value::Integer(a)) => {
+            Ok(Value::Boolean(a as f64 != b))
+        }
         (Value::Boolean(a), Value::Boolean(b)) => Ok(Value::Boolean(a != b)),
         (Value::String(a), Value::String(b)) => Ok(Value::Boolean(a != b)),
         _ => Err("Comparison operators support numbers, booleans, and strings".to_string()),
@@ -11,6 +11,7 @@ pub fn value_to_string(value: &Value) -> String {
                 n.to_string()
             }
         }
+        Value::Integer(n) => n.to_string(),
         Value::Boolean(b) => b.to_string(),
         Value::String(s) => s.clone(),
         Value::Complex(c) => {
@@ -40,6 +41,7 @@ pub fn value_to_string(value: &Value) -> String {
             format!("{}{}{}", from, arrow, to)
         }
         Value::TailCall(_) => "<tail-call>".to_string(),
+        Value::Dual(_) => "<dual>".to_string(),
         Value::EarlyReturn(_) => "<early-return>".to_string(),
         Value::MutableRef(r) => {
             let borrowed = r.borrow();
@@ -93,6 +93,7 @@ fn value_to_bool(value: &Value) -> Result<bool, String> {
     match value {
         Value::Boolean(b) => Ok(*b),
         Value::Number(n) => Ok(*n != 0.0),
+        Value::Integer(n) => Ok(*n != 0),
         _ => Err(format!("Cannot convert {:?} to boolean", value)),
     }
 }
@@ -2,6 +2,7 @@ use achronyme_parser::ast::AstNode;
 use achronyme_types::value::Value;
 use achronyme_types::function::Function;
 use crate::evaluator::Evaluator;
+use crate::type_checker;
 
 /// Handle the describe() function - returns a detailed string description of a value
 pub fn handle_describe(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value, String> {
@@ -15,11 +16,28 @@ pub fn handle_describe(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Va
     Ok(Value::String(description))
 }
 
+/// Handle the infer_types() function - runs the Hindley-Milner inference pass
+/// (`type_checker::infer_program`) over an unevaluated expression and returns
+/// the type it resolved to, so an unannotated lambda's parameter/return types
+/// can be inspected without writing them out by hand.
+///
+/// The argument is never evaluated - inference runs over the AST alone, the
+/// same way `check_node`/`infer_node` do for declared annotations.
+pub fn handle_infer_types(_evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value, String> {
+    if args.len() != 1 {
+        return Err(format!("infer_types() expects 1 argument, got {}", args.len()));
+    }
+
+    let inferred = type_checker::infer_program(&args[0..1])?;
+    Ok(Value::String(type_checker::type_annotation_to_string(&inferred[0])))
+}
+
 fn describe_value(value: &Value, indent: usize) -> String {
     let indent_str = "  ".repeat(indent);
 
     match value {
         Value::Number(n) => format!("Number({})", n),
+        Value::Integer(n) => format!("Integer({})", n),
         Value::Boolean(b) => format!("Boolean({})", b),
         Value::String(s) => format!("String({:?})", s),
         Value::Complex(c) => format!("Complex(re: {}, im: {})", c.re, c.im),
@@ -92,6 +110,11 @@ fn describe_value(value: &Value, indent: usize) -> String {
             "TailCall(internal marker - should not be visible)".to_string()
         }
 
+        Value::Dual(_) => {
+            // Dual should never be visible to user code - it's an internal autodiff marker
+            "Dual(internal marker - should not be visible)".to_string()
+        }
+
         Value::EarlyReturn(_) => {
             // EarlyReturn should never be visible to user code - it's an internal marker
             "EarlyReturn(internal marker - should not be visible)".to_string()
@@ -140,6 +163,14 @@ fn describe_function(func: &Function, indent: usize) -> String {
         Function::Builtin(name) => {
             format!("Function(Builtin: {})", name)
         }
+        Function::Partial { func, applied_args, total_arity } => {
+            format!(
+                "Function(Partial: {}/{} args applied to {})",
+                applied_args.len(),
+                total_arity,
+                describe_function(func, indent)
+            )
+        }
     }
 }
 
@@ -92,7 +92,9 @@ pub fn dispatch(evaluator: &mut Evaluator, name: &str, args: &[AstNode]) -> Resu
         "map" => return super::hof::handle_map(evaluator, args),
         "filter" => return super::hof::handle_filter(evaluator, args),
         "reduce" => return super::hof::handle_reduce(evaluator, args),
+        "scan" => return super::hof::handle_scan(evaluator, args),
         "pipe" => return super::hof::handle_pipe(evaluator, args),
+        "converge" => return super::hof::handle_converge(evaluator, args),
         // Tier 2 predicates
         "any" => return super::hof::handle_any(evaluator, args),
         "all" => return super::hof::handle_all(evaluator, args),
@@ -102,25 +104,44 @@ pub fn dispatch(evaluator: &mut Evaluator, name: &str, args: &[AstNode]) -> Resu
         _ => {}
     }
 
-    // Check for numerical calculus functions (need evaluator access for lambdas)
-    match name {
-        "diff" => return super::numerical::handle_diff(evaluator, args),
-        "diff2" => return super::numerical::handle_diff2(evaluator, args),
-        "diff3" => return super::numerical::handle_diff3(evaluator, args),
-        "gradient" => return super::numerical::handle_gradient(evaluator, args),
-        "integral" | "trapz" => return super::numerical::handle_integral(evaluator, args),
-        "simpson" => return super::numerical::handle_simpson(evaluator, args),
-        "romberg" => return super::numerical::handle_romberg(evaluator, args),
-        "quad" => return super::numerical::handle_quad(evaluator, args),
-        "solve" | "bisect" => return super::numerical::handle_solve(evaluator, args),
-        "newton" => return super::numerical::handle_newton(evaluator, args),
-        "secant" => return super::numerical::handle_secant(evaluator, args),
-        _ => {}
+    // Check for numerical calculus functions (need evaluator access for lambdas);
+    // validated/dispatched through the declarative BuiltinRegistry in `numerical`.
+    if let Some(result) = super::numerical::dispatch(evaluator, name, args) {
+        return result;
+    }
+
+    // Check for host-registered native functions (embedder callbacks)
+    if let Some((func, expected_arity)) = evaluator.native_function(name) {
+        let mut arg_values = Vec::new();
+        for arg in args {
+            arg_values.push(evaluator.evaluate(arg)?);
+        }
+
+        if expected_arity >= 0 && arg_values.len() < expected_arity as usize {
+            use achronyme_types::function::Function;
+            return Ok(Value::Function(Function::partial(
+                Function::builtin(name.to_string()),
+                arg_values,
+                expected_arity as usize,
+            )));
+        }
+
+        if expected_arity >= 0 && arg_values.len() != expected_arity as usize {
+            return Err(format!(
+                "Function {} expects {} arguments, got {}",
+                name,
+                expected_arity,
+                arg_values.len()
+            ));
+        }
+
+        return func(&arg_values);
     }
 
     // Check for debug functions
     match name {
         "describe" => return super::debug::handle_describe(evaluator, args),
+        "infer_types" => return super::debug::handle_infer_types(evaluator, args),
         _ => {}
     }
 
@@ -161,6 +182,17 @@ pub fn dispatch(evaluator: &mut Evaluator, name: &str, args: &[AstNode]) -> Resu
         arg_values.push(evaluator.evaluate(arg)?);
     }
 
+    // Fewer args than a known (non-variadic) arity: curry instead of erroring,
+    // same as calling a `Function::Builtin` value through `apply_lambda`.
+    if expected_arity >= 0 && arg_values.len() < expected_arity as usize {
+        use achronyme_types::function::Function;
+        return Ok(Value::Function(Function::partial(
+            Function::builtin(name.to_string()),
+            arg_values,
+            expected_arity as usize,
+        )));
+    }
+
     // Check arity (if not variadic)
     if expected_arity >= 0 && arg_values.len() != expected_arity as usize {
         return Err(format!(
@@ -33,6 +33,11 @@ pub fn apply_lambda(
 ) -> Result<Value, String> {
     match function {
         Function::UserDefined { params, param_types, return_type, body, closure_env } => {
+            // Fewer args than declared params: curry instead of erroring.
+            if args.len() < params.len() {
+                return Ok(Value::Function(Function::partial(function.clone(), args, params.len())));
+            }
+
             // Check arity
             if args.len() != params.len() {
                 return Err(format!(
@@ -42,16 +47,19 @@ pub fn apply_lambda(
                 ));
             }
 
-            // Type check arguments
+            // Type check arguments, sharing one dimension environment across
+            // them so named tensor shape variables (e.g. `N` in `matmul:
+            // (Tensor[N,M], Tensor[M,K]) -> Tensor[N,K]`) must agree across
+            // every parameter, not just within a single tensor.
+            let mut dims = crate::type_checker::DimEnv::new();
             for (i, (arg, param_type)) in args.iter().zip(param_types.iter()).enumerate() {
                 if let Some(expected_type) = param_type {
-                    crate::type_checker::check_type(arg, expected_type)
-                        .map_err(|_| format!(
-                            "Type error: argument {} (parameter '{}') expected {}, got {}",
+                    crate::type_checker::check_type_detailed_with_dims(arg, expected_type, &mut dims)
+                        .map_err(|e| format!(
+                            "Type error: argument {} (parameter '{}'): {}",
                             i + 1,
                             params[i],
-                            expected_type.to_string(),
-                            crate::type_checker::infer_type(arg).to_string()
+                            e
                         ))?;
                 }
             }
@@ -68,14 +76,12 @@ pub fn apply_lambda(
                 apply_lambda_regular(evaluator, params, param_types, return_type, body, closure_env, args)
             }?;
 
-            // Type check return value
+            // Type check return value, still tied to the same dimension
+            // environment so e.g. a declared `-> Tensor[N]` return must
+            // agree with whatever `N` the arguments already pinned down.
             if let Some(expected_return) = return_type {
-                crate::type_checker::check_type(&result, expected_return)
-                    .map_err(|_| format!(
-                        "Type error: function return type expected {}, got {}",
-                        expected_return.to_string(),
-                        crate::type_checker::infer_type(&result).to_string()
-                    ))?;
+                crate::type_checker::check_type_detailed_with_dims(&result, expected_return, &mut dims)
+                    .map_err(|e| format!("Type error: function return type: {}", e))?;
             }
 
             Ok(result)
@@ -92,6 +98,11 @@ pub fn apply_lambda(
             };
 
             if let Some((func, expected_arity)) = function_info {
+                // Fewer args than a known (non-variadic) arity: curry instead of erroring.
+                if expected_arity >= 0 && args.len() < expected_arity as usize {
+                    return Ok(Value::Function(Function::partial(function.clone(), args, expected_arity as usize)));
+                }
+
                 // Check arity (if not variadic)
                 if expected_arity >= 0 && args.len() != expected_arity as usize {
                     return Err(format!(
@@ -104,10 +115,45 @@ pub fn apply_lambda(
 
                 // Call the builtin function directly with the evaluated arguments
                 func(&args, evaluator.environment_mut())
+            } else if let Some((native_func, expected_arity)) = evaluator.native_function(name) {
+                // Host-registered native function, e.g. reached via
+                // `map(my_native_fn, xs)` where `my_native_fn` is a variable
+                // holding `Function::Builtin`. Same arity validation path as
+                // ordinary built-ins above.
+                if expected_arity >= 0 && args.len() < expected_arity as usize {
+                    return Ok(Value::Function(Function::partial(function.clone(), args, expected_arity as usize)));
+                }
+                if expected_arity >= 0 && args.len() != expected_arity as usize {
+                    return Err(format!(
+                        "Function {} expects {} arguments, got {}",
+                        name,
+                        expected_arity,
+                        args.len()
+                    ));
+                }
+
+                native_func(&args)
             } else {
                 Err(format!("Unknown built-in function: {}", name))
             }
         }
+        Function::Partial { func, applied_args, total_arity } => {
+            let mut combined = applied_args.clone();
+            combined.extend(args);
+
+            if combined.len() < *total_arity {
+                return Ok(Value::Function(Function::partial((**func).clone(), combined, *total_arity)));
+            }
+            if combined.len() > *total_arity {
+                return Err(format!(
+                    "Function expects {} arguments, got {}",
+                    total_arity,
+                    combined.len()
+                ));
+            }
+
+            apply_lambda(evaluator, func, combined)
+        }
     }
 }
 
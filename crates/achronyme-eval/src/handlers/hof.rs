@@ -6,7 +6,7 @@ use crate::evaluator::Evaluator;
 /// Higher-Order Functions Handler
 ///
 /// This module contains implementations of:
-/// - map, filter, reduce, pipe (original HOFs)
+/// - map, filter, reduce, pipe, converge (original HOFs)
 /// - any, all, find, findIndex, count (Tier 2 predicates)
 
 /// Helper: Convert a collection (Vector, Tensor, or ComplexTensor) to Vec<Value>
@@ -117,6 +117,7 @@ pub fn handle_filter(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Valu
         let should_include = match result {
             Value::Boolean(b) => b,
             Value::Number(n) => n != 0.0,
+            Value::Integer(n) => n != 0,
             _ => return Err("filter predicate must return a boolean or number".to_string()),
         };
 
@@ -165,6 +166,142 @@ pub fn handle_reduce(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Valu
     Ok(accumulator)
 }
 
+/// scan(f, init, collection) - Reduce, keeping every intermediate accumulator
+///
+/// Applies f(accumulator, element) repeatedly like `reduce`, but returns the
+/// vector of all accumulator states instead of just the final one, starting
+/// with `init` itself. So `scan` always returns a vector of length
+/// `collection.len() + 1`, e.g. an empty collection yields `[init]`.
+pub fn handle_scan(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value, String> {
+    if args.len() != 3 {
+        return Err("scan requires 3 arguments: function, initial value, and collection".to_string());
+    }
+
+    // Evaluate first argument (must be a function)
+    let func_value = evaluator.evaluate(&args[0])?;
+    let func = match func_value {
+        Value::Function(f) => f,
+        _ => return Err("First argument to scan must be a function".to_string()),
+    };
+
+    // Function must be binary
+    if func.arity() != 2 {
+        return Err("scan function must take exactly 2 arguments".to_string());
+    }
+
+    // Evaluate second argument (initial value)
+    let mut accumulator = evaluator.evaluate(&args[1])?;
+
+    // Evaluate third argument (collection)
+    let collection_value = evaluator.evaluate(&args[2])?;
+    let collection = collection_to_vec(collection_value)?;
+
+    let mut states = Vec::with_capacity(collection.len() + 1);
+    states.push(accumulator.clone());
+
+    for elem in collection {
+        accumulator = evaluator.apply_lambda(&func, vec![accumulator, elem])?;
+        states.push(accumulator.clone());
+    }
+
+    Ok(Value::Vector(states))
+}
+
+/// converge(f, x0, tol?, maxiter?) - Iterate a function to its fixed point
+///
+/// Starting from `x0`, repeatedly computes `x_{n+1} = f(x_n)` until
+/// `|x_{n+1} - x_n| <= tol` (default `1e-10`) or `maxiter` (default `1000`)
+/// iterations is reached, returning the last iterate. For a vector iterate,
+/// the comparison uses the max-norm of the componentwise difference.
+///
+/// `f` must be unary, the same arity check `pipe` applies to each stage.
+///
+/// Examples:
+/// - converge(x => (x + 2/x)/2, 1) => sqrt(2) (Newton's method)
+pub fn handle_converge(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value, String> {
+    if args.len() < 2 || args.len() > 4 {
+        return Err("converge requires 2 to 4 arguments: function, initial value, tolerance?, and max iterations?".to_string());
+    }
+
+    // Evaluate first argument (must be a function)
+    let func_value = evaluator.evaluate(&args[0])?;
+    let func = match func_value {
+        Value::Function(f) => f,
+        _ => return Err("First argument to converge must be a function".to_string()),
+    };
+
+    // Function must be unary
+    if func.arity() != 1 {
+        return Err("converge function must take exactly 1 argument".to_string());
+    }
+
+    // Evaluate second argument (initial value)
+    let mut x = evaluator.evaluate(&args[1])?;
+
+    let tol = if let Some(tol_expr) = args.get(2) {
+        match evaluator.evaluate(tol_expr)? {
+            Value::Number(n) => n,
+            Value::Integer(n) => n as f64,
+            _ => return Err("converge tolerance must be a number".to_string()),
+        }
+    } else {
+        1e-10
+    };
+
+    let maxiter = if let Some(maxiter_expr) = args.get(3) {
+        match evaluator.evaluate(maxiter_expr)? {
+            Value::Number(n) => n as usize,
+            Value::Integer(n) => {
+                if n < 0 {
+                    return Err("converge max iterations must be non-negative".to_string());
+                }
+                n as usize
+            }
+            _ => return Err("converge max iterations must be a number".to_string()),
+        }
+    } else {
+        1000
+    };
+
+    for _ in 0..maxiter {
+        let next = evaluator.apply_lambda(&func, vec![x.clone()])?;
+        let diff = converge_max_norm_diff(&next, &x)?;
+        if !diff.is_finite() {
+            return Err("converge: iteration diverged to NaN or Infinity".to_string());
+        }
+        x = next;
+        if diff <= tol {
+            return Ok(x);
+        }
+    }
+
+    Ok(x)
+}
+
+/// Max-norm of the componentwise difference between two iterates, recursing
+/// into nested vectors so `converge` can compare vector-valued iterates the
+/// same way it compares scalars.
+fn converge_max_norm_diff(a: &Value, b: &Value) -> Result<f64, String> {
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => Ok((x - y).abs()),
+        (Value::Integer(x), Value::Integer(y)) => Ok((*x as f64 - *y as f64).abs()),
+        (Value::Integer(x), Value::Number(y)) | (Value::Number(y), Value::Integer(x)) => {
+            Ok((*x as f64 - y).abs())
+        }
+        (Value::Vector(xs), Value::Vector(ys)) => {
+            if xs.len() != ys.len() {
+                return Err("converge: function changed the iterate's length".to_string());
+            }
+            let mut max_diff: f64 = 0.0;
+            for (xi, yi) in xs.iter().zip(ys.iter()) {
+                max_diff = max_diff.max(converge_max_norm_diff(xi, yi)?);
+            }
+            Ok(max_diff)
+        }
+        _ => Err("converge: function must return the same numeric type it was given (Number or Vector<Number>)".to_string()),
+    }
+}
+
 /// pipe(value, f1, f2, ...) - Apply functions left-to-right
 ///
 /// pipe(x, f, g, h) = h(g(f(x)))
@@ -244,6 +381,11 @@ pub fn handle_any(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value,
                     return Ok(Value::Boolean(true));
                 }
             }
+            Value::Integer(n) => {
+                if n != 0 {
+                    return Ok(Value::Boolean(true));
+                }
+            }
             _ => return Err("Predicate must return boolean or number".to_string()),
         }
     }
@@ -294,6 +436,11 @@ pub fn handle_all(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value,
                     return Ok(Value::Boolean(false));
                 }
             }
+            Value::Integer(n) => {
+                if n == 0 {
+                    return Ok(Value::Boolean(false));
+                }
+            }
             _ => return Err("Predicate must return boolean or number".to_string()),
         }
     }
@@ -341,6 +488,11 @@ pub fn handle_find(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value,
                     return Ok(item);
                 }
             }
+            Value::Integer(n) => {
+                if n != 0 {
+                    return Ok(item);
+                }
+            }
             _ => return Err("Predicate must return boolean or number".to_string()),
         }
     }
@@ -388,6 +540,11 @@ pub fn handle_find_index(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<
                     return Ok(Value::Number(index as f64));
                 }
             }
+            Value::Integer(n) => {
+                if n != 0 {
+                    return Ok(Value::Number(index as f64));
+                }
+            }
             _ => return Err("Predicate must return boolean or number".to_string()),
         }
     }
@@ -436,6 +593,11 @@ pub fn handle_count(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value
                     count += 1;
                 }
             }
+            Value::Integer(n) => {
+                if n != 0 {
+                    count += 1;
+                }
+            }
             _ => return Err("Predicate must return boolean or number".to_string()),
         }
     }
@@ -50,6 +50,7 @@ fn evaluate_index_arg(
                     let idx = n as isize;
                     Ok(EvaluatedIndex::Single(idx))
                 }
+                Value::Integer(n) => Ok(EvaluatedIndex::Single(n as isize)),
                 _ => Err("Index must be a number".to_string()),
             }
         }
@@ -58,6 +59,7 @@ fn evaluate_index_arg(
                 let value = evaluator.evaluate(start_expr)?;
                 match value {
                     Value::Number(n) => Some(n as isize),
+                    Value::Integer(n) => Some(n as isize),
                     _ => return Err("Range start must be a number".to_string()),
                 }
             } else {
@@ -68,6 +70,7 @@ fn evaluate_index_arg(
                 let value = evaluator.evaluate(end_expr)?;
                 match value {
                     Value::Number(n) => Some(n as isize),
+                    Value::Integer(n) => Some(n as isize),
                     _ => return Err("Range end must be a number".to_string()),
                 }
             } else {
@@ -10,6 +10,11 @@ pub fn evaluate_number(n: f64) -> Result<Value, String> {
     Ok(Value::Number(n))
 }
 
+/// Evaluate an integer literal
+pub fn evaluate_integer(n: i64) -> Result<Value, String> {
+    Ok(Value::Integer(n))
+}
+
 /// Evaluate a boolean literal
 pub fn evaluate_boolean(b: bool) -> Result<Value, String> {
     Ok(Value::Boolean(b))
@@ -132,14 +137,15 @@ fn validate_and_promote_vector(values: Vec<Value>) -> Result<Value, String> {
     // Apply type promotion for numeric types if applicable
     let has_complex = values.iter().any(|v| matches!(v, Value::Complex(_)));
 
-    // Only apply numeric promotion if ALL elements are numeric (Number or Complex)
-    let all_numeric = values.iter().all(|v| matches!(v, Value::Number(_) | Value::Complex(_)));
+    // Only apply numeric promotion if ALL elements are numeric (Number, Integer, or Complex)
+    let all_numeric = values.iter().all(|v| matches!(v, Value::Number(_) | Value::Integer(_) | Value::Complex(_)));
 
     if all_numeric && has_complex {
         // Promote all numbers to complex for consistency
         let promoted: Vec<Value> = values.into_iter()
             .map(|v| match v {
                 Value::Number(n) => Value::Complex(Complex::new(n, 0.0)),
+                Value::Integer(n) => Value::Complex(Complex::new(n as f64, 0.0)),
                 v => v,
             })
             .collect();
@@ -1,357 +1,342 @@
+use std::collections::HashMap;
+
 use achronyme_parser::ast::AstNode;
 use achronyme_types::value::Value;
 
 use crate::evaluator::Evaluator;
+use crate::signature::{Arg, BuiltinRegistry, BuiltinSignature, ParamKind, ParamSpec};
 
 /// Numerical Calculus Handler
 ///
 /// This module contains implementations of numerical differentiation,
-/// integration, and root-finding methods.
-
-/// Numerical first derivative: diff(f, x, h)
-pub fn handle_diff(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value, String> {
-    if args.len() != 3 {
-        return Err("diff() requires 3 arguments: function, x, h".to_string());
-    }
-
-    let func_value = evaluator.evaluate(&args[0])?;
-    let func = match func_value {
-        Value::Function(f) => f,
-        _ => return Err("diff() requires a function as first argument".to_string()),
-    };
-
-    let x = match evaluator.evaluate(&args[1])? {
-        Value::Number(n) => n,
-        _ => return Err("diff() requires a number for x".to_string()),
-    };
-
-    let h = match evaluator.evaluate(&args[2])? {
-        Value::Number(n) => n,
-        _ => return Err("diff() requires a number for h".to_string()),
-    };
+/// integration, and root-finding methods. Each builtin declares a
+/// `BuiltinSignature` up front; `dispatch` validates and coerces the raw
+/// `AstNode` arguments against it before handing already-typed `Arg`s to the
+/// implementation, so the implementations below only contain the actual
+/// numerical method.
+
+fn diff_sig() -> BuiltinSignature {
+    BuiltinSignature::new(
+        "diff",
+        vec![
+            ParamSpec::new("f", ParamKind::Function),
+            ParamSpec::new("x", ParamKind::Number),
+            ParamSpec::new("h", ParamKind::Number),
+        ],
+    )
+}
 
+fn diff_impl(evaluator: &mut Evaluator, args: &[Arg]) -> Result<Value, String> {
     use achronyme_numerical::diff_central;
-    let result = diff_central(evaluator, &func, x, h)?;
+    let result = diff_central(evaluator, args[0].function(), args[1].number(), args[2].number())?;
     Ok(Value::Number(result))
 }
 
-/// Numerical second derivative: diff2(f, x, h)
-pub fn handle_diff2(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value, String> {
-    if args.len() != 3 {
-        return Err("diff2() requires 3 arguments: function, x, h".to_string());
-    }
-
-    let func_value = evaluator.evaluate(&args[0])?;
-    let func = match func_value {
-        Value::Function(f) => f,
-        _ => return Err("diff2() requires a function as first argument".to_string()),
-    };
-
-    let x = match evaluator.evaluate(&args[1])? {
-        Value::Number(n) => n,
-        _ => return Err("diff2() requires a number for x".to_string()),
-    };
-
-    let h = match evaluator.evaluate(&args[2])? {
-        Value::Number(n) => n,
-        _ => return Err("diff2() requires a number for h".to_string()),
-    };
-
+fn diff2_impl(evaluator: &mut Evaluator, args: &[Arg]) -> Result<Value, String> {
     use achronyme_numerical::diff2_central;
-    let result = diff2_central(evaluator, &func, x, h)?;
+    let result = diff2_central(evaluator, args[0].function(), args[1].number(), args[2].number())?;
     Ok(Value::Number(result))
 }
 
-/// Numerical third derivative: diff3(f, x, h)
-pub fn handle_diff3(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value, String> {
-    if args.len() != 3 {
-        return Err("diff3() requires 3 arguments: function, x, h".to_string());
-    }
-
-    let func_value = evaluator.evaluate(&args[0])?;
-    let func = match func_value {
-        Value::Function(f) => f,
-        _ => return Err("diff3() requires a function as first argument".to_string()),
-    };
-
-    let x = match evaluator.evaluate(&args[1])? {
-        Value::Number(n) => n,
-        _ => return Err("diff3() requires a number for x".to_string()),
-    };
-
-    let h = match evaluator.evaluate(&args[2])? {
-        Value::Number(n) => n,
-        _ => return Err("diff3() requires a number for h".to_string()),
-    };
-
+fn diff3_impl(evaluator: &mut Evaluator, args: &[Arg]) -> Result<Value, String> {
     use achronyme_numerical::diff3_central;
-    let result = diff3_central(evaluator, &func, x, h)?;
+    let result = diff3_central(evaluator, args[0].function(), args[1].number(), args[2].number())?;
     Ok(Value::Number(result))
 }
 
-/// Gradient: gradient(f, point, h)
-pub fn handle_gradient(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value, String> {
-    if args.len() != 3 {
-        return Err("gradient() requires 3 arguments: function, point, h".to_string());
-    }
-
-    let func = match evaluator.evaluate(&args[0])? {
-        Value::Function(f) => f,
-        _ => return Err("gradient() requires a function as first argument".to_string()),
-    };
-
-    let point_value = evaluator.evaluate(&args[1])?;
-    let point_vec = match &point_value {
-        Value::Vector(v) => {
-            let mut points = Vec::new();
-            for val in v {
-                if let Value::Number(n) = val {
-                    points.push(*n);
-                } else {
-                    return Err("gradient() requires a numeric vector for point".to_string());
-                }
-            }
-            points
-        }
-        _ => return Err("gradient() requires a vector for point".to_string()),
-    };
-
-    let h = match evaluator.evaluate(&args[2])? {
-        Value::Number(n) => n,
-        _ => return Err("gradient() requires a number for h".to_string()),
-    };
+fn gradient_sig() -> BuiltinSignature {
+    BuiltinSignature::new(
+        "gradient",
+        vec![
+            ParamSpec::new("f", ParamKind::Function),
+            ParamSpec::new("point", ParamKind::NumericVector),
+            ParamSpec::new("h", ParamKind::Number),
+        ],
+    )
+}
 
+fn gradient_impl(evaluator: &mut Evaluator, args: &[Arg]) -> Result<Value, String> {
     use achronyme_numerical::gradient as gradient_calc;
-    let result = gradient_calc(evaluator, &func, &point_vec, h)?;
+    let result = gradient_calc(evaluator, args[0].function(), args[1].numeric_vector(), args[2].number())?;
     Ok(Value::Vector(result.into_iter().map(Value::Number).collect()))
 }
 
-/// Numerical integration (trapezoidal): integral(f, a, b, n)
-pub fn handle_integral(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value, String> {
-    if args.len() != 4 {
-        return Err("integral() requires 4 arguments: function, a, b, n".to_string());
-    }
-
-    let func_value = evaluator.evaluate(&args[0])?;
-    let func = match func_value {
-        Value::Function(f) => f,
-        _ => return Err("integral() requires a function as first argument".to_string()),
-    };
-
-    let a = match evaluator.evaluate(&args[1])? {
-        Value::Number(n) => n,
-        _ => return Err("integral() requires a number for a".to_string()),
-    };
-
-    let b = match evaluator.evaluate(&args[2])? {
-        Value::Number(n) => n,
-        _ => return Err("integral() requires a number for b".to_string()),
-    };
-
-    let n = match evaluator.evaluate(&args[3])? {
-        Value::Number(n) => n as usize,
-        _ => return Err("integral() requires a number for n".to_string()),
-    };
+fn integral_sig() -> BuiltinSignature {
+    BuiltinSignature::new(
+        "integral",
+        vec![
+            ParamSpec::new("f", ParamKind::Function),
+            ParamSpec::new("a", ParamKind::Number),
+            ParamSpec::new("b", ParamKind::Number),
+            ParamSpec::new("n", ParamKind::Number),
+        ],
+    )
+}
 
+fn integral_impl(evaluator: &mut Evaluator, args: &[Arg]) -> Result<Value, String> {
     use achronyme_numerical::trapz;
-    let result = trapz(evaluator, &func, a, b, n)?;
+    let n = args[3].number() as usize;
+    let result = trapz(evaluator, args[0].function(), args[1].number(), args[2].number(), n)?;
     Ok(Value::Number(result))
 }
 
-/// Simpson's rule integration: simpson(f, a, b, n)
-pub fn handle_simpson(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value, String> {
-    if args.len() != 4 {
-        return Err("simpson() requires 4 arguments: function, a, b, n".to_string());
-    }
-
-    let func_value = evaluator.evaluate(&args[0])?;
-    let func = match func_value {
-        Value::Function(f) => f,
-        _ => return Err("simpson() requires a function as first argument".to_string()),
-    };
-
-    let a = match evaluator.evaluate(&args[1])? {
-        Value::Number(n) => n,
-        _ => return Err("simpson() requires a number for a".to_string()),
-    };
-
-    let b = match evaluator.evaluate(&args[2])? {
-        Value::Number(n) => n,
-        _ => return Err("simpson() requires a number for b".to_string()),
-    };
-
-    let n = match evaluator.evaluate(&args[3])? {
-        Value::Number(n) => n as usize,
-        _ => return Err("simpson() requires a number for n".to_string()),
-    };
-
+fn simpson_impl(evaluator: &mut Evaluator, args: &[Arg]) -> Result<Value, String> {
     use achronyme_numerical::simpson;
-    let result = simpson(evaluator, &func, a, b, n)?;
+    let n = args[3].number() as usize;
+    let result = simpson(evaluator, args[0].function(), args[1].number(), args[2].number(), n)?;
     Ok(Value::Number(result))
 }
 
-/// Romberg integration: romberg(f, a, b, tol)
-pub fn handle_romberg(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value, String> {
-    if args.len() != 4 {
-        return Err("romberg() requires 4 arguments: function, a, b, tol".to_string());
-    }
-
-    let func_value = evaluator.evaluate(&args[0])?;
-    let func = match func_value {
-        Value::Function(f) => f,
-        _ => return Err("romberg() requires a function as first argument".to_string()),
-    };
-
-    let a = match evaluator.evaluate(&args[1])? {
-        Value::Number(n) => n,
-        _ => return Err("romberg() requires a number for a".to_string()),
-    };
-
-    let b = match evaluator.evaluate(&args[2])? {
-        Value::Number(n) => n,
-        _ => return Err("romberg() requires a number for b".to_string()),
-    };
-
-    let tol = match evaluator.evaluate(&args[3])? {
-        Value::Number(n) => n,
-        _ => return Err("romberg() requires a number for tol".to_string()),
-    };
+fn romberg_sig() -> BuiltinSignature {
+    BuiltinSignature::new(
+        "romberg",
+        vec![
+            ParamSpec::new("f", ParamKind::Function),
+            ParamSpec::new("a", ParamKind::Number),
+            ParamSpec::new("b", ParamKind::Number),
+            ParamSpec::new("tol", ParamKind::Number),
+        ],
+    )
+}
 
+fn romberg_impl(evaluator: &mut Evaluator, args: &[Arg]) -> Result<Value, String> {
     use achronyme_numerical::romberg;
-    let result = romberg(evaluator, &func, a, b, tol, 20)?;
+    let result = romberg(evaluator, args[0].function(), args[1].number(), args[2].number(), args[3].number(), 20)?;
     Ok(Value::Number(result))
 }
 
-/// Adaptive quadrature: quad(f, a, b)
-pub fn handle_quad(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value, String> {
-    if args.len() != 3 {
-        return Err("quad() requires 3 arguments: function, a, b".to_string());
-    }
-
-    let func_value = evaluator.evaluate(&args[0])?;
-    let func = match func_value {
-        Value::Function(f) => f,
-        _ => return Err("quad() requires a function as first argument".to_string()),
-    };
-
-    let a = match evaluator.evaluate(&args[1])? {
-        Value::Number(n) => n,
-        _ => return Err("quad() requires a number for a".to_string()),
-    };
-
-    let b = match evaluator.evaluate(&args[2])? {
-        Value::Number(n) => n,
-        _ => return Err("quad() requires a number for b".to_string()),
-    };
+fn quad_sig() -> BuiltinSignature {
+    BuiltinSignature::new(
+        "quad",
+        vec![
+            ParamSpec::new("f", ParamKind::Function),
+            ParamSpec::new("a", ParamKind::Number),
+            ParamSpec::new("b", ParamKind::Number),
+        ],
+    )
+}
 
+fn quad_impl(evaluator: &mut Evaluator, args: &[Arg]) -> Result<Value, String> {
     use achronyme_numerical::quad;
-    let result = quad(evaluator, &func, a, b, 1e-10)?;
+    let result = quad(evaluator, args[0].function(), args[1].number(), args[2].number(), 1e-10)?;
     Ok(Value::Number(result))
 }
 
-/// Root finding (bisection): solve(f, a, b, tol)
-pub fn handle_solve(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value, String> {
-    if args.len() != 4 {
-        return Err("solve() requires 4 arguments: function, a, b, tol".to_string());
-    }
-
-    let func_value = evaluator.evaluate(&args[0])?;
-    let func = match func_value {
-        Value::Function(f) => f,
-        _ => return Err("solve() requires a function as first argument".to_string()),
-    };
+fn quad_gk_sig() -> BuiltinSignature {
+    BuiltinSignature::new(
+        "quad_gk",
+        vec![
+            ParamSpec::new("f", ParamKind::Function),
+            ParamSpec::new("a", ParamKind::Number),
+            ParamSpec::new("b", ParamKind::Number),
+            ParamSpec::new("tol", ParamKind::Number),
+        ],
+    )
+}
 
-    let a = match evaluator.evaluate(&args[1])? {
-        Value::Number(n) => n,
-        _ => return Err("solve() requires a number for a".to_string()),
-    };
+fn quad_gk_impl(evaluator: &mut Evaluator, args: &[Arg]) -> Result<Value, String> {
+    use achronyme_numerical::quad_gk;
+    let result = quad_gk(evaluator, args[0].function(), args[1].number(), args[2].number(), args[3].number())?;
 
-    let b = match evaluator.evaluate(&args[2])? {
-        Value::Number(n) => n,
-        _ => return Err("solve() requires a number for b".to_string()),
-    };
+    let mut record = HashMap::new();
+    record.insert("value".to_string(), Value::Number(result.value));
+    record.insert("error".to_string(), Value::Number(result.error));
+    record.insert("evaluations".to_string(), Value::Number(result.evaluations as f64));
+    Ok(Value::Record(record))
+}
 
-    let tol = match evaluator.evaluate(&args[3])? {
-        Value::Number(n) => n,
-        _ => return Err("solve() requires a number for tol".to_string()),
-    };
+fn solve_sig() -> BuiltinSignature {
+    BuiltinSignature::new(
+        "solve",
+        vec![
+            ParamSpec::new("f", ParamKind::Function),
+            ParamSpec::new("a", ParamKind::Number),
+            ParamSpec::new("b", ParamKind::Number),
+            ParamSpec::new("tol", ParamKind::Number),
+        ],
+    )
+}
 
+fn solve_impl(evaluator: &mut Evaluator, args: &[Arg]) -> Result<Value, String> {
     use achronyme_numerical::bisect;
-    let result = bisect(evaluator, &func, a, b, tol)?;
+    let result = bisect(evaluator, args[0].function(), args[1].number(), args[2].number(), args[3].number())?;
     Ok(Value::Number(result))
 }
 
-/// Newton's method: newton(f, df, x0, tol, max_iter)
-pub fn handle_newton(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value, String> {
-    if args.len() != 5 {
-        return Err("newton() requires 5 arguments: function, derivative, x0, tol, max_iter".to_string());
-    }
-
-    let func_value = evaluator.evaluate(&args[0])?;
-    let func = match func_value {
-        Value::Function(f) => f,
-        _ => return Err("newton() requires a function as first argument".to_string()),
-    };
-
-    let dfunc_value = evaluator.evaluate(&args[1])?;
-    let dfunc = match dfunc_value {
-        Value::Function(f) => f,
-        _ => return Err("newton() requires a function as second argument (derivative)".to_string()),
-    };
-
-    let x0 = match evaluator.evaluate(&args[2])? {
-        Value::Number(n) => n,
-        _ => return Err("newton() requires a number for x0".to_string()),
-    };
-
-    let tol = match evaluator.evaluate(&args[3])? {
-        Value::Number(n) => n,
-        _ => return Err("newton() requires a number for tol".to_string()),
-    };
-
-    let max_iter = match evaluator.evaluate(&args[4])? {
-        Value::Number(n) => n as usize,
-        _ => return Err("newton() requires a number for max_iter".to_string()),
-    };
+fn newton_sig() -> BuiltinSignature {
+    BuiltinSignature::new(
+        "newton",
+        vec![
+            ParamSpec::new("f", ParamKind::Function),
+            ParamSpec::new("df", ParamKind::Function),
+            ParamSpec::new("x0", ParamKind::Number),
+            ParamSpec::new("tol", ParamKind::Number),
+            ParamSpec::new("max_iter", ParamKind::Number),
+        ],
+    )
+}
 
+fn newton_impl(evaluator: &mut Evaluator, args: &[Arg]) -> Result<Value, String> {
     use achronyme_numerical::newton;
-    let result = newton(evaluator, &func, &dfunc, x0, tol, max_iter)?;
+    let max_iter = args[4].number() as usize;
+    let result = newton(
+        evaluator,
+        args[0].function(),
+        args[1].function(),
+        args[2].number(),
+        args[3].number(),
+        max_iter,
+    )?;
     Ok(Value::Number(result))
 }
 
-/// Secant method: secant(f, x0, x1, tol, max_iter)
-pub fn handle_secant(evaluator: &mut Evaluator, args: &[AstNode]) -> Result<Value, String> {
-    if args.len() != 5 {
-        return Err("secant() requires 5 arguments: function, x0, x1, tol, max_iter".to_string());
-    }
+fn newton_autodiff_sig() -> BuiltinSignature {
+    BuiltinSignature::new(
+        "newton",
+        vec![
+            ParamSpec::new("f", ParamKind::Function),
+            ParamSpec::new("x0", ParamKind::Number),
+            ParamSpec::new("tol", ParamKind::Number),
+            ParamSpec::new("max_iter", ParamKind::Number),
+        ],
+    )
+}
 
-    let func_value = evaluator.evaluate(&args[0])?;
-    let func = match func_value {
-        Value::Function(f) => f,
-        _ => return Err("secant() requires a function as first argument".to_string()),
-    };
+fn newton_autodiff_impl(evaluator: &mut Evaluator, args: &[Arg]) -> Result<Value, String> {
+    use achronyme_numerical::newton_autodiff;
+    let max_iter = args[3].number() as usize;
+    let result = newton_autodiff(evaluator, args[0].function(), args[1].number(), args[2].number(), max_iter)?;
+    Ok(Value::Number(result))
+}
 
-    let x0 = match evaluator.evaluate(&args[1])? {
-        Value::Number(n) => n,
-        _ => return Err("secant() requires a number for x0".to_string()),
-    };
+fn autodiff_sig() -> BuiltinSignature {
+    BuiltinSignature::new(
+        "autodiff",
+        vec![
+            ParamSpec::new("f", ParamKind::Function),
+            ParamSpec::new("x", ParamKind::Number),
+        ],
+    )
+}
 
-    let x1 = match evaluator.evaluate(&args[2])? {
-        Value::Number(n) => n,
-        _ => return Err("secant() requires a number for x1".to_string()),
-    };
+fn autodiff_impl(evaluator: &mut Evaluator, args: &[Arg]) -> Result<Value, String> {
+    use achronyme_numerical::autodiff;
+    let result = autodiff(evaluator, args[0].function(), args[1].number())?;
+    Ok(Value::Number(result))
+}
 
-    let tol = match evaluator.evaluate(&args[3])? {
-        Value::Number(n) => n,
-        _ => return Err("secant() requires a number for tol".to_string()),
-    };
+fn odesolve_sig() -> BuiltinSignature {
+    BuiltinSignature::new(
+        "odesolve",
+        vec![
+            ParamSpec::new("f", ParamKind::Function),
+            ParamSpec::new("t0", ParamKind::Number),
+            ParamSpec::new("y0", ParamKind::NumberOrVector),
+            ParamSpec::new("t1", ParamKind::Number),
+            ParamSpec::new("tol", ParamKind::Number),
+        ],
+    )
+}
+
+fn odesolve_impl(evaluator: &mut Evaluator, args: &[Arg]) -> Result<Value, String> {
+    use achronyme_numerical::odesolve;
+    let trajectory = odesolve(
+        evaluator,
+        args[0].function(),
+        args[1].number(),
+        args[2].numeric_vector(),
+        args[3].number(),
+        args[4].number(),
+    )?;
+
+    let samples = trajectory
+        .into_iter()
+        .map(|sample| {
+            let y = if sample.y.len() == 1 {
+                Value::Number(sample.y[0])
+            } else {
+                Value::Vector(sample.y.into_iter().map(Value::Number).collect())
+            };
+            let mut record = HashMap::new();
+            record.insert("t".to_string(), Value::Number(sample.t));
+            record.insert("y".to_string(), y);
+            Value::Record(record)
+        })
+        .collect();
+
+    Ok(Value::Vector(samples))
+}
 
-    let max_iter = match evaluator.evaluate(&args[4])? {
-        Value::Number(n) => n as usize,
-        _ => return Err("secant() requires a number for max_iter".to_string()),
-    };
+fn secant_sig() -> BuiltinSignature {
+    BuiltinSignature::new(
+        "secant",
+        vec![
+            ParamSpec::new("f", ParamKind::Function),
+            ParamSpec::new("x0", ParamKind::Number),
+            ParamSpec::new("x1", ParamKind::Number),
+            ParamSpec::new("tol", ParamKind::Number),
+            ParamSpec::new("max_iter", ParamKind::Number),
+        ],
+    )
+}
 
+fn secant_impl(evaluator: &mut Evaluator, args: &[Arg]) -> Result<Value, String> {
     use achronyme_numerical::secant;
-    let result = secant(evaluator, &func, x0, x1, tol, max_iter)?;
+    let max_iter = args[4].number() as usize;
+    let result = secant(
+        evaluator,
+        args[0].function(),
+        args[1].number(),
+        args[2].number(),
+        args[3].number(),
+        max_iter,
+    )?;
     Ok(Value::Number(result))
 }
+
+/// Build the registry of numerical-calculus builtins, keyed by signature.
+///
+/// Built once and cached: every ordinary call not already special-cased in
+/// `dispatch` goes through this, so rebuilding the `BuiltinSignature` table
+/// (and its `Vec<ParamSpec>`s) from scratch on every call would make it a
+/// per-expression allocation hot path.
+fn registry() -> &'static BuiltinRegistry {
+    static REGISTRY: std::sync::OnceLock<BuiltinRegistry> = std::sync::OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let mut registry = BuiltinRegistry::new();
+        registry.register(diff_sig(), &[], diff_impl);
+        registry.register(BuiltinSignature::new("diff2", diff_sig().params), &[], diff2_impl);
+        registry.register(BuiltinSignature::new("diff3", diff_sig().params), &[], diff3_impl);
+        registry.register(gradient_sig(), &[], gradient_impl);
+        registry.register(integral_sig(), &["trapz"], integral_impl);
+        registry.register(BuiltinSignature::new("simpson", integral_sig().params), &[], simpson_impl);
+        registry.register(romberg_sig(), &[], romberg_impl);
+        registry.register(quad_sig(), &[], quad_impl);
+        registry.register(quad_gk_sig(), &[], quad_gk_impl);
+        registry.register(solve_sig(), &["bisect"], solve_impl);
+        registry.register(newton_sig(), &[], newton_impl);
+        registry.register(autodiff_sig(), &[], autodiff_impl);
+        registry.register(odesolve_sig(), &[], odesolve_impl);
+        registry.register(secant_sig(), &[], secant_impl);
+        registry
+    })
+}
+
+/// Dispatch `name` to a registered numerical builtin, if any.
+///
+/// `newton` is special-cased ahead of the registry: it has two call shapes
+/// depending on arity — `newton(f, df, x0, tol, max_iter)` with an explicit
+/// derivative, or `newton(f, x0, tol, max_iter)` where the derivative comes
+/// from `autodiff` instead. The registry only holds one signature per name,
+/// so the 4-arg form is routed here directly rather than through it.
+pub fn dispatch(evaluator: &mut Evaluator, name: &str, args: &[AstNode]) -> Option<Result<Value, String>> {
+    if name == "newton" && args.len() == 4 {
+        let sig = newton_autodiff_sig();
+        return Some(
+            crate::signature::validate_args(evaluator, &sig, args)
+                .and_then(|parsed| newton_autodiff_impl(evaluator, &parsed)),
+        );
+    }
+
+    registry().dispatch(evaluator, name, args)
+}
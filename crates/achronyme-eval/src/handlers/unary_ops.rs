@@ -13,13 +13,16 @@ pub fn apply(op: &UnaryOp, operand: Value) -> Result<Value, String> {
 fn apply_negate(operand: Value) -> Result<Value, String> {
     match operand {
         Value::Number(n) => Ok(Value::Number(-n)),
+        Value::Integer(n) => Ok(Value::Integer(-n)),
         Value::Complex(c) => Ok(Value::Complex(Complex::new(-c.re, -c.im))),
+        Value::Dual(d) => Ok(Value::Dual(-d)),
         Value::Vector(vec) => {
             if !Value::is_numeric_vector(&vec) {
                 return Err("Cannot negate a non-numeric vector".to_string());
             }
             let result: Vec<Value> = vec.iter().map(|v| match v {
                 Value::Number(n) => Value::Number(-n),
+                Value::Integer(n) => Value::Integer(-n),
                 Value::Complex(c) => Value::Complex(Complex::new(-c.re, -c.im)),
                 _ => unreachable!(),
             }).collect();
@@ -34,6 +37,7 @@ fn apply_not(operand: Value) -> Result<Value, String> {
     match operand {
         Value::Boolean(b) => Ok(Value::Boolean(!b)),
         Value::Number(n) => Ok(Value::Boolean(n == 0.0)),
+        Value::Integer(n) => Ok(Value::Boolean(n == 0)),
         _ => Err("Logical NOT operator requires a boolean or a number".to_string()),
     }
 }
\ No newline at end of file
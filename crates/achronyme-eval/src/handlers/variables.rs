@@ -14,6 +14,15 @@ pub fn evaluate_declaration(
     type_annotation: &Option<TypeAnnotation>,
     initializer: &AstNode,
 ) -> Result<Value, String> {
+    // Type check if annotation is provided
+    if let Some(expected_type) = type_annotation {
+        // Static check first: catches mismatches (e.g. an annotated lambda
+        // whose body can't produce the declared return type) before the
+        // initializer runs at all.
+        type_checker::check_node(initializer, expected_type, &std::collections::HashMap::new())
+            .map_err(|err| format!("Type error: variable '{}' {}", name, err.replace("Type mismatch: ", "")))?;
+    }
+
     // Evaluate the initializer
     let mut value = evaluator.evaluate(initializer)?;
 
@@ -25,8 +34,10 @@ pub fn evaluate_declaration(
         // Special case: if type is Function and value is a function, enrich with type info
         value = enrich_function_with_type(value, &resolved_type);
 
-        type_checker::check_type(&value, &resolved_type).map_err(|err| {
-            format!("Type error: variable '{}' {}", name, err.replace("Type mismatch: ", ""))
+        // Coercing, not just checking, so e.g. `let z: Complex = 3` stores
+        // an actual Complex rather than keeping the Number around.
+        value = type_checker::check_type_coercing(&value, &resolved_type).map_err(|err| {
+            format!("Type error: variable '{}' {}", name, err.to_string().replace("Type mismatch: ", ""))
         })?;
     }
 
@@ -104,8 +115,10 @@ pub fn evaluate_mutable_declaration(
         // Special case: if type is Function and value is a function, enrich with type info
         value = enrich_function_with_type(value, &resolved_type);
 
-        type_checker::check_type(&value, &resolved_type).map_err(|err| {
-            format!("Type error: variable '{}' {}", name, err.replace("Type mismatch: ", ""))
+        // Coercing, not just checking, so e.g. `mut z: Complex = 3` stores
+        // an actual Complex rather than keeping the Number around.
+        value = type_checker::check_type_coercing(&value, &resolved_type).map_err(|err| {
+            format!("Type error: variable '{}' {}", name, err.to_string().replace("Type mismatch: ", ""))
         })?;
 
         // Define as mutable variable with resolved type annotation (enforced on assignment)
@@ -146,6 +159,12 @@ pub fn evaluate_reference(evaluator: &Evaluator, name: &str) -> Result<Value, St
         return Ok(Value::Function(Function::builtin(name.to_string())));
     }
 
+    // Check if it's a host-registered native function
+    if evaluator.has_native_function(name) {
+        use achronyme_types::function::Function;
+        return Ok(Value::Function(Function::builtin(name.to_string())));
+    }
+
     // Check if it's a special form function (functions that require special evaluation)
     if is_special_form(name) {
         use achronyme_types::function::Function;
@@ -163,14 +182,14 @@ fn is_special_form(name: &str) -> bool {
     matches!(
         name,
         // Higher-order functions (require lazy evaluation)
-        "map" | "filter" | "reduce" | "pipe" |
+        "map" | "filter" | "reduce" | "scan" | "pipe" | "converge" |
         // Tier 2 array predicates (require lambda evaluation)
         "any" | "all" | "find" | "findIndex" | "count" |
         // Numerical calculus functions (require evaluator for lambda evaluation)
         "diff" | "diff2" | "diff3" | "gradient" | "integral" | "trapz" |
         "simpson" | "romberg" | "quad" | "solve" | "bisect" | "newton" | "secant" | "derivative" |
         // Debug functions
-        "describe" |
+        "describe" | "infer_types" |
         // Optimization functions
         "simplex" | "linprog" | "dual_simplex" | "two_phase_simplex" | "revised_simplex" |
         "objective_value" | "shadow_price" | "sensitivity_c" | "sensitivity_b" |
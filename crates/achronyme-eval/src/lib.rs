@@ -1,6 +1,7 @@
 pub mod evaluator;
 pub mod constants;
 pub mod functions;
+pub mod signature;
 pub mod tco;
 pub mod modules;
 pub mod type_checker;
@@ -11,4 +12,4 @@ mod function_modules;
 pub use achronyme_types::Environment;
 pub use evaluator::Evaluator;
 pub use modules::{Module, ModuleRegistry};
-pub use type_checker::{check_type, is_assignable, check_type_detailed, infer_type};
+pub use type_checker::{check_type, check_type_with_dims, is_assignable, check_type_detailed, coerce, infer_type, merge_record_types, DimEnv, PathSegment, TypeError};
@@ -90,6 +90,7 @@ fn register_prelude(registry: &mut ModuleRegistry, func_registry: &FunctionRegis
     register_if_exists(registry, func_registry, "print");
     register_if_exists(registry, func_registry, "type");
     register_if_exists(registry, func_registry, "str");
+    register_if_exists(registry, func_registry, "format");
 
     // === STRINGS (5) ===
     register_if_exists(registry, func_registry, "concat");
@@ -128,6 +129,11 @@ fn register_math_module(registry: &mut ModuleRegistry, func_registry: &FunctionR
     register_to_module(&mut module, func_registry, "cosh");
     register_to_module(&mut module, func_registry, "tanh");
 
+    // Inverse hyperbolic functions
+    register_to_module(&mut module, func_registry, "asinh");
+    register_to_module(&mut module, func_registry, "acosh");
+    register_to_module(&mut module, func_registry, "atanh");
+
     // Logarithms
     register_to_module(&mut module, func_registry, "log10");
     register_to_module(&mut module, func_registry, "log2");
@@ -280,11 +286,16 @@ fn register_complex_module(registry: &mut ModuleRegistry, func_registry: &Functi
     let mut module = Module::new("complex");
 
     register_to_module(&mut module, func_registry, "complex");
+    register_to_module(&mut module, func_registry, "parseComplex");
     register_to_module(&mut module, func_registry, "real");
     register_to_module(&mut module, func_registry, "imag");
     register_to_module(&mut module, func_registry, "arg");
     register_to_module(&mut module, func_registry, "conj");
     register_to_module(&mut module, func_registry, "rectangular");
+    register_to_module(&mut module, func_registry, "nthRoots");
+    register_to_module(&mut module, func_registry, "isNaN");
+    register_to_module(&mut module, func_registry, "isInfinite");
+    register_to_module(&mut module, func_registry, "isFinite");
 
     registry.register_module(module);
 }
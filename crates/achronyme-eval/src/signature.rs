@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+
+use achronyme_parser::ast::AstNode;
+use achronyme_types::function::Function;
+use achronyme_types::value::Value;
+
+use crate::evaluator::Evaluator;
+
+/// Declarative builtin signature registry
+///
+/// Handlers that need evaluator access (because an argument may be a lambda,
+/// or because arguments must stay lazy until validated) used to repeat the
+/// same `args.len()` check followed by a `match evaluate(&args[i])` against
+/// `Value::Number`/`Value::Function`/`Value::Vector`, each with its own
+/// hand-written error string. `BuiltinSignature` names the expected shape of
+/// a builtin's arguments once, and `validate_args` evaluates/coerces the
+/// AST nodes against it, producing uniform error messages like
+/// `"romberg() expected Number for argument 4 'tol', got Vector"`.
+
+/// The expected kind of a single builtin parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamKind {
+    Number,
+    Function,
+    NumericVector,
+    /// A `Number` or a `Vector<Number>` — e.g. an ODE state that may hold
+    /// one component or several. Always coerces to `Arg::NumericVector`.
+    NumberOrVector,
+    Any,
+}
+
+impl ParamKind {
+    fn describe(self) -> &'static str {
+        match self {
+            ParamKind::Number => "Number",
+            ParamKind::Function => "Function",
+            ParamKind::NumericVector => "Vector",
+            ParamKind::NumberOrVector => "Number or Vector",
+            ParamKind::Any => "Any",
+        }
+    }
+}
+
+/// Specification of a single builtin parameter.
+#[derive(Debug, Clone)]
+pub struct ParamSpec {
+    pub name: &'static str,
+    pub kind: ParamKind,
+    pub optional: bool,
+    pub variadic: bool,
+}
+
+impl ParamSpec {
+    pub const fn new(name: &'static str, kind: ParamKind) -> Self {
+        ParamSpec {
+            name,
+            kind,
+            optional: false,
+            variadic: false,
+        }
+    }
+
+    /// Mark this parameter as optional (may be omitted from the call).
+    pub const fn optional(mut self) -> Self {
+        self.optional = true;
+        self
+    }
+
+    /// Mark this parameter as variadic (absorbs all remaining arguments).
+    pub const fn variadic(mut self) -> Self {
+        self.variadic = true;
+        self
+    }
+}
+
+/// Declarative argument shape for a builtin function.
+#[derive(Debug, Clone)]
+pub struct BuiltinSignature {
+    pub name: &'static str,
+    pub params: Vec<ParamSpec>,
+}
+
+impl BuiltinSignature {
+    pub fn new(name: &'static str, params: Vec<ParamSpec>) -> Self {
+        BuiltinSignature { name, params }
+    }
+
+    fn required_count(&self) -> usize {
+        self.params.iter().filter(|p| !p.optional).count()
+    }
+}
+
+/// An argument already validated (and, where needed, coerced) against a `ParamSpec`.
+#[derive(Debug, Clone)]
+pub enum Arg {
+    Number(f64),
+    Function(Function),
+    NumericVector(Vec<f64>),
+    Any(Value),
+    Missing,
+}
+
+impl Arg {
+    /// Unwrap a validated `Number` argument.
+    ///
+    /// Panics if the signature didn't request `ParamKind::Number` for this
+    /// slot; `validate_args` guarantees that never happens for callers that
+    /// only read the kind they asked for.
+    pub fn number(&self) -> f64 {
+        match self {
+            Arg::Number(n) => *n,
+            other => panic!("Arg::number called on {:?}", other),
+        }
+    }
+
+    pub fn function(&self) -> &Function {
+        match self {
+            Arg::Function(f) => f,
+            other => panic!("Arg::function called on {:?}", other),
+        }
+    }
+
+    pub fn numeric_vector(&self) -> &[f64] {
+        match self {
+            Arg::NumericVector(v) => v,
+            other => panic!("Arg::numeric_vector called on {:?}", other),
+        }
+    }
+
+    pub fn value(&self) -> &Value {
+        match self {
+            Arg::Any(v) => v,
+            other => panic!("Arg::value called on {:?}", other),
+        }
+    }
+
+    pub fn is_missing(&self) -> bool {
+        matches!(self, Arg::Missing)
+    }
+}
+
+fn value_kind_name(value: &Value) -> &'static str {
+    match value {
+        Value::Number(_) => "Number",
+        Value::Integer(_) => "Integer",
+        Value::Boolean(_) => "Boolean",
+        Value::String(_) => "String",
+        Value::Complex(_) => "Complex",
+        Value::Vector(_) => "Vector",
+        Value::Tensor(_) => "Tensor",
+        Value::ComplexTensor(_) => "Tensor",
+        Value::Function(_) => "Function",
+        Value::Record(_) => "Record",
+        Value::Edge { .. } => "Edge",
+        Value::Null => "null",
+        Value::Generator(_) => "Generator",
+        _ => "value",
+    }
+}
+
+fn coerce(
+    sig_name: &str,
+    param: &ParamSpec,
+    index: usize,
+    value: Value,
+) -> Result<Arg, String> {
+    match (param.kind, value) {
+        (ParamKind::Number, Value::Number(n)) => Ok(Arg::Number(n)),
+        (ParamKind::Number, Value::Integer(n)) => Ok(Arg::Number(n as f64)),
+        (ParamKind::Function, Value::Function(f)) => Ok(Arg::Function(f)),
+        (ParamKind::NumberOrVector, Value::Number(n)) => Ok(Arg::NumericVector(vec![n])),
+        (ParamKind::NumberOrVector, Value::Integer(n)) => Ok(Arg::NumericVector(vec![n as f64])),
+        (ParamKind::NumberOrVector, Value::Vector(v)) | (ParamKind::NumericVector, Value::Vector(v)) => {
+            let mut numbers = Vec::with_capacity(v.len());
+            for element in &v {
+                match element {
+                    Value::Number(n) => numbers.push(*n),
+                    Value::Integer(n) => numbers.push(*n as f64),
+                    other => {
+                        return Err(format!(
+                            "{}() expected Vector<Number> for argument {} '{}', got Vector<{}>",
+                            sig_name,
+                            index + 1,
+                            param.name,
+                            value_kind_name(other)
+                        ))
+                    }
+                }
+            }
+            Ok(Arg::NumericVector(numbers))
+        }
+        (ParamKind::Any, v) => Ok(Arg::Any(v)),
+        (_, got) => Err(format!(
+            "{}() expected {} for argument {} '{}', got {}",
+            sig_name,
+            param.kind.describe(),
+            index + 1,
+            param.name,
+            value_kind_name(&got)
+        )),
+    }
+}
+
+/// Evaluate and validate `args` against `sig`, returning one `Arg` per
+/// declared parameter (in declaration order). Optional parameters that were
+/// not supplied resolve to `Arg::Missing`.
+pub fn validate_args(
+    evaluator: &mut Evaluator,
+    sig: &BuiltinSignature,
+    args: &[AstNode],
+) -> Result<Vec<Arg>, String> {
+    let required = sig.required_count();
+    if args.len() < required || args.len() > sig.params.len() {
+        return Err(format!(
+            "{}() requires {} argument{} ({}), got {}",
+            sig.name,
+            required,
+            if required == 1 { "" } else { "s" },
+            sig.params
+                .iter()
+                .map(|p| p.name)
+                .collect::<Vec<_>>()
+                .join(", "),
+            args.len()
+        ));
+    }
+
+    let mut out = Vec::with_capacity(sig.params.len());
+    for (index, param) in sig.params.iter().enumerate() {
+        if index >= args.len() {
+            out.push(Arg::Missing);
+            continue;
+        }
+        let value = evaluator.evaluate(&args[index])?;
+        out.push(coerce(sig.name, param, index, value)?);
+    }
+    Ok(out)
+}
+
+/// A builtin implementation that has already received validated/coerced
+/// arguments from `validate_args`.
+pub type BuiltinImpl = fn(&mut Evaluator, &[Arg]) -> Result<Value, String>;
+
+/// Registry mapping builtin names to their declared signature and
+/// implementation, so a handler module can dispatch by name without
+/// re-deriving the arity/type boilerplate at each call site.
+#[derive(Default)]
+pub struct BuiltinRegistry {
+    entries: HashMap<&'static str, (BuiltinSignature, BuiltinImpl)>,
+}
+
+impl BuiltinRegistry {
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Register a builtin under `sig.name`, plus any additional aliases.
+    pub fn register(&mut self, sig: BuiltinSignature, aliases: &[&'static str], implementation: BuiltinImpl) {
+        let name = sig.name;
+        self.entries.insert(name, (sig.clone(), implementation));
+        for alias in aliases {
+            self.entries.insert(alias, (sig.clone(), implementation));
+        }
+    }
+
+    pub fn has(&self, name: &str) -> bool {
+        self.entries.contains_key(name)
+    }
+
+    /// Validate `args` against the registered signature for `name` and call
+    /// its implementation. Returns `None` if `name` isn't registered here.
+    pub fn dispatch(
+        &self,
+        evaluator: &mut Evaluator,
+        name: &str,
+        args: &[AstNode],
+    ) -> Option<Result<Value, String>> {
+        let (sig, implementation) = self.entries.get(name)?;
+        Some(validate_args(evaluator, sig, args).and_then(|parsed| implementation(evaluator, &parsed)))
+    }
+}
@@ -32,6 +32,10 @@ pub fn is_tail_position(node: &AstNode) -> bool {
             matches!(**callee, AstNode::RecReference)
         }
 
+        // Pipe desugars to a call of its right-hand side, never to `rec`
+        // directly, so it's never itself a tail-recursive call.
+        AstNode::Pipe { .. } => false,
+
         // If-expression: both branches must be in tail position
         AstNode::If { then_expr, else_expr, .. } => {
             is_tail_position(then_expr) && is_tail_position(else_expr)
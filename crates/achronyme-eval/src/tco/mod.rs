@@ -35,6 +35,10 @@ pub fn is_tail_position(node: &AstNode) -> bool {
             matches!(**callee, AstNode::RecReference)
         }
 
+        // Pipe desugars to a call of its right-hand side, never to `rec`
+        // directly, so it's never itself a tail-recursive call.
+        AstNode::Pipe { .. } => false,
+
         // If-expression: both branches must be in tail position
         AstNode::If { then_expr, else_expr, .. } => {
             is_tail_position(then_expr) && is_tail_position(else_expr)
@@ -162,6 +166,8 @@ fn contains_rec(node: &AstNode) -> bool {
                 || args.iter().any(contains_rec)
         }
 
+        AstNode::Pipe { left, right } => contains_rec(left) || contains_rec(right),
+
         AstNode::BinaryOp { left, right, .. } => {
             contains_rec(left) || contains_rec(right)
         }
@@ -272,6 +278,12 @@ fn all_rec_are_tail_helper(node: &AstNode, in_tail_position: bool) -> bool {
             args.iter().all(|arg| all_rec_are_tail_helper(arg, false))
         }
 
+        // Pipe: both operands are evaluated before the desugared call runs,
+        // so neither is in tail position
+        AstNode::Pipe { left, right } => {
+            all_rec_are_tail_helper(left, false) && all_rec_are_tail_helper(right, false)
+        }
+
         // Binary/unary ops: children are NOT in tail position
         AstNode::BinaryOp { left, right, .. } => {
             all_rec_are_tail_helper(left, false) && all_rec_are_tail_helper(right, false)
@@ -73,6 +73,7 @@ fn get_value_type_name(value: &Value) -> String {
         }
         Value::Edge { .. } => "Edge".to_string(),
         Value::TailCall(_) => "TailCall (internal)".to_string(),
+        Value::Dual(_) => "Dual (internal)".to_string(),
         Value::EarlyReturn(_) => "EarlyReturn (internal)".to_string(),
         Value::MutableRef(_) => "MutableRef".to_string(),
         Value::Null => "null".to_string(),
@@ -462,6 +463,7 @@ pub fn infer_type(value: &Value) -> TypeAnnotation {
         Value::Edge { .. } => TypeAnnotation::Edge,
         // Internal values - should not appear in user code
         Value::TailCall(_) => TypeAnnotation::Any,
+        Value::Dual(_) => TypeAnnotation::Any,
         Value::EarlyReturn(_) => TypeAnnotation::Any,
         Value::MutableRef(_) => unreachable!("MutableRef should be dereferenced"),
         // Generator type - represents an iterator
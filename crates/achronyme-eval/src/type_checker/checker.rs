@@ -1,15 +1,25 @@
 //! Core type checking logic
 
+use std::collections::HashMap;
+
 use achronyme_parser::TypeAnnotation;
 use achronyme_types::value::Value;
 
+use super::coerce::coerce;
 use super::display::{get_value_type_name, type_annotation_to_string};
-use super::error::TypeError;
+use super::error::{PathSegment, TypeError};
+use super::inference::infer_type;
+use super::subtype::is_subtype;
 use super::validators::{
-    check_complex_tensor_type, check_function_type, check_real_tensor_type,
-    check_record_structural_type,
+    check_complex_tensor_type, check_function_type, check_function_type_detailed, check_real_tensor_type,
+    check_record_structural_type, first_mismatched_shape_dim,
 };
 
+/// Dimension environment for named tensor shape variables: the extent
+/// each `Var(name)` (e.g. `N` in `Tensor<Number, [N, M]>`) has been bound
+/// to so far within the current checking scope.
+pub type DimEnv = HashMap<String, usize>;
+
 /// Check if a runtime Value matches a TypeAnnotation
 ///
 /// This function performs runtime type checking according to the gradual type system rules.
@@ -41,13 +51,30 @@ use super::validators::{
 /// assert!(check_type(&Value::Boolean(true), &TypeAnnotation::Any).is_ok());
 /// ```
 pub fn check_type(value: &Value, expected: &TypeAnnotation) -> Result<(), String> {
+    // An Error value satisfies any expected type here, as in `matches_type`;
+    // only `check_type_detailed` surfaces its original message.
+    if matches!(value, Value::Error { .. }) {
+        return Ok(());
+    }
+    check_type_detailed(value, expected).map_err(|e| e.to_string())
+}
+
+/// Like [`check_type`], but reuses a caller-supplied dimension environment
+/// instead of starting from an empty one.
+///
+/// Pass the same `dims` across several calls to tie named shape variables
+/// together across a checking scope - e.g. checking every argument of one
+/// function call against its parameter types, so `matmul: (Tensor[N,M],
+/// Tensor[M,K]) -> Tensor[N,K]` rejects a call whose two tensors disagree
+/// on `M`.
+pub fn check_type_with_dims(value: &Value, expected: &TypeAnnotation, dims: &mut DimEnv) -> Result<(), String> {
     // Auto-dereference MutableRef for type checking
     let actual_value = match value {
         Value::MutableRef(rc) => &*rc.borrow(),
         v => v,
     };
 
-    if matches_type(actual_value, expected) {
+    if matches_type(actual_value, expected, dims) {
         Ok(())
     } else {
         Err(format!(
@@ -59,16 +86,39 @@ pub fn check_type(value: &Value, expected: &TypeAnnotation) -> Result<(), String
 }
 
 /// Check if a Value matches a TypeAnnotation (internal helper)
-pub(crate) fn matches_type(value: &Value, expected: &TypeAnnotation) -> bool {
+pub(crate) fn matches_type(value: &Value, expected: &TypeAnnotation, dims: &mut DimEnv) -> bool {
+    // A value that's already an Error carries whatever failure produced it.
+    // Rejecting it again here (e.g. "expected Number, got Error") would just
+    // be a fresh symptom of that original failure cascading further, so let
+    // it satisfy any expected type instead - `check_type_detailed` is where
+    // the original message gets surfaced, not here.
+    if matches!(value, Value::Error { .. }) {
+        return true;
+    }
+
     match expected {
         // Any type matches everything
         TypeAnnotation::Any => true,
 
+        // Never is uninhabited: no runtime value ever has this type
+        TypeAnnotation::Never => false,
+
+        // Structurally, only an actual Error value has this type - the
+        // Value::Error short-circuit above already handles the "propagate
+        // silently" half of Error's behavior, so by the time we get here
+        // `value` isn't one.
+        TypeAnnotation::Error(_) => false,
+
+        // An unresolved unification variable should have been zonked to
+        // Any already; be permissive rather than rejecting everything.
+        TypeAnnotation::TypeVar(_) => true,
+
         // Null type only matches null
         TypeAnnotation::Null => matches!(value, Value::Null),
 
         // Simple types
         TypeAnnotation::Number => matches!(value, Value::Number(_)),
+        TypeAnnotation::Integer => matches!(value, Value::Integer(_)),
         TypeAnnotation::Boolean => matches!(value, Value::Boolean(_)),
         TypeAnnotation::String => matches!(value, Value::String(_)),
         TypeAnnotation::Complex => matches!(value, Value::Complex(_)),
@@ -79,18 +129,28 @@ pub(crate) fn matches_type(value: &Value, expected: &TypeAnnotation) -> bool {
         // Edge type (graph edges)
         TypeAnnotation::Edge => matches!(value, Value::Edge { .. }),
 
-        // Generator type (opaque, no yield type checking)
-        TypeAnnotation::Generator => matches!(value, Value::Generator(_)),
+        // Generator type: if the generator carries a declared yield type
+        // (from `Generator::new_typed`), it must be a subtype of the
+        // expected element type; an untyped generator falls back to
+        // gradual/structural success, matching bare `Generator`'s Any
+        // element type.
+        TypeAnnotation::Generator { element_type } => match value {
+            Value::Generator(gen_rc) => match &gen_rc.borrow().declared_yield_type {
+                Some(yield_type) => is_subtype(yield_type, element_type),
+                None => true,
+            },
+            _ => false,
+        },
 
         // Function type (opaque, accepts any function without signature checking)
         TypeAnnotation::AnyFunction => matches!(value, Value::Function(_)),
 
         // Union type: value must match at least one variant
-        TypeAnnotation::Union(types) => types.iter().any(|t| matches_type(value, t)),
+        TypeAnnotation::Union(types) => types.iter().any(|t| matches_type(value, t, dims)),
 
         // Record type with structural typing
         TypeAnnotation::Record { fields } => match value {
-            Value::Record(actual_fields) => check_record_structural_type(fields, actual_fields),
+            Value::Record(actual_fields) => check_record_structural_type(fields, actual_fields, dims),
             _ => false,
         },
 
@@ -100,10 +160,10 @@ pub(crate) fn matches_type(value: &Value, expected: &TypeAnnotation) -> bool {
             shape,
         } => match value {
             Value::Tensor(tensor) => {
-                check_real_tensor_type(tensor, element_type, shape.as_ref())
+                check_real_tensor_type(tensor, element_type, shape.as_ref(), dims)
             }
             Value::ComplexTensor(tensor) => {
-                check_complex_tensor_type(tensor, element_type, shape.as_ref())
+                check_complex_tensor_type(tensor, element_type, shape.as_ref(), dims)
             }
             _ => false,
         },
@@ -111,9 +171,9 @@ pub(crate) fn matches_type(value: &Value, expected: &TypeAnnotation) -> bool {
         // Function type checking
         TypeAnnotation::Function {
             params,
-            return_type: _,
+            return_type,
         } => match value {
-            Value::Function(func) => check_function_type(func, params),
+            Value::Function(func) => check_function_type(func, params, return_type),
             _ => false,
         },
 
@@ -126,10 +186,16 @@ pub(crate) fn matches_type(value: &Value, expected: &TypeAnnotation) -> bool {
     }
 }
 
-/// Check if a Value can be assigned to a type (with coercion rules)
+/// Check if a Value can be assigned to a type (with subtyping and widening)
 ///
-/// This function is similar to `check_type` but returns a boolean and is intended
-/// for use cases where you need to check assignability without throwing errors.
+/// Unlike `check_type`/`matches_type`, which test exact structural
+/// compatibility, this infers the value's own type and asks whether it's a
+/// subtype of `expected` - so e.g. a `{name, age}` record is assignable
+/// where `{name}` is expected, per the record width subtyping in
+/// [`super::subtype::is_subtype`]. If that fails, it falls back to the
+/// widening coercion lattice in [`super::coerce`] - so e.g. a `Number` is
+/// assignable where a `Complex` is expected, even though neither is a
+/// subtype of the other.
 ///
 /// # Arguments
 /// * `value` - The runtime value to check
@@ -145,7 +211,36 @@ pub fn is_assignable(value: &Value, expected: &TypeAnnotation) -> bool {
         v => v,
     };
 
-    matches_type(actual_value, expected)
+    is_subtype(&infer_type(actual_value), expected) || coerce(actual_value, expected).is_ok()
+}
+
+/// Like [`check_type_detailed`], but falls back to the widening coercion
+/// lattice in [`super::coerce`] when an exact match fails, and returns the
+/// value a caller should actually store - the original value unchanged if
+/// it already matched, or the widened one otherwise. This is what lets
+/// `let z: Complex = 3` store a genuine `Complex` rather than silently
+/// keeping the original `Number` around under a `Complex` annotation,
+/// matching how a real type checker inserts a coercion node during
+/// checking instead of only validating.
+///
+/// # Arguments
+/// * `value` - The runtime value to check
+/// * `expected` - The expected type annotation
+///
+/// # Returns
+/// * `Ok(value)` - the value to store, coerced if necessary
+/// * `Err(TypeError)` if no exact match or coercion reaches `expected`
+pub fn check_type_coercing(value: &Value, expected: &TypeAnnotation) -> Result<Value, TypeError> {
+    // Auto-dereference MutableRef for type checking
+    let actual_value = match value {
+        Value::MutableRef(rc) => &*rc.borrow(),
+        v => v,
+    };
+
+    match check_type_detailed_impl(actual_value, expected, &mut DimEnv::new()) {
+        Ok(()) => Ok(actual_value.clone()),
+        Err(exact_err) => coerce(actual_value, expected).map_err(|_| exact_err),
+    }
 }
 
 /// Check if a Value matches a TypeAnnotation and return detailed error information
@@ -167,12 +262,152 @@ pub fn check_type_detailed(value: &Value, expected: &TypeAnnotation) -> Result<(
         v => v,
     };
 
-    if matches_type(actual_value, expected) {
-        Ok(())
-    } else {
-        Err(TypeError::new(
-            type_annotation_to_string(expected),
-            get_value_type_name(actual_value),
-        ))
+    check_type_detailed_impl(actual_value, expected, &mut DimEnv::new())
+}
+
+/// Like [`check_type_detailed`], but reuses a caller-supplied dimension
+/// environment instead of starting from an empty one - the `TypeError`
+/// counterpart of [`check_type_with_dims`], for callers (e.g. checking every
+/// argument of one function call against its parameter types) that want a
+/// structured error naming the conflicting dimension variable rather than a
+/// flattened string.
+pub fn check_type_detailed_with_dims(
+    value: &Value,
+    expected: &TypeAnnotation,
+    dims: &mut DimEnv,
+) -> Result<(), TypeError> {
+    // Auto-dereference MutableRef for type checking
+    let actual_value = match value {
+        Value::MutableRef(rc) => &*rc.borrow(),
+        v => v,
+    };
+
+    check_type_detailed_impl(actual_value, expected, dims)
+}
+
+/// Like [`matches_type`], but descends into `Record` fields and `Tensor`
+/// shape dimensions on failure, building up the [`PathSegment`] path to
+/// whichever sub-value actually disagreed instead of only reporting the
+/// outermost types.
+fn check_type_detailed_impl(
+    value: &Value,
+    expected: &TypeAnnotation,
+    dims: &mut DimEnv,
+) -> Result<(), TypeError> {
+    // Unlike `matches_type` (which lets an Error value satisfy anything to
+    // avoid cascading), `check_type_detailed` is the diagnostic-oriented
+    // entry point - surface the error's original message instead, since
+    // that's the first real problem rather than a downstream symptom of it.
+    // An `Error`-typed (or `Any`-typed) expectation is a genuine match, not
+    // a poisoned one, so those still succeed normally below.
+    if let Value::Error { message, .. } = value {
+        if !matches!(expected, TypeAnnotation::Error(_) | TypeAnnotation::Any) {
+            return Err(TypeError::new(type_annotation_to_string(expected), message.clone())
+                .with_context("value already carries a propagated error".to_string()));
+        }
+    }
+
+    match expected {
+        TypeAnnotation::Record { fields } => match value {
+            Value::Record(actual_fields) => {
+                for (field_name, (_is_mut, field_type)) in fields {
+                    match actual_fields.get(field_name) {
+                        Some(actual_value) => {
+                            check_type_detailed_impl(actual_value, field_type, dims)
+                                .map_err(|e| e.with_segment(PathSegment::Field(field_name.clone())))?;
+                        }
+                        None => {
+                            return Err(TypeError::new(
+                                type_annotation_to_string(field_type),
+                                "missing field".to_string(),
+                            )
+                            .with_segment(PathSegment::Field(field_name.clone())));
+                        }
+                    }
+                }
+                Ok(())
+            }
+            _ => Err(TypeError::new(
+                type_annotation_to_string(expected),
+                get_value_type_name(value),
+            )),
+        },
+
+        TypeAnnotation::Tensor {
+            element_type,
+            shape,
+        } => {
+            let (tensor_shape, element_matches) = match value {
+                Value::Tensor(tensor) => (
+                    tensor.shape(),
+                    matches!(element_type.as_ref(), TypeAnnotation::Number | TypeAnnotation::Any),
+                ),
+                Value::ComplexTensor(tensor) => (
+                    tensor.shape(),
+                    matches!(element_type.as_ref(), TypeAnnotation::Complex | TypeAnnotation::Any),
+                ),
+                _ => {
+                    return Err(TypeError::new(
+                        type_annotation_to_string(expected),
+                        get_value_type_name(value),
+                    ))
+                }
+            };
+
+            if !element_matches {
+                return Err(TypeError::new(
+                    type_annotation_to_string(expected),
+                    get_value_type_name(value),
+                ));
+            }
+
+            if let Some(expected_shape) = shape {
+                if expected_shape.len() != tensor_shape.len() {
+                    return Err(TypeError::new(
+                        type_annotation_to_string(expected),
+                        get_value_type_name(value),
+                    ));
+                }
+                if let Some((i, expected_extent, dim_name)) =
+                    first_mismatched_shape_dim(expected_shape, tensor_shape, dims)
+                {
+                    let mut err = TypeError::new(
+                        expected_extent.to_string(),
+                        tensor_shape[i].to_string(),
+                    )
+                    .with_segment(PathSegment::Index(i));
+                    if let Some(name) = dim_name {
+                        err = err.with_context(format!(
+                            "dimension variable '{}' (already bound to {}, saw {} here)",
+                            name, expected_extent, tensor_shape[i]
+                        ));
+                    }
+                    return Err(err);
+                }
+            }
+
+            Ok(())
+        }
+
+        TypeAnnotation::Function { params, return_type } => match value {
+            Value::Function(func) => check_function_type_detailed(func, params, return_type),
+            _ => Err(TypeError::new(
+                type_annotation_to_string(expected),
+                get_value_type_name(value),
+            )),
+        },
+
+        // Every other variant has no sub-structure worth descending into -
+        // fall back to the flat check used elsewhere.
+        _ => {
+            if matches_type(value, expected, dims) {
+                Ok(())
+            } else {
+                Err(TypeError::new(
+                    type_annotation_to_string(expected),
+                    get_value_type_name(value),
+                ))
+            }
+        }
     }
 }
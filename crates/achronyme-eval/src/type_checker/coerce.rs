@@ -0,0 +1,111 @@
+//! Widening coercions for the gradual type system
+//!
+//! Some values whose *exact* type differs from what's expected are still
+//! numerically fine to use in its place - e.g. a real `Number` where a
+//! `Complex` is wanted. `is_assignable` consults this lattice as a
+//! fallback when a value isn't already a (sub)type of what's expected;
+//! `coerce` exposes the actual value-level widening so callers can obtain
+//! the coerced value, not just learn that one exists. `check_type` stays
+//! an exact structural check and never consults this lattice.
+//!
+//! The lattice: `Integer ⟿ Number`; `Number ⟿ Complex` (imaginary part
+//! `0`); `Number ⟿ Tensor<Number, []>` / `Complex ⟿ Tensor<Complex, []>`
+//! (rank-0 tensor); an all-`Number` `Vector` ⟿ `Tensor<Number, [n]>`; and
+//! element-wise `RealTensor ⟿ ComplexTensor`. Everything past `Integer ⟿
+//! Number` and `Number ⟿ Tensor<Complex, ...>` isn't a rule of its own -
+//! both fall out of chaining the single-step rules above, since
+//! [`coerce`] re-applies the lattice until `target` is satisfied or no
+//! further step applies. There is deliberately no step that loses
+//! precision (e.g. no `Complex ⟿ Number`).
+
+use achronyme_parser::TypeAnnotation;
+use achronyme_types::complex::Complex;
+use achronyme_types::tensor::{ComplexTensor, RealTensor};
+use achronyme_types::value::Value;
+
+use super::checker::{matches_type, DimEnv};
+use super::display::{get_value_type_name, type_annotation_to_string};
+use super::error::TypeError;
+
+/// Widen `value` to `target` if the coercion lattice allows it, returning
+/// the coerced value so the caller (e.g. a `let` binding or argument
+/// pass) can store the widened form instead of the original. Already
+/// matching `target` is the identity coercion. Returns a [`TypeError`] if
+/// no chain of widening steps reaches `target`.
+pub fn coerce(value: &Value, target: &TypeAnnotation) -> Result<Value, TypeError> {
+    coerce_from(value, value, target)
+}
+
+/// Does the actual widening, but keeps `original` around across the
+/// recursion so a failing chain reports the type the caller actually
+/// passed in, not whatever intermediate value the lattice widened it to
+/// along the way (e.g. an `Integer` that widens to `Number` before
+/// failing to reach a non-numeric `target` should still report `Integer`,
+/// not `Number`, as the mismatched type).
+fn coerce_from(original: &Value, value: &Value, target: &TypeAnnotation) -> Result<Value, TypeError> {
+    if matches_type(value, target, &mut DimEnv::new()) {
+        return Ok(value.clone());
+    }
+
+    match widen_once(value, target) {
+        Some(widened) => coerce_from(original, &widened, target),
+        None => Err(TypeError::new(
+            type_annotation_to_string(target),
+            get_value_type_name(original),
+        )),
+    }
+}
+
+/// A single step of implicit widening toward `target`, or `None` if
+/// `value`'s shape has no applicable rule. `coerce` re-applies this until
+/// the result matches `target` outright, so a step doesn't need to reach
+/// `target` in one hop - e.g. `Number` widening toward `Tensor<Complex,
+/// ...>` takes this once to become a `Complex`, then once more to become
+/// a rank-0 `ComplexTensor`.
+fn widen_once(value: &Value, target: &TypeAnnotation) -> Option<Value> {
+    match (value, target) {
+        (Value::Number(n), TypeAnnotation::Complex) => Some(Value::Complex(Complex::from_real(*n))),
+
+        // Integer always widens to Number first, whatever the ultimate
+        // target is; `coerce`'s caller then re-applies this lattice to the
+        // resulting Number, so it reaches Complex/Tensor<Number>/Tensor<Complex>
+        // the same way a Number value would - this one step is the only
+        // Integer-specific rule the lattice needs.
+        (Value::Integer(n), _) => Some(Value::Number(*n as f64)),
+
+        (Value::Number(n), TypeAnnotation::Tensor { element_type, .. }) => match element_type.as_ref() {
+            TypeAnnotation::Number => RealTensor::new(vec![*n], vec![]).ok().map(Value::Tensor),
+            TypeAnnotation::Complex => Some(Value::Complex(Complex::from_real(*n))),
+            _ => None,
+        },
+
+        (Value::Complex(c), TypeAnnotation::Tensor { element_type, .. })
+            if matches!(element_type.as_ref(), TypeAnnotation::Complex) =>
+        {
+            ComplexTensor::new(vec![*c], vec![]).ok().map(Value::ComplexTensor)
+        }
+
+        (Value::Vector(items), TypeAnnotation::Tensor { element_type, .. })
+            if matches!(element_type.as_ref(), TypeAnnotation::Number)
+                && items.iter().all(|item| matches!(item, Value::Number(_))) =>
+        {
+            let data: Vec<f64> = items
+                .iter()
+                .map(|item| match item {
+                    Value::Number(n) => *n,
+                    _ => unreachable!("just checked every item is a Number"),
+                })
+                .collect();
+            let len = data.len();
+            RealTensor::new(data, vec![len]).ok().map(Value::Tensor)
+        }
+
+        (Value::Tensor(tensor), TypeAnnotation::Tensor { element_type, .. })
+            if matches!(element_type.as_ref(), TypeAnnotation::Complex) =>
+        {
+            Some(Value::ComplexTensor(tensor.to_complex()))
+        }
+
+        _ => None,
+    }
+}
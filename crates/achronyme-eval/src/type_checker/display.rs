@@ -7,6 +7,7 @@ use achronyme_types::value::Value;
 pub(crate) fn get_value_type_name(value: &Value) -> String {
     match value {
         Value::Number(_) => "Number".to_string(),
+        Value::Integer(_) => "Integer".to_string(),
         Value::Boolean(_) => "Boolean".to_string(),
         Value::String(_) => "String".to_string(),
         Value::Complex(_) => "Complex".to_string(),
@@ -20,10 +21,14 @@ pub(crate) fn get_value_type_name(value: &Value) -> String {
         }
         Value::Edge { .. } => "Edge".to_string(),
         Value::TailCall(_) => "TailCall (internal)".to_string(),
+        Value::Dual(_) => "Dual (internal)".to_string(),
         Value::EarlyReturn(_) => "EarlyReturn (internal)".to_string(),
         Value::MutableRef(_) => "MutableRef".to_string(),
         Value::Null => "null".to_string(),
-        Value::Generator(_) => "Generator".to_string(),
+        Value::Generator(gen_rc) => match &gen_rc.borrow().declared_yield_type {
+            Some(yield_type) => format!("Generator<{}>", type_annotation_to_string(yield_type)),
+            None => "Generator".to_string(),
+        },
         Value::GeneratorYield(_) => "GeneratorYield (internal)".to_string(),
         Value::Error { .. } => "Error".to_string(),
     }
@@ -33,16 +38,18 @@ pub(crate) fn get_value_type_name(value: &Value) -> String {
 pub(crate) fn type_annotation_to_string(ty: &TypeAnnotation) -> String {
     match ty {
         TypeAnnotation::Number => "Number".to_string(),
+        TypeAnnotation::Integer => "Integer".to_string(),
         TypeAnnotation::Boolean => "Boolean".to_string(),
         TypeAnnotation::String => "String".to_string(),
         TypeAnnotation::Complex => "Complex".to_string(),
         TypeAnnotation::Vector => "Vector".to_string(),
         TypeAnnotation::Edge => "Edge".to_string(),
-        TypeAnnotation::Generator => "Generator".to_string(),
-        TypeAnnotation::Error => "Error".to_string(),
+        TypeAnnotation::Error(_) => "Error".to_string(),
         TypeAnnotation::AnyFunction => "Function".to_string(),
         TypeAnnotation::Null => "null".to_string(),
         TypeAnnotation::Any => "Any".to_string(),
+        TypeAnnotation::Never => "Never".to_string(),
+        TypeAnnotation::TypeVar(id) => format!("'t{}", id),
 
         TypeAnnotation::Tensor {
             element_type,
@@ -54,7 +61,7 @@ pub(crate) fn type_annotation_to_string(ty: &TypeAnnotation) -> String {
                 Some(dims) => {
                     let dims_str = dims
                         .iter()
-                        .map(|d| d.map_or("_".to_string(), |n| n.to_string()))
+                        .map(|d| d.to_string())
                         .collect::<Vec<_>>()
                         .join(", ");
                     format!("Tensor<{}, [{}]>", elem_str, dims_str)
@@ -62,6 +69,11 @@ pub(crate) fn type_annotation_to_string(ty: &TypeAnnotation) -> String {
             }
         }
 
+        TypeAnnotation::Generator { element_type } => match element_type.as_ref() {
+            TypeAnnotation::Any => "Generator".to_string(),
+            elem => format!("Generator<{}>", type_annotation_to_string(elem)),
+        },
+
         TypeAnnotation::Record { fields } => {
             if fields.is_empty() {
                 "{}".to_string()
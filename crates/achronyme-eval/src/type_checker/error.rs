@@ -1,11 +1,43 @@
 //! Error types for type checking
 
+/// One step on the path from the value being checked down to the specific
+/// sub-value that disagreed - e.g. `[Field("address"), Field("street")]`
+/// for a mismatch inside `{ address: { street: String } }`, following the
+/// same approach dhall's typechecker uses to pinpoint the offending
+/// sub-expression rather than reporting only the outermost types.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathSegment {
+    /// A record field, e.g. `.address`.
+    Field(String),
+    /// A tensor shape dimension, e.g. `[1]` for a mismatch in the second
+    /// dimension's extent.
+    Index(usize),
+    /// A function's parameter at this position, e.g. `param[0]`.
+    Param(usize),
+    /// A function's return type.
+    Return,
+}
+
+impl std::fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PathSegment::Field(name) => write!(f, ".{}", name),
+            PathSegment::Index(i) => write!(f, "[{}]", i),
+            PathSegment::Param(i) => write!(f, "param[{}]", i),
+            PathSegment::Return => write!(f, "return"),
+        }
+    }
+}
+
 /// Error details for type mismatches
 #[derive(Debug, Clone, PartialEq)]
 pub struct TypeError {
     pub expected: String,
     pub actual: String,
     pub context: Option<String>,
+    /// Location of the mismatch relative to the value `check_type_detailed`
+    /// was originally called with. Empty for a mismatch at the top level.
+    pub path: Vec<PathSegment>,
 }
 
 impl TypeError {
@@ -14,6 +46,7 @@ impl TypeError {
             expected,
             actual,
             context: None,
+            path: Vec::new(),
         }
     }
 
@@ -21,10 +54,33 @@ impl TypeError {
         self.context = Some(context);
         self
     }
+
+    /// Prepend a path segment - used as a nested check's error propagates
+    /// back up through the field/dimension that led to it.
+    pub fn with_segment(mut self, segment: PathSegment) -> Self {
+        self.path.insert(0, segment);
+        self
+    }
 }
 
 impl std::fmt::Display for TypeError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.path.is_empty() {
+            let path_str: String = self.path.iter().map(PathSegment::to_string).collect();
+            return match &self.context {
+                Some(ctx) => write!(
+                    f,
+                    "at {} ({}): expected {}, got {}",
+                    path_str, ctx, self.expected, self.actual
+                ),
+                None => write!(
+                    f,
+                    "at {}: expected {}, got {}",
+                    path_str, self.expected, self.actual
+                ),
+            };
+        }
+
         match &self.context {
             Some(ctx) => write!(
                 f,
@@ -1,9 +1,12 @@
 //! Type inference for runtime values
 
-use achronyme_parser::TypeAnnotation;
+use achronyme_parser::{ShapeDim, TypeAnnotation};
+use achronyme_types::function::Function;
 use achronyme_types::value::Value;
 use std::collections::HashMap;
 
+use super::unify::UnificationTable;
+
 /// Infer the TypeAnnotation from a runtime Value
 ///
 /// This function attempts to infer the most specific type annotation
@@ -23,22 +26,35 @@ pub fn infer_type(value: &Value) -> TypeAnnotation {
 
     match actual_value {
         Value::Number(_) => TypeAnnotation::Number,
+        Value::Integer(_) => TypeAnnotation::Integer,
         Value::Boolean(_) => TypeAnnotation::Boolean,
         Value::String(_) => TypeAnnotation::String,
         Value::Complex(_) => TypeAnnotation::Complex,
         Value::Vector(_) => TypeAnnotation::Vector,
         Value::Null => TypeAnnotation::Null,
-        Value::Function(_) => TypeAnnotation::Function {
+        // Builtins are opaque (no stored signature); user-defined functions
+        // carry whatever param/return annotations they were declared with.
+        Value::Function(Function::Builtin(_)) => TypeAnnotation::Function {
+            params: vec![],
+            return_type: Box::new(TypeAnnotation::Any),
+        },
+        Value::Function(Function::UserDefined { param_types, return_type, .. }) => TypeAnnotation::Function {
+            params: param_types.clone(),
+            return_type: Box::new(return_type.clone().unwrap_or(TypeAnnotation::Any)),
+        },
+        // A curried partial's remaining signature isn't tracked on the value -
+        // same as `Builtin`, treat it as opaque.
+        Value::Function(Function::Partial { .. }) => TypeAnnotation::Function {
             params: vec![],
             return_type: Box::new(TypeAnnotation::Any),
         },
         Value::Tensor(t) => TypeAnnotation::Tensor {
             element_type: Box::new(TypeAnnotation::Number),
-            shape: Some(t.shape().iter().map(|&d| Some(d)).collect()),
+            shape: Some(t.shape().iter().map(|&d| ShapeDim::Fixed(d)).collect()),
         },
         Value::ComplexTensor(t) => TypeAnnotation::Tensor {
             element_type: Box::new(TypeAnnotation::Complex),
-            shape: Some(t.shape().iter().map(|&d| Some(d)).collect()),
+            shape: Some(t.shape().iter().map(|&d| ShapeDim::Fixed(d)).collect()),
         },
         Value::Record(fields) => {
             let type_fields: HashMap<String, (bool, TypeAnnotation)> = fields
@@ -52,13 +68,40 @@ pub fn infer_type(value: &Value) -> TypeAnnotation {
         Value::Edge { .. } => TypeAnnotation::Edge,
         // Internal values - should not appear in user code
         Value::TailCall(_) => TypeAnnotation::Any,
+        Value::Dual(_) => TypeAnnotation::Any,
         Value::EarlyReturn(_) => TypeAnnotation::Any,
         Value::MutableRef(_) => unreachable!("MutableRef should be dereferenced"),
-        // Generator type - represents an iterator
-        Value::Generator(_) => TypeAnnotation::Any, // TODO: Add Generator type annotation
+        // Generator type: reflects the declared yield type if the generator
+        // was created from a typed `fn ...: Generator<T>` context, and
+        // falls back to the gradual `Any` element otherwise.
+        Value::Generator(gen_rc) => TypeAnnotation::Generator {
+            element_type: Box::new(
+                gen_rc
+                    .borrow()
+                    .declared_yield_type
+                    .clone()
+                    .unwrap_or(TypeAnnotation::Any),
+            ),
+        },
         // GeneratorYield is internal marker - should not appear in type inference
         Value::GeneratorYield(_) => TypeAnnotation::Any,
-        // Error type - represents an error value
-        Value::Error { .. } => TypeAnnotation::Error,
+        // Error type - represents an error value, carrying its message.
+        Value::Error { message, .. } => TypeAnnotation::Error(message.clone()),
     }
 }
+
+/// Like [`infer_type`], but unifies the value's inferred concrete type
+/// against `expected` - which may still contain unification variables from
+/// `table` - refining `table`'s bindings and returning the zonked result.
+/// This is how a still-polymorphic declared annotation (e.g. a generic
+/// function's return type) gets pinned down once an actual runtime `Value`
+/// is produced for it.
+pub fn infer_type_with(
+    value: &Value,
+    expected: &TypeAnnotation,
+    table: &mut UnificationTable,
+) -> Result<TypeAnnotation, String> {
+    let actual = infer_type(value);
+    table.unify(expected, &actual)?;
+    Ok(table.zonk(expected))
+}
@@ -7,21 +7,60 @@
 //! Key features:
 //! - Union type support (value must match ANY type in the union)
 //! - Structural typing for Records (extra fields are allowed)
-//! - Tensor shape checking (optional shape constraints)
+//! - Tensor shape checking (optional shape constraints, including named
+//!   dimension variables shared across a checking scope - see `DimEnv`)
 //! - Any type (always matches - opt-out of type checking)
 //! - Null type support for optional values
 //! - Automatic dereferencing of MutableRef values
+//! - Bidirectional static checking of `AstNode`s before they run (see `static_check`)
+//! - Hindley-Milner inference with unification variables for unannotated
+//!   code, so call sites can pin down a lambda's parameter types (see
+//!   `unify`); exposed to user code through the `infer_types()` builtin
+//!   (`handlers::debug::handle_infer_types`)
+//! - `generalize`/`instantiate` on `UnificationTable` for reusing a
+//!   polymorphic function's type at multiple concrete call sites, and
+//!   `infer_type_with` for refining a `Var`-carrying expected annotation
+//!   against a concrete runtime `Value`
+//! - Path-carrying errors from `check_type_detailed`, pinpointing exactly
+//!   which record field, tensor dimension, function parameter, or function
+//!   return type disagreed (see `PathSegment`)
+//! - A widening coercion lattice (`Number` to `Complex`, `RealTensor` to
+//!   `ComplexTensor`, and more) consulted by `is_assignable`, with `coerce`
+//!   exposing the actual value-level conversion and `check_type_coercing`
+//!   folding it into a checking pass that returns the value to store (see
+//!   `coerce`)
+//! - `Never`, the bottom type, a subtype of everything (see `is_subtype`)
+//! - `Error(String)`, which propagates silently through `check_type` and
+//!   `is_assignable` so one already-reported failure doesn't cascade into
+//!   unrelated mismatches downstream; `check_type_detailed` surfaces the
+//!   original message instead of swallowing it, since it's meant to explain
+//!   the problem rather than stay permissive
+//! - `normalize_union`, flattening nested unions and dropping `Never`
+//!   members that don't change what a union accepts
 
 mod checker;
+mod coerce;
 mod display;
 mod error;
 mod inference;
+mod static_check;
+mod subtype;
+mod unify;
 mod validators;
 
 #[cfg(test)]
 mod tests;
 
 // Re-export public API
-pub use checker::{check_type, check_type_detailed, is_assignable};
-pub use error::TypeError;
-pub use inference::infer_type;
+pub use checker::{
+    check_type, check_type_coercing, check_type_detailed, check_type_detailed_with_dims, check_type_with_dims,
+    is_assignable, DimEnv,
+};
+pub use coerce::coerce;
+pub(crate) use display::type_annotation_to_string;
+pub use error::{PathSegment, TypeError};
+pub use inference::{infer_type, infer_type_with};
+pub use static_check::{check_node, infer_node};
+pub use subtype::{has_redundant_arm, is_exhaustive, is_subtype, narrow, normalize_union};
+pub use unify::{infer_program, UnificationTable};
+pub use validators::merge_record_types;
@@ -0,0 +1,246 @@
+//! Bidirectional static type checking over AST nodes
+//!
+//! Unlike `checker::check_type`, which validates an already-evaluated
+//! `Value`, this module validates an `AstNode` *before* it runs. It follows
+//! the usual bidirectional discipline: `infer_node` synthesizes a type from
+//! a node with no expected type in hand, and `check_node` verifies a node
+//! against an expected type, pushing annotations into `ctx` as it descends
+//! into binders (lambda parameters). Where there's no specific checking
+//! rule for a node, `check_node` falls back to synthesis followed by an
+//! assignability check.
+//!
+//! `ctx` only tracks variables whose type is statically known (annotated
+//! parameters, typed bindings). A lookup miss is not an error - it means
+//! the variable's type isn't tracked yet - so it synthesizes to `Any` and
+//! checking against it always succeeds, consistent with gradual typing.
+
+use std::collections::HashMap;
+
+use achronyme_parser::ast::{AstNode, BinaryOp};
+use achronyme_parser::TypeAnnotation;
+
+use super::display::type_annotation_to_string;
+use super::subtype::is_subtype;
+
+type Ctx = HashMap<String, TypeAnnotation>;
+
+/// Synthesize a type for `node` (synthesis/"infer" mode).
+pub fn infer_node(node: &AstNode, ctx: &Ctx) -> Result<TypeAnnotation, String> {
+    match node {
+        AstNode::Number(_) => Ok(TypeAnnotation::Number),
+        AstNode::Integer(_) => Ok(TypeAnnotation::Integer),
+        AstNode::Boolean(_) => Ok(TypeAnnotation::Boolean),
+        AstNode::StringLiteral(_) => Ok(TypeAnnotation::String),
+        AstNode::ComplexLiteral { .. } => Ok(TypeAnnotation::Complex),
+        AstNode::Null => Ok(TypeAnnotation::Null),
+
+        // A variable only has a synthesized type if we're tracking it;
+        // otherwise it's gradually typed and treated as Any.
+        AstNode::VariableRef(name) => Ok(ctx.get(name).cloned().unwrap_or(TypeAnnotation::Any)),
+
+        AstNode::BinaryOp { op, left, right } => infer_binary_op(op, left, right, ctx),
+
+        AstNode::If { then_expr, else_expr, .. } => {
+            let then_ty = infer_node(then_expr, ctx)?;
+            let else_ty = infer_node(else_expr, ctx)?;
+            if is_subtype(&then_ty, &else_ty) {
+                Ok(else_ty)
+            } else if is_subtype(&else_ty, &then_ty) {
+                Ok(then_ty)
+            } else {
+                Ok(TypeAnnotation::Union(vec![then_ty, else_ty]))
+            }
+        }
+
+        AstNode::FunctionCall { name, args } => match ctx.get(name).cloned() {
+            Some(TypeAnnotation::Function { params, return_type }) => {
+                check_call_args(name, args, &params, ctx)?;
+                Ok(*return_type)
+            }
+            Some(other) => Err(format!(
+                "'{}' is not callable (has type {})",
+                name,
+                type_annotation_to_string(&other)
+            )),
+            // Builtins and other untyped callees aren't tracked in ctx.
+            None => Ok(TypeAnnotation::Any),
+        },
+
+        AstNode::CallExpression { callee, args } => match infer_node(callee, ctx)? {
+            TypeAnnotation::Function { params, return_type } => {
+                check_call_args("<callee>", args, &params, ctx)?;
+                Ok(*return_type)
+            }
+            TypeAnnotation::Any | TypeAnnotation::AnyFunction => Ok(TypeAnnotation::Any),
+            other => Err(format!(
+                "Cannot call a value of type {}",
+                type_annotation_to_string(&other)
+            )),
+        },
+
+        AstNode::Pipe { left, right } => infer_node(&desugar_pipe(left, right), ctx),
+
+        AstNode::Lambda { params, return_type, body } => {
+            let mut body_ctx = ctx.clone();
+            let param_types: Vec<Option<TypeAnnotation>> = params
+                .iter()
+                .map(|(name, ty)| {
+                    if let Some(ty) = ty {
+                        body_ctx.insert(name.clone(), ty.clone());
+                    }
+                    ty.clone()
+                })
+                .collect();
+
+            let return_ty = match return_type {
+                Some(ty) => {
+                    check_node(body, ty, &body_ctx)?;
+                    ty.clone()
+                }
+                None => infer_node(body, &body_ctx)?,
+            };
+
+            Ok(TypeAnnotation::Function {
+                params: param_types,
+                return_type: Box::new(return_ty),
+            })
+        }
+
+        // No specific synthesis rule - gradual typing treats this as Any.
+        _ => Ok(TypeAnnotation::Any),
+    }
+}
+
+/// Check `node` against `expected` (checking mode).
+pub fn check_node(node: &AstNode, expected: &TypeAnnotation, ctx: &Ctx) -> Result<(), String> {
+    if let AstNode::Lambda { params, return_type, body } = node {
+        if let TypeAnnotation::Function { params: expected_params, return_type: expected_return } = expected {
+            if params.len() != expected_params.len() {
+                return Err(format!(
+                    "Expected a lambda with {} parameter(s), got {}",
+                    expected_params.len(),
+                    params.len()
+                ));
+            }
+
+            let mut body_ctx = ctx.clone();
+            for ((name, declared), expected_param) in params.iter().zip(expected_params.iter()) {
+                if let Some(ty) = declared.clone().or_else(|| expected_param.clone()) {
+                    body_ctx.insert(name.clone(), ty);
+                }
+            }
+
+            let expected_body_return = return_type.clone().unwrap_or_else(|| (**expected_return).clone());
+            return check_node(body, &expected_body_return, &body_ctx);
+        }
+
+        return Err(format!(
+            "Expected {}, got a lambda expression",
+            type_annotation_to_string(expected)
+        ));
+    }
+
+    // No specific checking rule: synthesize, then check assignability.
+    let actual = infer_node(node, ctx)?;
+    if is_subtype(&actual, expected) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Type mismatch: expected {}, got {}",
+            type_annotation_to_string(expected),
+            type_annotation_to_string(&actual)
+        ))
+    }
+}
+
+/// Check call arguments against declared parameter types, skipping
+/// parameters left untyped (gradual typing opts them out of checking).
+fn check_call_args(
+    callee_name: &str,
+    args: &[AstNode],
+    params: &[Option<TypeAnnotation>],
+    ctx: &Ctx,
+) -> Result<(), String> {
+    if !params.is_empty() && args.len() != params.len() {
+        return Err(format!(
+            "'{}' expects {} argument(s), got {}",
+            callee_name,
+            params.len(),
+            args.len()
+        ));
+    }
+
+    for (i, (arg, param_ty)) in args.iter().zip(params.iter()).enumerate() {
+        if let Some(param_ty) = param_ty {
+            check_node(arg, param_ty, ctx)
+                .map_err(|err| format!("argument {} of '{}': {}", i + 1, callee_name, err))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Infer the result type of an arithmetic/comparison/logical binary op.
+fn infer_binary_op(op: &BinaryOp, left: &AstNode, right: &AstNode, ctx: &Ctx) -> Result<TypeAnnotation, String> {
+    let left_ty = infer_node(left, ctx)?;
+    let right_ty = infer_node(right, ctx)?;
+
+    match op {
+        BinaryOp::Add | BinaryOp::Subtract | BinaryOp::Multiply | BinaryOp::Divide | BinaryOp::Power | BinaryOp::Modulo => {
+            if !is_numeric_type(&left_ty) {
+                return Err(format!(
+                    "Left-hand side of arithmetic operation must be numeric, got {}",
+                    type_annotation_to_string(&left_ty)
+                ));
+            }
+            if !is_numeric_type(&right_ty) {
+                return Err(format!(
+                    "Right-hand side of arithmetic operation must be numeric, got {}",
+                    type_annotation_to_string(&right_ty)
+                ));
+            }
+
+            if matches!(left_ty, TypeAnnotation::Complex) || matches!(right_ty, TypeAnnotation::Complex) {
+                Ok(TypeAnnotation::Complex)
+            } else if matches!(op, BinaryOp::Divide) {
+                // Division isn't closed over the integers even when both sides are.
+                Ok(TypeAnnotation::Number)
+            } else if matches!(left_ty, TypeAnnotation::Integer) && matches!(right_ty, TypeAnnotation::Integer) {
+                Ok(TypeAnnotation::Integer)
+            } else {
+                Ok(TypeAnnotation::Number)
+            }
+        }
+
+        BinaryOp::Gt | BinaryOp::Lt | BinaryOp::Gte | BinaryOp::Lte | BinaryOp::Eq | BinaryOp::Neq | BinaryOp::And | BinaryOp::Or => {
+            Ok(TypeAnnotation::Boolean)
+        }
+    }
+}
+
+fn is_numeric_type(ty: &TypeAnnotation) -> bool {
+    matches!(ty, TypeAnnotation::Number | TypeAnnotation::Integer | TypeAnnotation::Complex | TypeAnnotation::Any)
+}
+
+/// Desugar `left |> right` into the call it rewrites to, mirroring
+/// `Evaluator`'s own pipe desugaring so both stages agree on semantics.
+fn desugar_pipe(left: &AstNode, right: &AstNode) -> AstNode {
+    match right {
+        AstNode::FunctionCall { name, args } => {
+            let mut piped_args = Vec::with_capacity(args.len() + 1);
+            piped_args.push(left.clone());
+            piped_args.extend_from_slice(args);
+            AstNode::FunctionCall { name: name.clone(), args: piped_args }
+        }
+        AstNode::CallExpression { callee, args } => {
+            let mut piped_args = Vec::with_capacity(args.len() + 1);
+            piped_args.push(left.clone());
+            piped_args.extend_from_slice(args);
+            AstNode::CallExpression { callee: callee.clone(), args: piped_args }
+        }
+        _ => AstNode::CallExpression {
+            callee: Box::new(right.clone()),
+            args: vec![left.clone()],
+        },
+    }
+}
@@ -0,0 +1,270 @@
+//! Structural subtyping lattice for `TypeAnnotation`
+//!
+//! `Never <: T <: Any` for every `T`; `Any` is additionally treated as a
+//! *bottom* as well as a top, so `Any <: T` also holds - this is gradual
+//! typing's consistency relation rather than strict soundness, matching
+//! `Any`'s role elsewhere as an opt-out of checking. `Number <: Complex`
+//! mirrors the widening coercion in [`super::coerce`]. Records get
+//! width+depth subtyping (a record with more fields is a subtype of one
+//! with fewer, and each shared field's type must itself be a subtype),
+//! except `mut` fields, which are invariant - a `mut` field's type must
+//! match exactly in both directions, since a mutable binding can be written
+//! back through either view of it. `Tensor` element types are covariant;
+//! and functions are contravariant in their parameters and covariant in
+//! their return type.
+
+use achronyme_parser::{ShapeDim, TypeAnnotation};
+
+/// Is `sub` a subtype of `sup`? (`sub <: sup`, i.e. a `sub` value can be
+/// used wherever a `sup` is expected.)
+pub fn is_subtype(sub: &TypeAnnotation, sup: &TypeAnnotation) -> bool {
+    if matches!(sub, TypeAnnotation::Never) {
+        return true;
+    }
+    if matches!(sup, TypeAnnotation::Any) || matches!(sub, TypeAnnotation::Any) {
+        return true;
+    }
+    // An Error-typed value already carries whatever failure produced it;
+    // letting it satisfy (or be satisfied by) anything here keeps that
+    // failure from cascading into a fresh, unrelated assignability error.
+    if matches!(sup, TypeAnnotation::Error(_)) || matches!(sub, TypeAnnotation::Error(_)) {
+        return true;
+    }
+    if sub == sup {
+        return true;
+    }
+    if matches!((sub, sup), (TypeAnnotation::Number, TypeAnnotation::Complex)) {
+        return true;
+    }
+    // Integer widens to Number (and transitively to Complex): every whole
+    // number is a number, mirroring Number <: Complex above.
+    if matches!(
+        (sub, sup),
+        (TypeAnnotation::Integer, TypeAnnotation::Number)
+            | (TypeAnnotation::Integer, TypeAnnotation::Complex)
+    ) {
+        return true;
+    }
+
+    match sup {
+        TypeAnnotation::Union(sup_types) => return sup_types.iter().any(|t| is_subtype(sub, t)),
+        TypeAnnotation::AnyFunction => return matches!(sub, TypeAnnotation::Function { .. }),
+        _ => {}
+    }
+
+    if let TypeAnnotation::Union(sub_types) = sub {
+        return sub_types.iter().all(|t| is_subtype(t, sup));
+    }
+
+    match (sub, sup) {
+        // Width+depth record subtyping: sub may have extra fields, but
+        // every field sup requires must be present in sub with a subtype.
+        // A field that's `mut` on either side is invariant: its type must
+        // be a subtype in both directions (i.e. equivalent).
+        (TypeAnnotation::Record { fields: sub_fields }, TypeAnnotation::Record { fields: sup_fields }) => {
+            sup_fields.iter().all(|(name, (sup_is_mut, sup_field_ty))| {
+                sub_fields.get(name).map_or(false, |(sub_is_mut, sub_field_ty)| {
+                    if *sup_is_mut || *sub_is_mut {
+                        is_subtype(sub_field_ty, sup_field_ty) && is_subtype(sup_field_ty, sub_field_ty)
+                    } else {
+                        is_subtype(sub_field_ty, sup_field_ty)
+                    }
+                })
+            })
+        }
+
+        // Covariant element type; sup's shape constraint (if any) must be
+        // met exactly by sub's (more specific) shape.
+        (
+            TypeAnnotation::Tensor { element_type: sub_elem, shape: sub_shape },
+            TypeAnnotation::Tensor { element_type: sup_elem, shape: sup_shape },
+        ) => is_subtype(sub_elem, sup_elem) && shape_is_subtype(sub_shape.as_ref(), sup_shape.as_ref()),
+
+        // A generator can only be read from, so its yielded element type is
+        // covariant like Tensor's.
+        (
+            TypeAnnotation::Generator { element_type: sub_elem },
+            TypeAnnotation::Generator { element_type: sup_elem },
+        ) => is_subtype(sub_elem, sup_elem),
+
+        (
+            TypeAnnotation::Function { params: sub_params, return_type: sub_ret },
+            TypeAnnotation::Function { params: sup_params, return_type: sup_ret },
+        ) => function_is_subtype(sub_params, sub_ret, sup_params, sup_ret),
+
+        _ => false,
+    }
+}
+
+/// Narrow a scrutinee's union type by removing every variant covered by a
+/// matched arm. Non-`Union` scrutinees are treated as a single-variant
+/// union: fully covered (`matched` a supertype) narrows to `Union(vec![])`,
+/// otherwise the scrutinee passes through unchanged. A remaining single
+/// variant collapses back to a bare type rather than a one-element `Union`,
+/// and full coverage narrows to the empty marker `Union(vec![])`. `Any` is
+/// never narrowed away by a finite `matched` type, matching its role as
+/// gradual typing's top (and bottom).
+pub fn narrow(union: &TypeAnnotation, matched: &TypeAnnotation) -> TypeAnnotation {
+    if matches!(union, TypeAnnotation::Any) {
+        return TypeAnnotation::Any;
+    }
+
+    let variants: Vec<TypeAnnotation> = match union {
+        TypeAnnotation::Union(types) => types.clone(),
+        other => vec![other.clone()],
+    };
+
+    let remaining: Vec<TypeAnnotation> = variants
+        .into_iter()
+        .filter(|variant| !is_subtype(variant, matched))
+        .collect();
+
+    match remaining.len() {
+        1 => remaining.into_iter().next().unwrap(),
+        _ => TypeAnnotation::Union(remaining),
+    }
+}
+
+/// Is the empty-union marker `narrow` settles on once every variant has been
+/// covered.
+fn is_exhausted(ty: &TypeAnnotation) -> bool {
+    matches!(ty, TypeAnnotation::Union(types) if types.is_empty())
+}
+
+/// Fold `narrow` across `arms` in declaration order and report whether every
+/// variant of `union` ends up covered. On failure, returns the uncovered
+/// variants (in their original order) so the evaluator can name exactly
+/// which union members a `match` is missing. `Any` is never exhaustible by a
+/// finite arm list.
+pub fn is_exhaustive(union: &TypeAnnotation, arms: &[TypeAnnotation]) -> Result<(), Vec<TypeAnnotation>> {
+    let mut remaining = union.clone();
+    if is_exhausted(&remaining) {
+        return Ok(());
+    }
+    for arm in arms {
+        remaining = narrow(&remaining, arm);
+        if is_exhausted(&remaining) {
+            return Ok(());
+        }
+    }
+
+    if matches!(remaining, TypeAnnotation::Any) {
+        return Err(vec![TypeAnnotation::Any]);
+    }
+
+    Err(match remaining {
+        TypeAnnotation::Union(types) => types,
+        other => vec![other],
+    })
+}
+
+/// Does any arm in `arms` fail to narrow the union any further than the
+/// previous arms already had - i.e. is it fully subsumed by what came
+/// before, so matching against it can never run? Reported separately from
+/// [`is_exhaustive`] since a redundant arm isn't itself an exhaustiveness
+/// failure.
+pub fn has_redundant_arm(union: &TypeAnnotation, arms: &[TypeAnnotation]) -> bool {
+    let mut remaining = union.clone();
+    for arm in arms {
+        // Everything was already covered by an earlier arm - this (and any
+        // further) arm is unreachable.
+        if is_exhausted(&remaining) {
+            return true;
+        }
+
+        let narrowed = narrow(&remaining, arm);
+        if narrowed == remaining {
+            return true;
+        }
+        remaining = narrowed;
+    }
+    false
+}
+
+/// Simplify a `Union` by flattening nested unions and dropping `Never`
+/// members - `Never` is the bottom type, so including it in a union changes
+/// nothing about what the union accepts, same as how [`is_subtype`] already
+/// treats it. A union that simplifies to a single member collapses to that
+/// bare type rather than a one-element `Union`, mirroring [`narrow`]; one
+/// that simplifies to no members at all becomes the empty-union marker
+/// `Union(vec![])` that [`is_exhausted`] recognizes. Non-`Union` types pass
+/// through unchanged - this only touches the outermost union, not types
+/// nested inside `Record`/`Tensor`/`Function` members.
+pub fn normalize_union(ty: &TypeAnnotation) -> TypeAnnotation {
+    let TypeAnnotation::Union(members) = ty else {
+        return ty.clone();
+    };
+
+    let mut flattened = Vec::new();
+    flatten_union_members(members, &mut flattened);
+
+    match flattened.len() {
+        1 => flattened.into_iter().next().unwrap(),
+        _ => TypeAnnotation::Union(flattened),
+    }
+}
+
+fn flatten_union_members(members: &[TypeAnnotation], out: &mut Vec<TypeAnnotation>) {
+    for member in members {
+        match member {
+            TypeAnnotation::Never => {}
+            TypeAnnotation::Union(nested) => flatten_union_members(nested, out),
+            other => {
+                if !out.contains(other) {
+                    out.push(other.clone());
+                }
+            }
+        }
+    }
+}
+
+fn shape_is_subtype(sub_shape: Option<&Vec<ShapeDim>>, sup_shape: Option<&Vec<ShapeDim>>) -> bool {
+    match sup_shape {
+        // sup doesn't constrain shape - anything matches.
+        None => true,
+        Some(sup_dims) => match sub_shape {
+            // sub is less specific than what sup requires.
+            None => false,
+            Some(sub_dims) => {
+                sub_dims.len() == sup_dims.len()
+                    && sub_dims.iter().zip(sup_dims.iter()).all(|(sub_dim, sup_dim)| {
+                        // A named variable or wildcard on sup's side doesn't
+                        // constrain sub; cross-tensor consistency between
+                        // occurrences of the same name is enforced by the
+                        // dimension environment in `validators`, not here.
+                        match sup_dim {
+                            ShapeDim::Any | ShapeDim::Var(_) => true,
+                            ShapeDim::Fixed(_) => sub_dim == sup_dim,
+                        }
+                    })
+            }
+        },
+    }
+}
+
+/// Contravariant in parameters, covariant in return type. An empty
+/// parameter list on either side represents an opaque/unchecked signature
+/// (e.g. a builtin) and is treated as compatible with any arity.
+fn function_is_subtype(
+    sub_params: &[Option<TypeAnnotation>],
+    sub_ret: &TypeAnnotation,
+    sup_params: &[Option<TypeAnnotation>],
+    sup_ret: &TypeAnnotation,
+) -> bool {
+    if sub_params.is_empty() || sup_params.is_empty() {
+        return is_subtype(sub_ret, sup_ret);
+    }
+
+    if sub_params.len() != sup_params.len() {
+        return false;
+    }
+
+    let params_ok = sub_params.iter().zip(sup_params.iter()).all(|(sub_p, sup_p)| {
+        let sub_p = sub_p.clone().unwrap_or(TypeAnnotation::Any);
+        let sup_p = sup_p.clone().unwrap_or(TypeAnnotation::Any);
+        // sup's param type must accept everything sub's param type accepts.
+        is_subtype(&sup_p, &sub_p)
+    });
+
+    params_ok && is_subtype(sub_ret, sup_ret)
+}
@@ -2,8 +2,14 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::type_checker::{check_type, check_type_detailed, infer_type, is_assignable};
-    use achronyme_parser::TypeAnnotation;
+    use crate::type_checker::{
+        check_node, check_type, check_type_coercing, check_type_detailed, check_type_detailed_with_dims,
+        check_type_with_dims, coerce, has_redundant_arm, infer_node, infer_program, infer_type, infer_type_with,
+        is_assignable, is_exhaustive, is_subtype, merge_record_types, narrow, normalize_union, DimEnv, PathSegment,
+        UnificationTable,
+    };
+    use achronyme_parser::ast::AstNode;
+    use achronyme_parser::{ShapeDim, TypeAnnotation};
     use achronyme_types::complex::Complex;
     use achronyme_types::function::Function;
     use achronyme_types::tensor::{ComplexTensor, RealTensor};
@@ -62,6 +68,31 @@ mod tests {
         assert!(check_type(&number, &TypeAnnotation::Null).is_err());
     }
 
+    #[test]
+    fn test_generator_type() {
+        use achronyme_types::value::GeneratorState;
+
+        let env = achronyme_types::Environment::new();
+        let untyped = Value::Generator(Rc::new(RefCell::new(GeneratorState::new(
+            env.clone(),
+            vec![],
+        ))));
+        // Untyped generator: gradual fallback matches any declared element type.
+        assert!(check_type(&untyped, &TypeAnnotation::Generator { element_type: Box::new(TypeAnnotation::Any) }).is_ok());
+        assert!(check_type(&untyped, &TypeAnnotation::Generator { element_type: Box::new(TypeAnnotation::Number) }).is_ok());
+        assert!(check_type(&untyped, &TypeAnnotation::Number).is_err());
+
+        let typed = Value::Generator(Rc::new(RefCell::new(GeneratorState::new_typed(
+            env,
+            vec![],
+            TypeAnnotation::Number,
+        ))));
+        assert!(check_type(&typed, &TypeAnnotation::Generator { element_type: Box::new(TypeAnnotation::Number) }).is_ok());
+        assert!(check_type(&typed, &TypeAnnotation::Generator { element_type: Box::new(TypeAnnotation::String) }).is_err());
+        // Number <: Complex widening also holds through the generator's element type.
+        assert!(check_type(&typed, &TypeAnnotation::Generator { element_type: Box::new(TypeAnnotation::Complex) }).is_ok());
+    }
+
     #[test]
     fn test_any_type_matches_everything() {
         assert!(check_type(&Value::Number(42.0), &TypeAnnotation::Any).is_ok());
@@ -241,7 +272,7 @@ mod tests {
 
         let tensor_type = TypeAnnotation::Tensor {
             element_type: Box::new(TypeAnnotation::Number),
-            shape: Some(vec![Some(2), Some(3)]),
+            shape: Some(vec![ShapeDim::Fixed(2), ShapeDim::Fixed(3)]),
         };
 
         assert!(check_type(&value, &tensor_type).is_ok());
@@ -249,7 +280,7 @@ mod tests {
         // Wrong shape should fail
         let wrong_shape_type = TypeAnnotation::Tensor {
             element_type: Box::new(TypeAnnotation::Number),
-            shape: Some(vec![Some(3), Some(2)]),
+            shape: Some(vec![ShapeDim::Fixed(3), ShapeDim::Fixed(2)]),
         };
         assert!(check_type(&value, &wrong_shape_type).is_err());
     }
@@ -262,7 +293,7 @@ mod tests {
         // Wildcard for first dimension
         let tensor_type = TypeAnnotation::Tensor {
             element_type: Box::new(TypeAnnotation::Number),
-            shape: Some(vec![None, Some(3)]), // [_, 3]
+            shape: Some(vec![ShapeDim::Any, ShapeDim::Fixed(3)]), // [_, 3]
         };
 
         assert!(check_type(&value, &tensor_type).is_ok());
@@ -270,7 +301,7 @@ mod tests {
         // Wildcard for second dimension
         let tensor_type2 = TypeAnnotation::Tensor {
             element_type: Box::new(TypeAnnotation::Number),
-            shape: Some(vec![Some(2), None]), // [2, _]
+            shape: Some(vec![ShapeDim::Fixed(2), ShapeDim::Any]), // [2, _]
         };
 
         assert!(check_type(&value, &tensor_type2).is_ok());
@@ -278,7 +309,7 @@ mod tests {
         // All wildcards
         let tensor_type3 = TypeAnnotation::Tensor {
             element_type: Box::new(TypeAnnotation::Number),
-            shape: Some(vec![None, None]), // [_, _]
+            shape: Some(vec![ShapeDim::Any, ShapeDim::Any]), // [_, _]
         };
 
         assert!(check_type(&value, &tensor_type3).is_ok());
@@ -291,7 +322,7 @@ mod tests {
 
         let tensor_type = TypeAnnotation::Tensor {
             element_type: Box::new(TypeAnnotation::Number),
-            shape: Some(vec![Some(3), Some(1)]), // Expects 2D
+            shape: Some(vec![ShapeDim::Fixed(3), ShapeDim::Fixed(1)]), // Expects 2D
         };
 
         assert!(check_type(&value, &tensor_type).is_err());
@@ -321,6 +352,48 @@ mod tests {
         assert!(check_type(&value, &wrong_element_type).is_err());
     }
 
+    #[test]
+    fn test_named_dim_binds_on_first_occurrence() {
+        // Tensor[N] against a length-3 tensor binds N=3, and a second,
+        // independent check_type call doesn't see that binding.
+        let tensor_type = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Number),
+            shape: Some(vec![ShapeDim::Var("N".to_string())]),
+        };
+
+        let three = Value::Tensor(RealTensor::new(vec![1.0, 2.0, 3.0], vec![3]).unwrap());
+        let four = Value::Tensor(RealTensor::new(vec![1.0, 2.0, 3.0, 4.0], vec![4]).unwrap());
+
+        assert!(check_type(&three, &tensor_type).is_ok());
+        assert!(check_type(&four, &tensor_type).is_ok());
+    }
+
+    #[test]
+    fn test_named_dim_agrees_within_a_shared_scope() {
+        // matmul-style check: (Tensor[N,M], Tensor[M,K]) sharing one DimEnv
+        // requires both tensors' middle dimension to agree.
+        let left_type = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Number),
+            shape: Some(vec![ShapeDim::Var("N".to_string()), ShapeDim::Var("M".to_string())]),
+        };
+        let right_type = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Number),
+            shape: Some(vec![ShapeDim::Var("M".to_string()), ShapeDim::Var("K".to_string())]),
+        };
+
+        let left = Value::Tensor(RealTensor::new(vec![0.0; 6], vec![2, 3]).unwrap()); // N=2, M=3
+        let agreeing_right = Value::Tensor(RealTensor::new(vec![0.0; 12], vec![3, 4]).unwrap()); // M=3, K=4
+        let mismatched_right = Value::Tensor(RealTensor::new(vec![0.0; 8], vec![4, 2]).unwrap()); // M=4, mismatch
+
+        let mut dims = DimEnv::new();
+        assert!(check_type_with_dims(&left, &left_type, &mut dims).is_ok());
+        assert!(check_type_with_dims(&agreeing_right, &right_type, &mut dims).is_ok());
+
+        let mut dims = DimEnv::new();
+        assert!(check_type_with_dims(&left, &left_type, &mut dims).is_ok());
+        assert!(check_type_with_dims(&mismatched_right, &right_type, &mut dims).is_err());
+    }
+
     #[test]
     fn test_function_type_basic() {
         let func = create_test_function(2);
@@ -365,6 +438,319 @@ mod tests {
         assert!(check_type(&value, &func_type).is_ok());
     }
 
+    #[test]
+    fn test_function_type_checks_declared_signature() {
+        let env = achronyme_types::Environment::new();
+        let typed_func = Function::new_typed(
+            vec!["x".to_string()],
+            vec![Some(TypeAnnotation::Number)],
+            Some(TypeAnnotation::Number),
+            AstNode::Number(0.0),
+            Rc::new(RefCell::new(env)),
+        );
+        let value = Value::Function(typed_func);
+
+        // Matches its declared (Number) -> Number signature.
+        assert!(check_type(
+            &value,
+            &TypeAnnotation::Function {
+                params: vec![Some(TypeAnnotation::Number)],
+                return_type: Box::new(TypeAnnotation::Number),
+            }
+        )
+        .is_ok());
+
+        // A declared return of Number is not a subtype of String.
+        assert!(check_type(
+            &value,
+            &TypeAnnotation::Function {
+                params: vec![Some(TypeAnnotation::Number)],
+                return_type: Box::new(TypeAnnotation::String),
+            }
+        )
+        .is_err());
+
+        // Number <: Complex, so a declared Number return also satisfies a
+        // Complex-returning annotation (covariant return).
+        assert!(check_type(
+            &value,
+            &TypeAnnotation::Function {
+                params: vec![Some(TypeAnnotation::Number)],
+                return_type: Box::new(TypeAnnotation::Complex),
+            }
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_check_type_detailed_names_the_offending_function_param() {
+        let env = achronyme_types::Environment::new();
+        let typed_func = Function::new_typed(
+            vec!["x".to_string(), "y".to_string()],
+            vec![Some(TypeAnnotation::Number), Some(TypeAnnotation::String)],
+            Some(TypeAnnotation::Number),
+            AstNode::Number(0.0),
+            Rc::new(RefCell::new(env)),
+        );
+        let value = Value::Function(typed_func);
+
+        let err = check_type_detailed(
+            &value,
+            &TypeAnnotation::Function {
+                params: vec![Some(TypeAnnotation::Number), Some(TypeAnnotation::Number)],
+                return_type: Box::new(TypeAnnotation::Number),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(err.path, vec![PathSegment::Param(1)]);
+    }
+
+    #[test]
+    fn test_check_type_detailed_names_the_offending_function_return() {
+        let env = achronyme_types::Environment::new();
+        let typed_func = Function::new_typed(
+            vec!["x".to_string()],
+            vec![Some(TypeAnnotation::Number)],
+            Some(TypeAnnotation::String),
+            AstNode::Number(0.0),
+            Rc::new(RefCell::new(env)),
+        );
+        let value = Value::Function(typed_func);
+
+        let err = check_type_detailed(
+            &value,
+            &TypeAnnotation::Function {
+                params: vec![Some(TypeAnnotation::Number)],
+                return_type: Box::new(TypeAnnotation::Number),
+            },
+        )
+        .unwrap_err();
+
+        assert_eq!(err.path, vec![PathSegment::Return]);
+    }
+
+    #[test]
+    fn test_merge_record_types_disjoint_fields() {
+        let mut a_fields = HashMap::new();
+        a_fields.insert("name".to_string(), (false, TypeAnnotation::String));
+        let a = TypeAnnotation::Record { fields: a_fields };
+
+        let mut b_fields = HashMap::new();
+        b_fields.insert("age".to_string(), (false, TypeAnnotation::Number));
+        let b = TypeAnnotation::Record { fields: b_fields };
+
+        let merged = merge_record_types(&a, &b).unwrap();
+        match merged {
+            TypeAnnotation::Record { fields } => {
+                assert_eq!(fields.get("name"), Some(&(false, TypeAnnotation::String)));
+                assert_eq!(fields.get("age"), Some(&(false, TypeAnnotation::Number)));
+            }
+            other => panic!("Expected Record type, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_merge_record_types_recurses_into_shared_record_field() {
+        let mut a_inner = HashMap::new();
+        a_inner.insert("street".to_string(), (false, TypeAnnotation::String));
+        let mut a_fields = HashMap::new();
+        a_fields.insert("address".to_string(), (false, TypeAnnotation::Record { fields: a_inner }));
+        let a = TypeAnnotation::Record { fields: a_fields };
+
+        let mut b_inner = HashMap::new();
+        b_inner.insert("zip".to_string(), (false, TypeAnnotation::Number));
+        let mut b_fields = HashMap::new();
+        b_fields.insert("address".to_string(), (false, TypeAnnotation::Record { fields: b_inner }));
+        let b = TypeAnnotation::Record { fields: b_fields };
+
+        let merged = merge_record_types(&a, &b).unwrap();
+        match merged {
+            TypeAnnotation::Record { fields } => match fields.get("address") {
+                Some((false, TypeAnnotation::Record { fields: address_fields })) => {
+                    assert_eq!(address_fields.get("street"), Some(&(false, TypeAnnotation::String)));
+                    assert_eq!(address_fields.get("zip"), Some(&(false, TypeAnnotation::Number)));
+                }
+                other => panic!("Expected merged address Record, got {:?}", other),
+            },
+            other => panic!("Expected Record type, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_merge_record_types_field_collision() {
+        let mut a_fields = HashMap::new();
+        a_fields.insert("name".to_string(), (false, TypeAnnotation::String));
+        let a = TypeAnnotation::Record { fields: a_fields };
+
+        let mut b_fields = HashMap::new();
+        b_fields.insert("name".to_string(), (false, TypeAnnotation::Number));
+        let b = TypeAnnotation::Record { fields: b_fields };
+
+        let err = merge_record_types(&a, &b).unwrap_err();
+        assert_eq!(err.context, Some("FieldCollision: name".to_string()));
+    }
+
+    #[test]
+    fn test_merge_record_types_mutability_collision() {
+        let mut a_fields = HashMap::new();
+        a_fields.insert("count".to_string(), (true, TypeAnnotation::Number));
+        let a = TypeAnnotation::Record { fields: a_fields };
+
+        let mut b_fields = HashMap::new();
+        b_fields.insert("count".to_string(), (false, TypeAnnotation::Number));
+        let b = TypeAnnotation::Record { fields: b_fields };
+
+        let err = merge_record_types(&a, &b).unwrap_err();
+        assert_eq!(err.context, Some("FieldCollision: count".to_string()));
+    }
+
+    #[test]
+    fn test_merge_record_types_rejects_non_record_operands() {
+        assert!(merge_record_types(&TypeAnnotation::Number, &TypeAnnotation::String).is_err());
+    }
+
+    #[test]
+    fn test_narrow_removes_matched_variant() {
+        let union = TypeAnnotation::Union(vec![
+            TypeAnnotation::Number,
+            TypeAnnotation::String,
+            TypeAnnotation::Boolean,
+        ]);
+
+        // Matching Number leaves the other two variants.
+        let narrowed = narrow(&union, &TypeAnnotation::Number);
+        assert_eq!(
+            narrowed,
+            TypeAnnotation::Union(vec![TypeAnnotation::String, TypeAnnotation::Boolean])
+        );
+    }
+
+    #[test]
+    fn test_narrow_collapses_single_remaining_variant() {
+        let union = TypeAnnotation::Union(vec![TypeAnnotation::Number, TypeAnnotation::String]);
+        let narrowed = narrow(&union, &TypeAnnotation::Number);
+        assert_eq!(narrowed, TypeAnnotation::String);
+    }
+
+    #[test]
+    fn test_narrow_full_coverage_yields_empty_union() {
+        let union = TypeAnnotation::Union(vec![TypeAnnotation::Number, TypeAnnotation::String]);
+        let narrowed = narrow(&union, &union);
+        assert_eq!(narrowed, TypeAnnotation::Union(vec![]));
+    }
+
+    #[test]
+    fn test_narrow_any_is_never_narrowed() {
+        assert_eq!(narrow(&TypeAnnotation::Any, &TypeAnnotation::Number), TypeAnnotation::Any);
+    }
+
+    #[test]
+    fn test_is_exhaustive_covers_every_variant() {
+        let union = TypeAnnotation::Union(vec![
+            TypeAnnotation::Number,
+            TypeAnnotation::String,
+            TypeAnnotation::Boolean,
+        ]);
+
+        assert!(is_exhaustive(
+            &union,
+            &[TypeAnnotation::Number, TypeAnnotation::String, TypeAnnotation::Boolean]
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_is_exhaustive_reports_missing_variants() {
+        let union = TypeAnnotation::Union(vec![
+            TypeAnnotation::Number,
+            TypeAnnotation::String,
+            TypeAnnotation::Boolean,
+        ]);
+
+        let missing = is_exhaustive(&union, &[TypeAnnotation::Number]).unwrap_err();
+        assert_eq!(missing, vec![TypeAnnotation::String, TypeAnnotation::Boolean]);
+    }
+
+    #[test]
+    fn test_is_exhaustive_any_is_never_exhausted() {
+        assert_eq!(
+            is_exhaustive(&TypeAnnotation::Any, &[TypeAnnotation::Number]).unwrap_err(),
+            vec![TypeAnnotation::Any]
+        );
+    }
+
+    #[test]
+    fn test_has_redundant_arm_detects_duplicate() {
+        let union = TypeAnnotation::Union(vec![TypeAnnotation::Number, TypeAnnotation::String]);
+
+        assert!(!has_redundant_arm(&union, &[TypeAnnotation::Number, TypeAnnotation::String]));
+        // Number covered twice - the second occurrence narrows nothing new.
+        assert!(has_redundant_arm(
+            &union,
+            &[TypeAnnotation::Number, TypeAnnotation::Number, TypeAnnotation::String]
+        ));
+        // An arm after the union is already fully covered is unreachable.
+        assert!(has_redundant_arm(
+            &union,
+            &[TypeAnnotation::Number, TypeAnnotation::String, TypeAnnotation::Boolean]
+        ));
+    }
+
+    #[test]
+    fn test_infer_type_with_pins_down_expected_var() {
+        let mut table = UnificationTable::new();
+        let expected = table.fresh();
+
+        let refined = infer_type_with(&Value::Number(1.0), &expected, &mut table).unwrap();
+        assert_eq!(refined, TypeAnnotation::Number);
+
+        // The table itself now resolves that same variable to Number too.
+        assert_eq!(table.zonk(&expected), TypeAnnotation::Number);
+    }
+
+    #[test]
+    fn test_infer_type_with_rejects_mismatch() {
+        let mut table = UnificationTable::new();
+        assert!(infer_type_with(&Value::Number(1.0), &TypeAnnotation::String, &mut table).is_err());
+    }
+
+    #[test]
+    fn test_generalize_instantiate_freshen_independently() {
+        let mut table = UnificationTable::new();
+        let var = table.fresh();
+
+        // identity: (var) -> var
+        let identity = TypeAnnotation::Function {
+            params: vec![Some(var.clone())],
+            return_type: Box::new(var),
+        };
+        let scheme = table.generalize(&identity);
+
+        // Two independent instantiations can be pinned to different
+        // concrete types without interfering with each other.
+        let call_site_a = table.instantiate(&scheme);
+        table.unify(&call_site_a, &TypeAnnotation::Function {
+            params: vec![Some(TypeAnnotation::Number)],
+            return_type: Box::new(TypeAnnotation::Number),
+        }).unwrap();
+
+        let call_site_b = table.instantiate(&scheme);
+        table.unify(&call_site_b, &TypeAnnotation::Function {
+            params: vec![Some(TypeAnnotation::String)],
+            return_type: Box::new(TypeAnnotation::String),
+        }).unwrap();
+
+        assert_eq!(table.zonk(&call_site_a), TypeAnnotation::Function {
+            params: vec![Some(TypeAnnotation::Number)],
+            return_type: Box::new(TypeAnnotation::Number),
+        });
+        assert_eq!(table.zonk(&call_site_b), TypeAnnotation::Function {
+            params: vec![Some(TypeAnnotation::String)],
+            return_type: Box::new(TypeAnnotation::String),
+        });
+    }
+
     #[test]
     fn test_mutable_ref_auto_deref() {
         let inner_value = Value::Number(42.0);
@@ -443,7 +829,7 @@ mod tests {
                 shape,
             } => {
                 assert_eq!(*element_type, TypeAnnotation::Number);
-                assert_eq!(shape, Some(vec![Some(3)]));
+                assert_eq!(shape, Some(vec![ShapeDim::Fixed(3)]));
             }
             _ => panic!("Expected Tensor type annotation"),
         }
@@ -509,4 +895,636 @@ mod tests {
         // Number doesn't match
         assert!(check_type(&Value::Number(42.0), &union).is_err());
     }
+
+    #[test]
+    fn test_infer_node_literals() {
+        let ctx = HashMap::new();
+        assert_eq!(infer_node(&AstNode::Number(1.0), &ctx), Ok(TypeAnnotation::Number));
+        assert_eq!(infer_node(&AstNode::Boolean(true), &ctx), Ok(TypeAnnotation::Boolean));
+        assert_eq!(
+            infer_node(&AstNode::StringLiteral("hi".to_string()), &ctx),
+            Ok(TypeAnnotation::String)
+        );
+    }
+
+    #[test]
+    fn test_infer_node_untracked_variable_is_any() {
+        let ctx = HashMap::new();
+        assert_eq!(
+            infer_node(&AstNode::VariableRef("x".to_string()), &ctx),
+            Ok(TypeAnnotation::Any)
+        );
+    }
+
+    #[test]
+    fn test_check_node_lambda_matches_annotation() {
+        // (x: Number) -> x + 1
+        let lambda = AstNode::Lambda {
+            params: vec![("x".to_string(), Some(TypeAnnotation::Number))],
+            return_type: Some(TypeAnnotation::Number),
+            body: Box::new(AstNode::BinaryOp {
+                op: achronyme_parser::ast::BinaryOp::Add,
+                left: Box::new(AstNode::VariableRef("x".to_string())),
+                right: Box::new(AstNode::Number(1.0)),
+            }),
+        };
+
+        let expected = TypeAnnotation::Function {
+            params: vec![Some(TypeAnnotation::Number)],
+            return_type: Box::new(TypeAnnotation::Number),
+        };
+
+        assert!(check_node(&lambda, &expected, &HashMap::new()).is_ok());
+    }
+
+    #[test]
+    fn test_check_node_lambda_body_type_mismatch() {
+        // (x: Number) -> x + "s" should fail statically: Number + String
+        let lambda = AstNode::Lambda {
+            params: vec![("x".to_string(), Some(TypeAnnotation::Number))],
+            return_type: None,
+            body: Box::new(AstNode::BinaryOp {
+                op: achronyme_parser::ast::BinaryOp::Add,
+                left: Box::new(AstNode::VariableRef("x".to_string())),
+                right: Box::new(AstNode::StringLiteral("s".to_string())),
+            }),
+        };
+
+        let expected = TypeAnnotation::Function {
+            params: vec![Some(TypeAnnotation::Number)],
+            return_type: Box::new(TypeAnnotation::Number),
+        };
+
+        assert!(check_node(&lambda, &expected, &HashMap::new()).is_err());
+    }
+
+    #[test]
+    fn test_is_subtype_never_and_any() {
+        assert!(is_subtype(&TypeAnnotation::Never, &TypeAnnotation::Number));
+        assert!(is_subtype(&TypeAnnotation::Number, &TypeAnnotation::Any));
+        // Any is gradual typing's consistency relation, not strict
+        // soundness - it's treated as a bottom as well as a top, so this
+        // also holds (unlike Never <: Any, which is true for unrelated
+        // reasons: Never is bottom for every type).
+        assert!(is_subtype(&TypeAnnotation::Any, &TypeAnnotation::Number));
+    }
+
+    #[test]
+    fn test_is_subtype_number_widens_to_complex() {
+        assert!(is_subtype(&TypeAnnotation::Number, &TypeAnnotation::Complex));
+        assert!(!is_subtype(&TypeAnnotation::Complex, &TypeAnnotation::Number));
+    }
+
+    #[test]
+    fn test_is_subtype_tensor_element_widens_to_complex() {
+        // Tensor<Number> <: Tensor<Complex> falls out of Number <: Complex
+        // plus covariant Tensor element types.
+        let real_tensor = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Number),
+            shape: None,
+        };
+        let complex_tensor = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Complex),
+            shape: None,
+        };
+
+        assert!(is_subtype(&real_tensor, &complex_tensor));
+        assert!(!is_subtype(&complex_tensor, &real_tensor));
+    }
+
+    #[test]
+    fn test_is_subtype_tensor_shape_concrete_to_wildcard_only() {
+        let concrete = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Number),
+            shape: Some(vec![ShapeDim::Fixed(2), ShapeDim::Fixed(3)]),
+        };
+        let wildcard = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Number),
+            shape: None,
+        };
+
+        // A concrete shape is a subtype of an unconstrained one...
+        assert!(is_subtype(&concrete, &wildcard));
+        // ...but not vice versa: the unconstrained shape isn't guaranteed
+        // to be [2, 3].
+        assert!(!is_subtype(&wildcard, &concrete));
+    }
+
+    #[test]
+    fn test_is_subtype_union_both_directions() {
+        let union = TypeAnnotation::Union(vec![TypeAnnotation::Number, TypeAnnotation::String]);
+
+        // A <: Union[..] iff A is a subtype of some member.
+        assert!(is_subtype(&TypeAnnotation::Number, &union));
+        assert!(!is_subtype(&TypeAnnotation::Boolean, &union));
+
+        // Union[..] <: B iff every member is a subtype of B.
+        let number_or_complex = TypeAnnotation::Union(vec![TypeAnnotation::Number, TypeAnnotation::Complex]);
+        assert!(is_subtype(&number_or_complex, &TypeAnnotation::Complex));
+        assert!(!is_subtype(&union, &TypeAnnotation::Complex));
+    }
+
+    #[test]
+    fn test_is_subtype_mut_record_fields_are_invariant() {
+        let mut sub_fields = HashMap::new();
+        sub_fields.insert("count".to_string(), (true, TypeAnnotation::Number));
+
+        let mut sup_fields = HashMap::new();
+        sup_fields.insert("count".to_string(), (true, TypeAnnotation::Complex));
+
+        let sub_ty = TypeAnnotation::Record { fields: sub_fields };
+        let sup_ty = TypeAnnotation::Record { fields: sup_fields };
+
+        // Even though Number <: Complex, a `mut` field can't widen: the
+        // supertype view could write a Complex back through a binding
+        // that's really backed by a plain f64.
+        assert!(!is_subtype(&sub_ty, &sup_ty));
+    }
+
+    #[test]
+    fn test_is_subtype_record_width_and_depth() {
+        let mut narrow = HashMap::new();
+        narrow.insert("name".to_string(), (false, TypeAnnotation::String));
+
+        let mut wide = HashMap::new();
+        wide.insert("name".to_string(), (false, TypeAnnotation::String));
+        wide.insert("age".to_string(), (false, TypeAnnotation::Number));
+
+        let narrow_ty = TypeAnnotation::Record { fields: narrow };
+        let wide_ty = TypeAnnotation::Record { fields: wide };
+
+        // {name, age} <: {name}
+        assert!(is_subtype(&wide_ty, &narrow_ty));
+        // {name} is not a subtype of {name, age} (missing `age`)
+        assert!(!is_subtype(&narrow_ty, &wide_ty));
+    }
+
+    #[test]
+    fn test_is_subtype_function_variance() {
+        let mut narrow = HashMap::new();
+        narrow.insert("name".to_string(), (false, TypeAnnotation::String));
+
+        let mut wide = HashMap::new();
+        wide.insert("name".to_string(), (false, TypeAnnotation::String));
+        wide.insert("age".to_string(), (false, TypeAnnotation::Number));
+
+        let narrow_ty = TypeAnnotation::Record { fields: narrow };
+        let wide_ty = TypeAnnotation::Record { fields: wide };
+
+        // (narrow) -> wide <: (wide) -> narrow: contravariant in the
+        // parameter (accepting the more general `narrow` is a supertype of
+        // requiring the more specific `wide`) and covariant in the return
+        // (producing the more specific `wide` is a subtype of `narrow`).
+        let sub = TypeAnnotation::Function {
+            params: vec![Some(narrow_ty.clone())],
+            return_type: Box::new(wide_ty.clone()),
+        };
+        let sup = TypeAnnotation::Function {
+            params: vec![Some(wide_ty)],
+            return_type: Box::new(narrow_ty),
+        };
+
+        assert!(is_subtype(&sub, &sup));
+        assert!(!is_subtype(&sup, &sub));
+    }
+
+    #[test]
+    fn test_infer_program_pins_unannotated_lambda_param() {
+        // (x) => x + 1 - x's type var is pinned to Number by the
+        // arithmetic in the lambda's body.
+        let lambda = AstNode::Lambda {
+            params: vec![("x".to_string(), None)],
+            return_type: None,
+            body: Box::new(AstNode::BinaryOp {
+                op: achronyme_parser::ast::BinaryOp::Add,
+                left: Box::new(AstNode::VariableRef("x".to_string())),
+                right: Box::new(AstNode::Number(1.0)),
+            }),
+        };
+
+        let nodes = vec![lambda];
+        let inferred = infer_program(&nodes).unwrap();
+
+        match &inferred[0] {
+            TypeAnnotation::Function { params, return_type } => {
+                assert_eq!(params[0], Some(TypeAnnotation::Number));
+                assert_eq!(**return_type, TypeAnnotation::Number);
+            }
+            other => panic!("Expected Function type annotation, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_infer_program_unifies_if_branches() {
+        // if true then 1 else 2 - both branches must agree on Number.
+        let if_node = AstNode::If {
+            condition: Box::new(AstNode::Boolean(true)),
+            then_expr: Box::new(AstNode::Number(1.0)),
+            else_expr: Box::new(AstNode::Number(2.0)),
+        };
+
+        let inferred = infer_program(&[if_node]).unwrap();
+        assert_eq!(inferred[0], TypeAnnotation::Number);
+    }
+
+    #[test]
+    fn test_infer_program_rejects_mismatched_if_branches() {
+        let if_node = AstNode::If {
+            condition: Box::new(AstNode::Boolean(true)),
+            then_expr: Box::new(AstNode::Number(1.0)),
+            else_expr: Box::new(AstNode::StringLiteral("nope".to_string())),
+        };
+
+        assert!(infer_program(&[if_node]).is_err());
+    }
+
+    #[test]
+    fn test_infer_program_defaults_unconstrained_var_to_any() {
+        // An identity lambda with no call site never gets its parameter
+        // pinned down, so it should zonk to Any rather than leak a TypeVar.
+        let identity = AstNode::Lambda {
+            params: vec![("x".to_string(), None)],
+            return_type: None,
+            body: Box::new(AstNode::VariableRef("x".to_string())),
+        };
+
+        let inferred = infer_program(&[identity]).unwrap();
+        match &inferred[0] {
+            TypeAnnotation::Function { params, return_type } => {
+                assert_eq!(params[0], Some(TypeAnnotation::Any));
+                assert_eq!(**return_type, TypeAnnotation::Any);
+            }
+            other => panic!("Expected Function type annotation, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_infer_program_pins_unannotated_let_binding() {
+        // let x = 1; x + "nope" - x's type var is pinned to Number by its
+        // initializer, so the later mismatched use is now caught instead of
+        // silently falling through to a fresh, unconstrained variable.
+        let decl = AstNode::VariableDecl {
+            name: "x".to_string(),
+            type_annotation: None,
+            initializer: Box::new(AstNode::Number(1.0)),
+        };
+        let usage = AstNode::BinaryOp {
+            op: achronyme_parser::ast::BinaryOp::Add,
+            left: Box::new(AstNode::VariableRef("x".to_string())),
+            right: Box::new(AstNode::StringLiteral("nope".to_string())),
+        };
+
+        assert!(infer_program(&[decl, usage]).is_err());
+    }
+
+    #[test]
+    fn test_infer_program_sequence_returns_last_statement_type() {
+        // do { let x = 1; x + 2 } - the block's type is its last statement's.
+        let block = AstNode::DoBlock {
+            statements: vec![
+                AstNode::VariableDecl {
+                    name: "x".to_string(),
+                    type_annotation: None,
+                    initializer: Box::new(AstNode::Number(1.0)),
+                },
+                AstNode::BinaryOp {
+                    op: achronyme_parser::ast::BinaryOp::Add,
+                    left: Box::new(AstNode::VariableRef("x".to_string())),
+                    right: Box::new(AstNode::Number(2.0)),
+                },
+            ],
+        };
+
+        let inferred = infer_program(&[block]).unwrap();
+        assert_eq!(inferred[0], TypeAnnotation::Number);
+    }
+
+    #[test]
+    fn test_check_type_detailed_pinpoints_nested_record_field() {
+        let mut inner_fields = HashMap::new();
+        inner_fields.insert("street".to_string(), (false, TypeAnnotation::String));
+        let inner_record = TypeAnnotation::Record {
+            fields: inner_fields,
+        };
+
+        let mut outer_fields = HashMap::new();
+        outer_fields.insert("address".to_string(), (false, inner_record));
+        let outer_record = TypeAnnotation::Record {
+            fields: outer_fields,
+        };
+
+        let mut inner_value = HashMap::new();
+        inner_value.insert("street".to_string(), Value::Number(42.0)); // wrong type
+
+        let mut outer_value = HashMap::new();
+        outer_value.insert("address".to_string(), Value::Record(inner_value));
+
+        let err = check_type_detailed(&Value::Record(outer_value), &outer_record).unwrap_err();
+        assert_eq!(
+            err.path,
+            vec![
+                PathSegment::Field("address".to_string()),
+                PathSegment::Field("street".to_string()),
+            ]
+        );
+        assert_eq!(
+            err.to_string(),
+            "at .address.street: expected String, got Number"
+        );
+    }
+
+    #[test]
+    fn test_check_type_detailed_pinpoints_tensor_dimension() {
+        let tensor_type = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Number),
+            shape: Some(vec![ShapeDim::Fixed(2), ShapeDim::Fixed(3)]),
+        };
+        let tensor = Value::Tensor(RealTensor::new(vec![0.0; 8], vec![2, 4]).unwrap());
+
+        let err = check_type_detailed(&tensor, &tensor_type).unwrap_err();
+        assert_eq!(err.path, vec![PathSegment::Index(1)]);
+        assert_eq!(err.to_string(), "at [1]: expected 3, got 4");
+    }
+
+    #[test]
+    fn test_check_type_detailed_reports_bound_extent_for_named_dim_mismatch() {
+        // Tensor<Number, [N, N]> against shape [3, 4]: N binds to 3 at
+        // index 0, so the error at index 1 should report "expected 3" -
+        // the extent N was bound to - not the literal variable name.
+        let tensor_type = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Number),
+            shape: Some(vec![ShapeDim::Var("N".to_string()), ShapeDim::Var("N".to_string())]),
+        };
+        let tensor = Value::Tensor(RealTensor::new(vec![0.0; 12], vec![3, 4]).unwrap());
+
+        let err = check_type_detailed(&tensor, &tensor_type).unwrap_err();
+        assert_eq!(err.path, vec![PathSegment::Index(1)]);
+        assert_eq!(err.to_string(), "at [1] (dimension variable 'N' (already bound to 3, saw 4 here)): expected 3, got 4");
+    }
+
+    #[test]
+    fn test_check_type_detailed_names_conflicting_dim_variable_across_tensor_args() {
+        // matmul-style signature: (Tensor[N,M], Tensor[M,K]) -> Tensor[N,K].
+        // Sharing one DimEnv across both parameters, M binds to 3 from the
+        // first tensor, so the second tensor's mismatched M must be named
+        // in the resulting error rather than just reporting raw extents.
+        let first_param = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Number),
+            shape: Some(vec![ShapeDim::Var("N".to_string()), ShapeDim::Var("M".to_string())]),
+        };
+        let second_param = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Number),
+            shape: Some(vec![ShapeDim::Var("M".to_string()), ShapeDim::Var("K".to_string())]),
+        };
+        let first_arg = Value::Tensor(RealTensor::new(vec![0.0; 6], vec![2, 3]).unwrap());
+        let second_arg = Value::Tensor(RealTensor::new(vec![0.0; 20], vec![4, 5]).unwrap());
+
+        let mut dims = DimEnv::new();
+        check_type_with_dims(&first_arg, &first_param, &mut dims).unwrap();
+
+        let err = check_type_detailed_with_dims(&second_arg, &second_param, &mut dims).unwrap_err();
+        assert_eq!(err.path, vec![PathSegment::Index(0)]);
+        assert!(err.context.unwrap().contains("dimension variable 'M'"));
+        assert_eq!(err.expected, "3");
+        assert_eq!(err.actual, "4");
+    }
+
+    #[test]
+    fn test_check_type_detailed_falls_back_to_flat_error_outside_records_and_tensors() {
+        let err = check_type_detailed(&Value::Number(1.0), &TypeAnnotation::String).unwrap_err();
+        assert!(err.path.is_empty());
+        assert_eq!(err.to_string(), "Type mismatch: expected String, got Number");
+    }
+
+    #[test]
+    fn test_coerce_number_to_complex() {
+        let widened = coerce(&Value::Number(3.0), &TypeAnnotation::Complex).unwrap();
+        assert_eq!(widened, Value::Complex(Complex::new(3.0, 0.0)));
+
+        assert!(coerce(&Value::String("3".into()), &TypeAnnotation::Complex).is_err());
+    }
+
+    #[test]
+    fn test_coerce_is_identity_when_already_matching() {
+        // No widening needed - the value already matches, so it comes back unchanged.
+        let widened = coerce(&Value::Number(3.0), &TypeAnnotation::Number).unwrap();
+        assert_eq!(widened, Value::Number(3.0));
+    }
+
+    #[test]
+    fn test_coerce_number_to_rank0_tensor() {
+        let tensor_type = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Number),
+            shape: Some(vec![]),
+        };
+
+        let widened = coerce(&Value::Number(5.0), &tensor_type).unwrap();
+        match widened {
+            Value::Tensor(t) => {
+                assert_eq!(t.shape(), &[] as &[usize]);
+                assert_eq!(t.data(), &[5.0]);
+            }
+            other => panic!("Expected Tensor, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_coerce_integer_to_rank0_tensor() {
+        // Integer -> Number -> Tensor<Number, []>, chained through two
+        // widening steps rather than a dedicated Integer -> Tensor rule.
+        let tensor_type = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Number),
+            shape: Some(vec![]),
+        };
+
+        let widened = coerce(&Value::Integer(5), &tensor_type).unwrap();
+        match widened {
+            Value::Tensor(t) => {
+                assert_eq!(t.shape(), &[] as &[usize]);
+                assert_eq!(t.data(), &[5.0]);
+            }
+            other => panic!("Expected Tensor, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_coerce_number_to_rank0_complex_tensor_via_complex() {
+        // Number -> Complex -> Tensor<Complex, []>, chained through two
+        // widening steps rather than a dedicated rule.
+        let tensor_type = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Complex),
+            shape: Some(vec![]),
+        };
+
+        let widened = coerce(&Value::Number(5.0), &tensor_type).unwrap();
+        match widened {
+            Value::ComplexTensor(t) => {
+                assert_eq!(t.data(), &[Complex::new(5.0, 0.0)]);
+            }
+            other => panic!("Expected ComplexTensor, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_coerce_number_vector_to_tensor() {
+        let vector = Value::Vector(vec![Value::Number(1.0), Value::Number(2.0), Value::Number(3.0)]);
+        let tensor_type = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Number),
+            shape: Some(vec![ShapeDim::Fixed(3)]),
+        };
+
+        let widened = coerce(&vector, &tensor_type).unwrap();
+        match widened {
+            Value::Tensor(t) => assert_eq!(t.data(), &[1.0, 2.0, 3.0]),
+            other => panic!("Expected Tensor, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_coerce_rejects_mixed_vector_to_tensor() {
+        let vector = Value::Vector(vec![Value::Number(1.0), Value::String("nope".into())]);
+        let tensor_type = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Number),
+            shape: None,
+        };
+
+        assert!(coerce(&vector, &tensor_type).is_err());
+    }
+
+    #[test]
+    fn test_coerce_never_narrows_complex_to_number() {
+        assert!(coerce(&Value::Complex(Complex::new(1.0, 2.0)), &TypeAnnotation::Number).is_err());
+    }
+
+    #[test]
+    fn test_coerce_real_tensor_to_complex_tensor() {
+        let tensor = RealTensor::new(vec![1.0, 2.0, 3.0, 4.0], vec![2, 2]).unwrap();
+        let tensor_type = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Complex),
+            shape: Some(vec![ShapeDim::Fixed(2), ShapeDim::Fixed(2)]),
+        };
+
+        let widened = coerce(&Value::Tensor(tensor), &tensor_type).unwrap();
+        match widened {
+            Value::ComplexTensor(t) => {
+                assert_eq!(t.shape(), &[2, 2]);
+                assert_eq!(t.data(), &[
+                    Complex::new(1.0, 0.0),
+                    Complex::new(2.0, 0.0),
+                    Complex::new(3.0, 0.0),
+                    Complex::new(4.0, 0.0),
+                ]);
+            }
+            other => panic!("Expected ComplexTensor, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_coerce_real_tensor_rejects_mismatched_shape() {
+        let tensor = RealTensor::new(vec![1.0, 2.0, 3.0], vec![3]).unwrap();
+        let tensor_type = TypeAnnotation::Tensor {
+            element_type: Box::new(TypeAnnotation::Complex),
+            shape: Some(vec![ShapeDim::Fixed(2), ShapeDim::Fixed(2)]),
+        };
+
+        assert!(coerce(&Value::Tensor(tensor), &tensor_type).is_err());
+    }
+
+    #[test]
+    fn test_is_assignable_widens_number_to_complex() {
+        assert!(is_assignable(&Value::Number(42.0), &TypeAnnotation::Complex));
+        assert!(!is_assignable(&Value::String("nope".into()), &TypeAnnotation::Complex));
+    }
+
+    #[test]
+    fn test_check_type_stays_strict_without_coercion() {
+        // Unlike `is_assignable`, `check_type` never consults the
+        // coercion lattice - a Number is not exactly a Complex.
+        assert!(check_type(&Value::Number(42.0), &TypeAnnotation::Complex).is_err());
+    }
+
+    #[test]
+    fn test_check_type_coercing_widens_and_returns_the_stored_value() {
+        let widened = check_type_coercing(&Value::Number(3.0), &TypeAnnotation::Complex).unwrap();
+        assert_eq!(widened, Value::Complex(Complex::new(3.0, 0.0)));
+
+        assert!(check_type_coercing(&Value::String("nope".into()), &TypeAnnotation::Complex).is_err());
+    }
+
+    fn test_error_value(message: &str) -> Value {
+        Value::Error {
+            message: message.to_string(),
+            kind: None,
+            source: None,
+        }
+    }
+
+    #[test]
+    fn test_check_type_lets_an_error_value_satisfy_any_expected_type() {
+        // An already-Error value shouldn't cascade into a fresh "expected X,
+        // got Error" mismatch on top of whatever error produced it.
+        let err_value = test_error_value("division by zero");
+        assert!(check_type(&err_value, &TypeAnnotation::Number).is_ok());
+        assert!(check_type(&err_value, &TypeAnnotation::String).is_ok());
+        assert!(check_type(
+            &err_value,
+            &TypeAnnotation::Record { fields: HashMap::new() }
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_check_type_detailed_surfaces_the_propagated_error_message() {
+        // Unlike `check_type`, the diagnostic-oriented `check_type_detailed`
+        // doesn't silently let an Error value through - it reports the
+        // original message rather than inventing a new mismatch.
+        let err_value = test_error_value("division by zero");
+        let err = check_type_detailed(&err_value, &TypeAnnotation::Number).unwrap_err();
+        assert_eq!(err.actual, "division by zero");
+        assert_eq!(err.expected, "Number");
+    }
+
+    #[test]
+    fn test_check_type_detailed_an_error_typed_expectation_is_a_real_match() {
+        let err_value = test_error_value("boom");
+        assert!(check_type_detailed(&err_value, &TypeAnnotation::Error(String::new())).is_ok());
+        assert!(check_type_detailed(&err_value, &TypeAnnotation::Any).is_ok());
+    }
+
+    #[test]
+    fn test_is_assignable_lets_an_error_value_satisfy_any_expected_type() {
+        let err_value = test_error_value("boom");
+        assert!(is_assignable(&err_value, &TypeAnnotation::Record { fields: HashMap::new() }));
+    }
+
+    #[test]
+    fn test_normalize_union_drops_never_and_flattens_nested_unions() {
+        let union = TypeAnnotation::Union(vec![
+            TypeAnnotation::Number,
+            TypeAnnotation::Never,
+            TypeAnnotation::Union(vec![TypeAnnotation::String, TypeAnnotation::Number]),
+        ]);
+
+        assert_eq!(
+            normalize_union(&union),
+            TypeAnnotation::Union(vec![TypeAnnotation::Number, TypeAnnotation::String])
+        );
+    }
+
+    #[test]
+    fn test_normalize_union_collapses_to_a_bare_type() {
+        let union = TypeAnnotation::Union(vec![TypeAnnotation::Never, TypeAnnotation::Number]);
+        assert_eq!(normalize_union(&union), TypeAnnotation::Number);
+    }
+
+    #[test]
+    fn test_normalize_union_of_only_never_is_the_empty_union_marker() {
+        let union = TypeAnnotation::Union(vec![TypeAnnotation::Never, TypeAnnotation::Never]);
+        assert_eq!(normalize_union(&union), TypeAnnotation::Union(vec![]));
+    }
+
+    #[test]
+    fn test_normalize_union_leaves_non_union_types_unchanged() {
+        assert_eq!(normalize_union(&TypeAnnotation::Number), TypeAnnotation::Number);
+    }
 }
@@ -0,0 +1,440 @@
+//! Hindley-Milner-style inference with unification variables
+//!
+//! `static_check`'s `infer_node`/`check_node` need an annotation to make
+//! progress and default anything else to `Any`. This module instead gives
+//! every unannotated binder a fresh `TypeVar`, walks the program bottom-up
+//! generating equality constraints (an application constrains its callee to
+//! `(argtypes...) -> fresh_ret`; a conditional constrains both branches
+//! equal; a `let`/`mut` constrains its name to its initializer), and solves
+//! them with union-find unification - so an unannotated lambda's parameter
+//! types, or an unannotated local binding, still come out concrete when
+//! later uses pin them down.
+
+use std::collections::HashMap;
+
+use achronyme_parser::ast::{AstNode, BinaryOp};
+use achronyme_parser::TypeAnnotation;
+
+use super::display::type_annotation_to_string;
+
+/// Union-find table mapping unification variables to their resolved type.
+#[derive(Debug, Default)]
+pub struct UnificationTable {
+    bindings: HashMap<u32, TypeAnnotation>,
+    next_var: u32,
+}
+
+impl UnificationTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allocate a fresh, as-yet-unbound type variable.
+    pub fn fresh(&mut self) -> TypeAnnotation {
+        let id = self.next_var;
+        self.next_var += 1;
+        TypeAnnotation::TypeVar(id)
+    }
+
+    /// Follow `ty` through existing bindings until it's no longer a bound variable.
+    fn resolve(&self, ty: &TypeAnnotation) -> TypeAnnotation {
+        let mut current = ty.clone();
+        while let TypeAnnotation::TypeVar(id) = current {
+            match self.bindings.get(&id) {
+                Some(bound) => current = bound.clone(),
+                None => return TypeAnnotation::TypeVar(id),
+            }
+        }
+        current
+    }
+
+    /// Does unification variable `var` appear (transitively) inside `ty`?
+    /// Binding a variable to a type that contains itself would produce an
+    /// infinite type, so `bind` refuses to do so.
+    fn occurs_in(&self, var: u32, ty: &TypeAnnotation) -> bool {
+        match self.resolve(ty) {
+            TypeAnnotation::TypeVar(id) => id == var,
+            TypeAnnotation::Tensor { element_type, .. } => self.occurs_in(var, &element_type),
+            TypeAnnotation::Record { fields } => fields.values().any(|(_, t)| self.occurs_in(var, t)),
+            TypeAnnotation::Function { params, return_type } => {
+                params.iter().flatten().any(|t| self.occurs_in(var, t)) || self.occurs_in(var, &return_type)
+            }
+            TypeAnnotation::Union(types) => types.iter().any(|t| self.occurs_in(var, t)),
+            _ => false,
+        }
+    }
+
+    fn bind(&mut self, var: u32, ty: TypeAnnotation) -> Result<(), String> {
+        if self.occurs_in(var, &ty) {
+            return Err(format!(
+                "Occurs check failed: 't{} occurs in {}",
+                var,
+                type_annotation_to_string(&ty)
+            ));
+        }
+        self.bindings.insert(var, ty);
+        Ok(())
+    }
+
+    /// Unify `a` and `b`, recording whatever variable bindings are needed
+    /// to make them equal. Recurses structurally through `Record`,
+    /// `Tensor`, `Function`, and `Union`.
+    pub fn unify(&mut self, a: &TypeAnnotation, b: &TypeAnnotation) -> Result<(), String> {
+        let a = self.resolve(a);
+        let b = self.resolve(b);
+
+        match (&a, &b) {
+            (TypeAnnotation::TypeVar(v1), TypeAnnotation::TypeVar(v2)) if v1 == v2 => Ok(()),
+            (TypeAnnotation::TypeVar(v), _) => self.bind(*v, b),
+            (_, TypeAnnotation::TypeVar(v)) => self.bind(*v, a),
+
+            (TypeAnnotation::Any, _) | (_, TypeAnnotation::Any) => Ok(()),
+
+            // Integer widens to Number under unification too, so an
+            // arithmetic operand inferred as the narrower Integer still
+            // unifies against the Number each operator demands.
+            (TypeAnnotation::Integer, TypeAnnotation::Number)
+            | (TypeAnnotation::Number, TypeAnnotation::Integer) => Ok(()),
+
+            (
+                TypeAnnotation::Tensor { element_type: e1, shape: s1 },
+                TypeAnnotation::Tensor { element_type: e2, shape: s2 },
+            ) => {
+                if s1 != s2 {
+                    return Err(format!("Cannot unify tensor shapes {:?} and {:?}", s1, s2));
+                }
+                self.unify(e1, e2)
+            }
+
+            (TypeAnnotation::Record { fields: f1 }, TypeAnnotation::Record { fields: f2 }) => {
+                for (name, (_, t1)) in f1 {
+                    let (_, t2) = f2
+                        .get(name)
+                        .ok_or_else(|| format!("Record field '{}' missing when unifying records", name))?;
+                    self.unify(t1, t2)?;
+                }
+                for name in f2.keys() {
+                    if !f1.contains_key(name) {
+                        return Err(format!("Record field '{}' missing when unifying records", name));
+                    }
+                }
+                Ok(())
+            }
+
+            (
+                TypeAnnotation::Function { params: p1, return_type: r1 },
+                TypeAnnotation::Function { params: p2, return_type: r2 },
+            ) => {
+                if p1.len() != p2.len() {
+                    return Err(format!(
+                        "Cannot unify functions of different arity ({} vs {})",
+                        p1.len(),
+                        p2.len()
+                    ));
+                }
+                for (t1, t2) in p1.iter().zip(p2.iter()) {
+                    if let (Some(t1), Some(t2)) = (t1, t2) {
+                        self.unify(t1, t2)?;
+                    }
+                }
+                self.unify(r1, r2)
+            }
+
+            (TypeAnnotation::Union(types1), TypeAnnotation::Union(types2)) => {
+                if types1.len() != types2.len() {
+                    return Err("Cannot unify unions of different size".to_string());
+                }
+                for (t1, t2) in types1.iter().zip(types2.iter()) {
+                    self.unify(t1, t2)?;
+                }
+                Ok(())
+            }
+
+            _ if a == b => Ok(()),
+
+            _ => Err(format!(
+                "Cannot unify {} with {}",
+                type_annotation_to_string(&a),
+                type_annotation_to_string(&b)
+            )),
+        }
+    }
+
+    /// Zonk `ty` but leave any still-unbound variable as a free `TypeVar`
+    /// instead of defaulting to `Any` - the "type scheme" a let-bound
+    /// function keeps so each call site can [`instantiate`](Self::instantiate)
+    /// its own fresh copy of those variables rather than all call sites
+    /// fighting over one shared binding.
+    pub fn generalize(&self, ty: &TypeAnnotation) -> TypeAnnotation {
+        match self.resolve(ty) {
+            TypeAnnotation::TypeVar(id) => TypeAnnotation::TypeVar(id),
+            TypeAnnotation::Tensor { element_type, shape } => TypeAnnotation::Tensor {
+                element_type: Box::new(self.generalize(&element_type)),
+                shape,
+            },
+            TypeAnnotation::Record { fields } => TypeAnnotation::Record {
+                fields: fields
+                    .into_iter()
+                    .map(|(name, (is_mut, t))| (name, (is_mut, self.generalize(&t))))
+                    .collect(),
+            },
+            TypeAnnotation::Function { params, return_type } => TypeAnnotation::Function {
+                params: params.into_iter().map(|p| p.map(|t| self.generalize(&t))).collect(),
+                return_type: Box::new(self.generalize(&return_type)),
+            },
+            TypeAnnotation::Union(types) => {
+                TypeAnnotation::Union(types.into_iter().map(|t| self.generalize(&t)).collect())
+            }
+            other => other,
+        }
+    }
+
+    /// Freshen every free variable a prior [`generalize`](Self::generalize)
+    /// left in `ty`, consistently mapping repeated occurrences of the same
+    /// variable to the same fresh one - so a polymorphic function's type can
+    /// be reused at two call sites and pinned to two different concrete
+    /// types without either interfering with the other.
+    pub fn instantiate(&mut self, ty: &TypeAnnotation) -> TypeAnnotation {
+        let mut renaming = HashMap::new();
+        self.instantiate_with(ty, &mut renaming)
+    }
+
+    fn instantiate_with(&mut self, ty: &TypeAnnotation, renaming: &mut HashMap<u32, TypeAnnotation>) -> TypeAnnotation {
+        match ty {
+            TypeAnnotation::TypeVar(id) => renaming.entry(*id).or_insert_with(|| self.fresh()).clone(),
+            TypeAnnotation::Tensor { element_type, shape } => TypeAnnotation::Tensor {
+                element_type: Box::new(self.instantiate_with(element_type, renaming)),
+                shape: shape.clone(),
+            },
+            TypeAnnotation::Record { fields } => TypeAnnotation::Record {
+                fields: fields
+                    .iter()
+                    .map(|(name, (is_mut, t))| (name.clone(), (*is_mut, self.instantiate_with(t, renaming))))
+                    .collect(),
+            },
+            TypeAnnotation::Function { params, return_type } => TypeAnnotation::Function {
+                params: params
+                    .iter()
+                    .map(|p| p.as_ref().map(|t| self.instantiate_with(t, renaming)))
+                    .collect(),
+                return_type: Box::new(self.instantiate_with(return_type, renaming)),
+            },
+            TypeAnnotation::Union(types) => {
+                TypeAnnotation::Union(types.iter().map(|t| self.instantiate_with(t, renaming)).collect())
+            }
+            other => other.clone(),
+        }
+    }
+
+    /// Replace every resolved variable in `ty` with its bound type,
+    /// recursively; variables with no binding default to `Any`.
+    pub fn zonk(&self, ty: &TypeAnnotation) -> TypeAnnotation {
+        match self.resolve(ty) {
+            TypeAnnotation::TypeVar(_) => TypeAnnotation::Any,
+            TypeAnnotation::Tensor { element_type, shape } => TypeAnnotation::Tensor {
+                element_type: Box::new(self.zonk(&element_type)),
+                shape,
+            },
+            TypeAnnotation::Record { fields } => TypeAnnotation::Record {
+                fields: fields
+                    .into_iter()
+                    .map(|(name, (is_mut, t))| (name, (is_mut, self.zonk(&t))))
+                    .collect(),
+            },
+            TypeAnnotation::Function { params, return_type } => TypeAnnotation::Function {
+                params: params.into_iter().map(|p| p.map(|t| self.zonk(&t))).collect(),
+                return_type: Box::new(self.zonk(&return_type)),
+            },
+            TypeAnnotation::Union(types) => {
+                TypeAnnotation::Union(types.into_iter().map(|t| self.zonk(&t)).collect())
+            }
+            other => other,
+        }
+    }
+}
+
+type Ctx = HashMap<String, TypeAnnotation>;
+
+/// Infer a type for each top-level node in `nodes`, in one bottom-up pass,
+/// returning the zonked result type for each.
+pub fn infer_program(nodes: &[AstNode]) -> Result<Vec<TypeAnnotation>, String> {
+    let mut table = UnificationTable::new();
+    let mut ctx = Ctx::new();
+
+    let raw_types: Vec<TypeAnnotation> = nodes
+        .iter()
+        .map(|node| infer_node_hm(node, &mut ctx, &mut table))
+        .collect::<Result<_, _>>()?;
+
+    Ok(raw_types.iter().map(|ty| table.zonk(ty)).collect())
+}
+
+fn infer_node_hm(node: &AstNode, ctx: &mut Ctx, table: &mut UnificationTable) -> Result<TypeAnnotation, String> {
+    match node {
+        AstNode::Number(_) => Ok(TypeAnnotation::Number),
+        AstNode::Integer(_) => Ok(TypeAnnotation::Integer),
+        AstNode::Boolean(_) => Ok(TypeAnnotation::Boolean),
+        AstNode::StringLiteral(_) => Ok(TypeAnnotation::String),
+        AstNode::ComplexLiteral { .. } => Ok(TypeAnnotation::Complex),
+        AstNode::Null => Ok(TypeAnnotation::Null),
+
+        // First mention of a variable allocates the type var that every
+        // later use of it will be unified against.
+        AstNode::VariableRef(name) => Ok(ctx.entry(name.clone()).or_insert_with(|| table.fresh()).clone()),
+
+        AstNode::BinaryOp { op, left, right } => {
+            let left_ty = infer_node_hm(left, ctx, table)?;
+            let right_ty = infer_node_hm(right, ctx, table)?;
+            match op {
+                BinaryOp::Add
+                | BinaryOp::Subtract
+                | BinaryOp::Multiply
+                | BinaryOp::Divide
+                | BinaryOp::Power
+                | BinaryOp::Modulo => {
+                    table.unify(&left_ty, &TypeAnnotation::Number)?;
+                    table.unify(&right_ty, &TypeAnnotation::Number)?;
+                    if !matches!(op, BinaryOp::Divide)
+                        && matches!(left_ty, TypeAnnotation::Integer)
+                        && matches!(right_ty, TypeAnnotation::Integer)
+                    {
+                        Ok(TypeAnnotation::Integer)
+                    } else {
+                        Ok(TypeAnnotation::Number)
+                    }
+                }
+                _ => {
+                    table.unify(&left_ty, &right_ty)?;
+                    Ok(TypeAnnotation::Boolean)
+                }
+            }
+        }
+
+        AstNode::If { condition, then_expr, else_expr } => {
+            let cond_ty = infer_node_hm(condition, ctx, table)?;
+            table.unify(&cond_ty, &TypeAnnotation::Boolean)?;
+            let then_ty = infer_node_hm(then_expr, ctx, table)?;
+            let else_ty = infer_node_hm(else_expr, ctx, table)?;
+            table.unify(&then_ty, &else_ty)?;
+            Ok(then_ty)
+        }
+
+        AstNode::Lambda { params, return_type, body } => {
+            let mut body_ctx = ctx.clone();
+            let param_types: Vec<TypeAnnotation> = params
+                .iter()
+                .map(|(name, declared)| {
+                    let ty = declared.clone().unwrap_or_else(|| table.fresh());
+                    body_ctx.insert(name.clone(), ty.clone());
+                    ty
+                })
+                .collect();
+
+            let body_ty = infer_node_hm(body, &mut body_ctx, table)?;
+            if let Some(declared_return) = return_type {
+                table.unify(&body_ty, declared_return)?;
+            }
+
+            Ok(TypeAnnotation::Function {
+                params: param_types.into_iter().map(Some).collect(),
+                return_type: Box::new(body_ty),
+            })
+        }
+
+        AstNode::FunctionCall { name, args } => {
+            let callee_ty = ctx.entry(name.clone()).or_insert_with(|| table.fresh()).clone();
+            infer_application(&callee_ty, args, ctx, table)
+        }
+
+        AstNode::CallExpression { callee, args } => {
+            let callee_ty = infer_node_hm(callee, ctx, table)?;
+            infer_application(&callee_ty, args, ctx, table)
+        }
+
+        AstNode::Pipe { left, right } => infer_node_hm(&desugar_pipe(left, right), ctx, table),
+
+        // An un-annotated `let`/`mut` binding gets a fresh variable, unified
+        // against its initializer's inferred type - so a declared annotation
+        // still constrains it exactly, but one left off is pinned down by
+        // whatever the initializer turns out to be instead of defaulting to
+        // `Any` and losing the chance to catch a later misuse.
+        AstNode::VariableDecl { name, type_annotation, initializer }
+        | AstNode::MutableDecl { name, type_annotation, initializer } => {
+            let init_ty = infer_node_hm(initializer, ctx, table)?;
+            let declared_ty = type_annotation.clone().unwrap_or_else(|| table.fresh());
+            table.unify(&declared_ty, &init_ty)?;
+            ctx.insert(name.clone(), declared_ty.clone());
+            Ok(declared_ty)
+        }
+
+        AstNode::Assignment { target, value } => {
+            let target_ty = infer_node_hm(target, ctx, table)?;
+            let value_ty = infer_node_hm(value, ctx, table)?;
+            table.unify(&target_ty, &value_ty)?;
+            Ok(value_ty)
+        }
+
+        AstNode::Return { value } => infer_node_hm(value, ctx, table),
+
+        // A block's statements run in the same scope, each one seeing
+        // bindings introduced by the ones before it; the block's own type
+        // is whatever its last statement produces.
+        AstNode::Sequence { statements } | AstNode::DoBlock { statements } => {
+            let mut last_ty = TypeAnnotation::Null;
+            for stmt in statements {
+                last_ty = infer_node_hm(stmt, ctx, table)?;
+            }
+            Ok(last_ty)
+        }
+
+        // No constraint-generation rule for this node shape yet - give it
+        // a fresh variable rather than forcing Any, so an enclosing
+        // constraint can still pin it down later.
+        _ => Ok(table.fresh()),
+    }
+}
+
+/// An application `callee(args...)` constrains the callee to
+/// `(argtypes...) -> fresh_ret` and returns `fresh_ret`.
+fn infer_application(
+    callee_ty: &TypeAnnotation,
+    args: &[AstNode],
+    ctx: &mut Ctx,
+    table: &mut UnificationTable,
+) -> Result<TypeAnnotation, String> {
+    let arg_types: Vec<TypeAnnotation> = args
+        .iter()
+        .map(|arg| infer_node_hm(arg, ctx, table))
+        .collect::<Result<_, _>>()?;
+
+    let ret = table.fresh();
+    let expected_callee_ty = TypeAnnotation::Function {
+        params: arg_types.into_iter().map(Some).collect(),
+        return_type: Box::new(ret.clone()),
+    };
+    table.unify(callee_ty, &expected_callee_ty)?;
+    Ok(ret)
+}
+
+/// Desugar `left |> right` into the call it rewrites to, mirroring
+/// `Evaluator`'s own pipe desugaring so both stages agree on semantics.
+fn desugar_pipe(left: &AstNode, right: &AstNode) -> AstNode {
+    match right {
+        AstNode::FunctionCall { name, args } => {
+            let mut piped_args = Vec::with_capacity(args.len() + 1);
+            piped_args.push(left.clone());
+            piped_args.extend_from_slice(args);
+            AstNode::FunctionCall { name: name.clone(), args: piped_args }
+        }
+        AstNode::CallExpression { callee, args } => {
+            let mut piped_args = Vec::with_capacity(args.len() + 1);
+            piped_args.push(left.clone());
+            piped_args.extend_from_slice(args);
+            AstNode::CallExpression { callee: callee.clone(), args: piped_args }
+        }
+        _ => AstNode::CallExpression {
+            callee: Box::new(right.clone()),
+            args: vec![left.clone()],
+        },
+    }
+}
@@ -1,28 +1,90 @@
 //! Specialized type validators for complex types
 
-use achronyme_parser::TypeAnnotation;
+use achronyme_parser::{ShapeDim, TypeAnnotation};
 use achronyme_types::value::Value;
 use std::collections::HashMap;
 
+use super::checker::DimEnv;
+use super::display::type_annotation_to_string;
+use super::error::{PathSegment, TypeError};
+
 /// Check structural typing for records
 /// A record matches if it has all required fields with correct types (extra fields are OK)
 pub(crate) fn check_record_structural_type(
     required_fields: &HashMap<String, (bool, TypeAnnotation)>,
     actual_fields: &HashMap<String, Value>,
+    dims: &mut DimEnv,
 ) -> bool {
     required_fields.iter().all(|(field_name, (_is_mut, field_type))| {
         actual_fields
             .get(field_name)
-            .map(|actual_value| super::checker::matches_type(actual_value, field_type))
+            .map(|actual_value| super::checker::matches_type(actual_value, field_type, dims))
             .unwrap_or(false)
     })
 }
 
+/// Does `shape` match `actual_shape` given the dimension bindings seen so
+/// far? The first time a named `Var(name)` is matched against a concrete
+/// extent it binds `name` in `dims`; every later match (in this tensor or
+/// another one checked with the same `dims`) must agree with that extent.
+fn shape_matches(expected: &[ShapeDim], actual_shape: &[usize], dims: &mut DimEnv) -> bool {
+    expected.len() == actual_shape.len() && first_mismatched_dim(expected, actual_shape, dims).is_none()
+}
+
+/// The single extent a `ShapeDim` resolves to for a given actual dimension:
+/// the fixed extent for `Fixed`, whatever `dims` has `Var(name)` bound to
+/// (or `act_dim` itself if this is its first occurrence), or `act_dim`
+/// unconditionally for `Any` since it imposes no constraint.
+fn resolved_extent(exp_dim: &ShapeDim, act_dim: usize, dims: &mut DimEnv) -> usize {
+    match exp_dim {
+        ShapeDim::Any => act_dim,
+        ShapeDim::Fixed(n) => *n,
+        ShapeDim::Var(name) => *dims.entry(name.clone()).or_insert(act_dim),
+    }
+}
+
+/// Find the first shape dimension that disagrees, binding named `Var`s
+/// into `dims` along the way. Returns `None` if every dimension agrees *or*
+/// the ranks differ outright - a rank mismatch isn't any one dimension's
+/// fault, so it's left for the caller to report as a whole-shape error
+/// instead.
+fn first_mismatched_dim(expected: &[ShapeDim], actual_shape: &[usize], dims: &mut DimEnv) -> Option<usize> {
+    if expected.len() != actual_shape.len() {
+        return None;
+    }
+    expected
+        .iter()
+        .zip(actual_shape)
+        .position(|(exp_dim, &act_dim)| resolved_extent(exp_dim, act_dim, dims) != act_dim)
+}
+
+/// Like [`first_mismatched_dim`], but also resolves the expected extent at
+/// that index (e.g. whatever a named `Var` was already bound to) rather
+/// than leaving the caller to format the raw `ShapeDim`, which for `Var`
+/// would print the variable's name instead of the extent it disagreed on.
+/// The third element names the dimension variable that conflicted, when
+/// the mismatch came from a `Var` whose binding disagreed with this
+/// occurrence - `None` for a plain `Fixed` mismatch, which has no variable
+/// to name.
+pub(crate) fn first_mismatched_shape_dim(
+    expected: &[ShapeDim],
+    actual_shape: &[usize],
+    dims: &mut DimEnv,
+) -> Option<(usize, usize, Option<String>)> {
+    let i = first_mismatched_dim(expected, actual_shape, dims)?;
+    let dim_name = match &expected[i] {
+        ShapeDim::Var(name) => Some(name.clone()),
+        ShapeDim::Fixed(_) | ShapeDim::Any => None,
+    };
+    Some((i, resolved_extent(&expected[i], actual_shape[i], dims), dim_name))
+}
+
 /// Check if a RealTensor matches a Tensor type annotation
 pub(crate) fn check_real_tensor_type(
     tensor: &achronyme_types::tensor::RealTensor,
     element_type: &TypeAnnotation,
-    expected_shape: Option<&Vec<Option<usize>>>,
+    expected_shape: Option<&Vec<ShapeDim>>,
+    dims: &mut DimEnv,
 ) -> bool {
     // Check element type - RealTensor contains f64, so element type must be Number
     let element_type_matches = matches!(element_type, TypeAnnotation::Number | TypeAnnotation::Any);
@@ -34,17 +96,7 @@ pub(crate) fn check_real_tensor_type(
     // Check shape if specified
     match expected_shape {
         None => true, // No shape constraint
-        Some(expected) => {
-            let actual_shape = tensor.shape();
-            // Rank must match
-            if expected.len() != actual_shape.len() {
-                return false;
-            }
-            // Each dimension must match (None means wildcard)
-            expected.iter().zip(actual_shape).all(|(exp_dim, &act_dim)| {
-                exp_dim.map_or(true, |e| e == act_dim)
-            })
-        }
+        Some(expected) => shape_matches(expected, tensor.shape(), dims),
     }
 }
 
@@ -52,7 +104,8 @@ pub(crate) fn check_real_tensor_type(
 pub(crate) fn check_complex_tensor_type(
     tensor: &achronyme_types::tensor::ComplexTensor,
     element_type: &TypeAnnotation,
-    expected_shape: Option<&Vec<Option<usize>>>,
+    expected_shape: Option<&Vec<ShapeDim>>,
+    dims: &mut DimEnv,
 ) -> bool {
     // Check element type - ComplexTensor contains Complex, so element type must be Complex
     let element_type_matches = matches!(element_type, TypeAnnotation::Complex | TypeAnnotation::Any);
@@ -64,33 +117,139 @@ pub(crate) fn check_complex_tensor_type(
     // Check shape if specified
     match expected_shape {
         None => true, // No shape constraint
-        Some(expected) => {
-            let actual_shape = tensor.shape();
-            // Rank must match
-            if expected.len() != actual_shape.len() {
-                return false;
+        Some(expected) => shape_matches(expected, tensor.shape(), dims),
+    }
+}
+
+/// Merge two record types per Dhall's recursive record-type-merge rule, for
+/// checking `{...a, ...b}` spread/merge expressions: a field present on only
+/// one side passes through with its original type and mutability; a field
+/// present on both sides must itself be a `Record` on both sides, merging
+/// recursively, otherwise it's an unrecoverable `FieldCollision`. The two
+/// sides disagreeing on `mut` for a shared field is also a collision - there
+/// is no single mutability to give the merged field.
+pub fn merge_record_types(
+    x: &TypeAnnotation,
+    y: &TypeAnnotation,
+) -> Result<TypeAnnotation, TypeError> {
+    let (x_fields, y_fields) = match (x, y) {
+        (TypeAnnotation::Record { fields: xf }, TypeAnnotation::Record { fields: yf }) => (xf, yf),
+        _ => {
+            return Err(TypeError::new(
+                "Record".to_string(),
+                format!("{} and {}", type_annotation_to_string(x), type_annotation_to_string(y)),
+            )
+            .with_context("record merge".to_string()))
+        }
+    };
+
+    let mut merged = x_fields.clone();
+
+    for (name, (y_is_mut, y_ty)) in y_fields {
+        match merged.get(name).cloned() {
+            None => {
+                merged.insert(name.clone(), (*y_is_mut, y_ty.clone()));
+            }
+            Some((x_is_mut, x_ty)) => {
+                if x_is_mut != *y_is_mut {
+                    return Err(TypeError::new(
+                        type_annotation_to_string(&x_ty),
+                        type_annotation_to_string(y_ty),
+                    )
+                    .with_context(format!("FieldCollision: {}", name))
+                    .with_segment(PathSegment::Field(name.clone())));
+                }
+
+                let merged_field_ty = merge_record_types(&x_ty, y_ty).map_err(|_| {
+                    TypeError::new(type_annotation_to_string(&x_ty), type_annotation_to_string(y_ty))
+                        .with_context(format!("FieldCollision: {}", name))
+                        .with_segment(PathSegment::Field(name.clone()))
+                })?;
+
+                merged.insert(name.clone(), (x_is_mut, merged_field_ty));
             }
-            // Each dimension must match (None means wildcard)
-            expected.iter().zip(actual_shape).all(|(exp_dim, &act_dim)| {
-                exp_dim.map_or(true, |e| e == act_dim)
-            })
         }
     }
+
+    Ok(TypeAnnotation::Record { fields: merged })
 }
 
 /// Check if a Function matches a Function type annotation
+///
+/// Builtins and any `expected_params`-empty annotation are opaque/unchecked
+/// signatures (only arity matters, matching [`super::subtype::is_subtype`]'s
+/// treatment of empty parameter lists). A `UserDefined` function with stored
+/// param/return annotations is checked as real function subtyping: each
+/// expected param must be a subtype of the function's declared param
+/// (contravariant), and the function's declared return must be a subtype of
+/// the expected return (covariant). A `None` slot on either side - declared
+/// param/return omitted, or the expected annotation untyped - is a gradual
+/// wildcard that always matches.
 pub(crate) fn check_function_type(
     func: &achronyme_types::function::Function,
-    expected_params: &Vec<Option<TypeAnnotation>>,
+    expected_params: &[Option<TypeAnnotation>],
+    expected_return: &TypeAnnotation,
 ) -> bool {
-    // Get the actual parameter count from the function
-    let actual_param_count = func.arity();
+    check_function_type_detailed(func, expected_params, expected_return).is_ok()
+}
 
-    // If expected_params is empty, we don't check parameter count
+/// Like [`check_function_type`], but reports a structured [`TypeError`]
+/// naming the offending parameter index (`PathSegment::Param`) or the
+/// return position (`PathSegment::Return`) instead of a flat bool, so a
+/// caller like [`super::checker::check_type_detailed`] can pinpoint which
+/// part of the signature disagreed rather than only the outermost types.
+pub(crate) fn check_function_type_detailed(
+    func: &achronyme_types::function::Function,
+    expected_params: &[Option<TypeAnnotation>],
+    expected_return: &TypeAnnotation,
+) -> Result<(), TypeError> {
     if expected_params.is_empty() {
-        return true;
+        return Ok(());
+    }
+
+    if func.arity() != expected_params.len() {
+        return Err(TypeError::new(
+            format!("a function of arity {}", expected_params.len()),
+            format!("a function of arity {}", func.arity()),
+        ));
+    }
+
+    let (declared_params, declared_return) = match func {
+        achronyme_types::function::Function::UserDefined { param_types, return_type, .. } => {
+            (param_types.as_slice(), return_type.as_ref())
+        }
+        achronyme_types::function::Function::Builtin(_) => return Ok(()),
+        // A curried partial's declared param/return types for the remaining
+        // args aren't tracked on the value itself (only the inner callable's
+        // full signature is) - same as `Builtin`, trust the arity check above.
+        achronyme_types::function::Function::Partial { .. } => return Ok(()),
+    };
+
+    // Contravariant: the function must accept at least what's expected of
+    // it, so the expected param type must be a subtype of the declared one.
+    for (i, (expected, declared)) in expected_params.iter().zip(declared_params.iter()).enumerate() {
+        let expected = expected.clone().unwrap_or(TypeAnnotation::Any);
+        let declared = declared.clone().unwrap_or(TypeAnnotation::Any);
+        if !super::subtype::is_subtype(&expected, &declared) {
+            return Err(TypeError::new(
+                type_annotation_to_string(&expected),
+                type_annotation_to_string(&declared),
+            )
+            .with_segment(PathSegment::Param(i)));
+        }
+    }
+
+    // Covariant: the function's declared return must be a subtype of
+    // whatever's expected of it.
+    if let Some(declared) = declared_return {
+        if !super::subtype::is_subtype(declared, expected_return) {
+            return Err(TypeError::new(
+                type_annotation_to_string(expected_return),
+                type_annotation_to_string(declared),
+            )
+            .with_segment(PathSegment::Return));
+        }
     }
 
-    // Otherwise, check parameter count matches
-    actual_param_count == expected_params.len()
+    Ok(())
 }
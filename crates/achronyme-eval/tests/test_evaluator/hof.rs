@@ -9,9 +9,9 @@ fn test_map_single_collection() {
     match result {
         Value::Vector(v) => {
             assert_eq!(v.len(), 3);
-            assert_eq!(v[0], Value::Number(2.0));
-            assert_eq!(v[1], Value::Number(4.0));
-            assert_eq!(v[2], Value::Number(6.0));
+            assert_eq!(v[0], Value::Integer(2));
+            assert_eq!(v[1], Value::Integer(4));
+            assert_eq!(v[2], Value::Integer(6));
         }
         _ => panic!("Expected vector"),
     }
@@ -24,9 +24,9 @@ fn test_map_multi_collection() {
     match result {
         Value::Vector(v) => {
             assert_eq!(v.len(), 3);
-            assert_eq!(v[0], Value::Number(5.0));
-            assert_eq!(v[1], Value::Number(7.0));
-            assert_eq!(v[2], Value::Number(9.0));
+            assert_eq!(v[0], Value::Integer(5));
+            assert_eq!(v[1], Value::Integer(7));
+            assert_eq!(v[2], Value::Integer(9));
         }
         _ => panic!("Expected vector"),
     }
@@ -39,8 +39,8 @@ fn test_map_truncates_to_shortest() {
     match result {
         Value::Vector(v) => {
             assert_eq!(v.len(), 2);
-            assert_eq!(v[0], Value::Number(4.0));
-            assert_eq!(v[1], Value::Number(6.0));
+            assert_eq!(v[0], Value::Integer(4));
+            assert_eq!(v[1], Value::Integer(6));
         }
         _ => panic!("Expected vector"),
     }
@@ -53,9 +53,9 @@ fn test_filter() {
     match result {
         Value::Vector(v) => {
             assert_eq!(v.len(), 3);
-            assert_eq!(v[0], Value::Number(3.0));
-            assert_eq!(v[1], Value::Number(4.0));
-            assert_eq!(v[2], Value::Number(5.0));
+            assert_eq!(v[0], Value::Integer(3));
+            assert_eq!(v[1], Value::Integer(4));
+            assert_eq!(v[2], Value::Integer(5));
         }
         _ => panic!("Expected vector"),
     }
@@ -69,9 +69,9 @@ fn test_filter_even_numbers() {
     match result {
         Value::Vector(v) => {
             assert_eq!(v.len(), 3);
-            assert_eq!(v[0], Value::Number(2.0));
-            assert_eq!(v[1], Value::Number(4.0));
-            assert_eq!(v[2], Value::Number(6.0));
+            assert_eq!(v[0], Value::Integer(2));
+            assert_eq!(v[1], Value::Integer(4));
+            assert_eq!(v[2], Value::Integer(6));
         }
         _ => panic!("Expected vector"),
     }
@@ -81,28 +81,63 @@ fn test_filter_even_numbers() {
 fn test_reduce_sum() {
     // reduce((acc, x) => acc + x, 0, [1,2,3,4]) → 10
     let result = eval("reduce((acc,x) => acc + x,0,[1,2,3,4])").unwrap();
-    assert_eq!(result, Value::Number(10.0));
+    assert_eq!(result, Value::Integer(10));
 }
 
 #[test]
 fn test_reduce_product() {
     // reduce((acc, x) => acc * x, 1, [2,3,4]) → 24
     let result = eval("reduce((acc,x) => acc * x,1,[2,3,4])").unwrap();
-    assert_eq!(result, Value::Number(24.0));
+    assert_eq!(result, Value::Integer(24));
 }
 
 #[test]
 fn test_reduce_max() {
     // reduce((acc, x) => max(acc, x), 0, [3,1,4,1,5,9]) → 9
     let result = eval("reduce((acc,x) => max(acc,x),0,[3,1,4,1,5,9])").unwrap();
-    assert_eq!(result, Value::Number(9.0));
+    assert_eq!(result, Value::Integer(9));
+}
+
+#[test]
+fn test_scan_sum() {
+    // scan((acc, x) => acc + x, 0, [1,2,3,4]) → [0,1,3,6,10]
+    let result = eval("scan((acc,x) => acc + x,0,[1,2,3,4])").unwrap();
+    match result {
+        Value::Vector(v) => {
+            assert_eq!(v, vec![
+                Value::Integer(0),
+                Value::Integer(1),
+                Value::Integer(3),
+                Value::Integer(6),
+                Value::Integer(10),
+            ]);
+        }
+        _ => panic!("Expected vector"),
+    }
+}
+
+#[test]
+fn test_scan_empty_collection_yields_init() {
+    // scan(f, init, []) → [init]
+    let result = eval("scan((acc,x) => acc + x,5,[])").unwrap();
+    match result {
+        Value::Vector(v) => assert_eq!(v, vec![Value::Integer(5)]),
+        _ => panic!("Expected vector"),
+    }
+}
+
+#[test]
+fn test_scan_non_binary_function() {
+    // scan with non-binary function should fail
+    let result = eval("scan(x => x * 2,0,[1,2,3])");
+    assert!(result.is_err());
 }
 
 #[test]
 fn test_pipe_simple() {
     // pipe(5, x => x * 2, x => x + 1) → 11
     let result = eval("pipe(5,x => x * 2,x => x + 1)").unwrap();
-    assert_eq!(result, Value::Number(11.0));
+    assert_eq!(result, Value::Integer(11));
 }
 
 #[test]
@@ -110,7 +145,7 @@ fn test_pipe_multiple_functions() {
     // pipe(2, x => x + 1, x => x * 2, x => x ^ 2) → 36
     // 2 → 3 → 6 → 36
     let result = eval("pipe(2,x => x + 1,x => x * 2,x => x ^ 2)").unwrap();
-    assert_eq!(result, Value::Number(36.0));
+    assert_eq!(result, Value::Integer(36));
 }
 
 #[test]
@@ -126,9 +161,9 @@ fn test_hof_composition() {
     match result {
         Value::Vector(v) => {
             assert_eq!(v.len(), 3);
-            assert_eq!(v[0], Value::Number(4.0));  // 2^2
-            assert_eq!(v[1], Value::Number(16.0)); // 4^2
-            assert_eq!(v[2], Value::Number(36.0)); // 6^2
+            assert_eq!(v[0], Value::Integer(4));  // 2^2
+            assert_eq!(v[1], Value::Integer(16)); // 4^2
+            assert_eq!(v[2], Value::Integer(36)); // 6^2
         }
         _ => panic!("Expected vector"),
     }
@@ -161,3 +196,179 @@ fn test_pipe_non_unary_function() {
     let result = eval("pipe(5,(x,y) => x + y)");
     assert!(result.is_err());
 }
+
+// ========================================================================
+// Integer preservation through HOFs
+//
+// Integer literal arithmetic (+ - * ^) stays closed over the integers, so
+// these check that each HOF keeps `Value::Integer` results, and that `/` or
+// a Number seed promotes them to `Value::Number`.
+// ========================================================================
+
+#[test]
+fn test_map_preserves_integer() {
+    // map(x => x * 2, [1,2,3]) → [2,4,6] as Integer
+    let result = eval("map(x => x * 2,[1,2,3])").unwrap();
+    match result {
+        Value::Vector(v) => {
+            assert_eq!(v, vec![Value::Integer(2), Value::Integer(4), Value::Integer(6)]);
+        }
+        _ => panic!("Expected vector"),
+    }
+}
+
+#[test]
+fn test_map_integer_division_promotes_to_number() {
+    // Division isn't closed over the integers, even when both operands are.
+    let result = eval("map(x => x / 2,[1,2,3])").unwrap();
+    match result {
+        Value::Vector(v) => {
+            assert_eq!(v, vec![Value::Number(0.5), Value::Number(1.0), Value::Number(1.5)]);
+        }
+        _ => panic!("Expected vector"),
+    }
+}
+
+#[test]
+fn test_filter_preserves_integer() {
+    // filter(x => x > 2, [1,2,3,4,5]) → [3,4,5] as Integer
+    let result = eval("filter(x => x > 2,[1,2,3,4,5])").unwrap();
+    match result {
+        Value::Vector(v) => {
+            assert_eq!(v, vec![Value::Integer(3), Value::Integer(4), Value::Integer(5)]);
+        }
+        _ => panic!("Expected vector"),
+    }
+}
+
+#[test]
+fn test_reduce_preserves_integer() {
+    // reduce((acc, x) => acc + x, 0, [1,2,3,4]) → 10 as Integer
+    let result = eval("reduce((acc,x) => acc + x,0,[1,2,3,4])").unwrap();
+    assert_eq!(result, Value::Integer(10));
+}
+
+#[test]
+fn test_reduce_mixed_with_number_seed_promotes() {
+    // A Number seed promotes the whole fold, even though every element is Integer.
+    let result = eval("reduce((acc,x) => acc + x,0.0,[1,2,3,4])").unwrap();
+    assert_eq!(result, Value::Number(10.0));
+}
+
+// ========================================================================
+// converge
+// ========================================================================
+
+#[test]
+fn test_converge_newtons_method_sqrt2() {
+    // converge(x => (x + 2/x)/2, 1) iterates Newton's method for sqrt(2)
+    // down to the default tolerance.
+    let result = eval("converge(x => (x + 2/x)/2,1)").unwrap();
+    match result {
+        Value::Number(n) => assert!((n - std::f64::consts::SQRT_2).abs() < 1e-9),
+        other => panic!("Expected number, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_converge_custom_tolerance_and_maxiter() {
+    // A loose tolerance should stop converge well before the tight default.
+    let result = eval("converge(x => (x + 2/x)/2,1,0.01,10)").unwrap();
+    match result {
+        Value::Number(n) => assert!((n - std::f64::consts::SQRT_2).abs() < 0.01),
+        other => panic!("Expected number, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_converge_stops_at_maxiter_without_reaching_tolerance() {
+    // An unreachable tolerance still returns the last iterate after maxiter steps.
+    let result = eval("converge(x => (x + 2/x)/2,1,1e-300,5)");
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_converge_vector_max_norm() {
+    // converge over a vector iterate uses the max-norm of the componentwise
+    // difference; here f is already at its fixed point.
+    let result = eval("converge(v => v,[1,2,3])").unwrap();
+    match result {
+        Value::Vector(v) => {
+            assert_eq!(v, vec![Value::Integer(1), Value::Integer(2), Value::Integer(3)]);
+        }
+        _ => panic!("Expected vector"),
+    }
+}
+
+#[test]
+fn test_converge_non_unary_function() {
+    // converge with a non-unary function should fail, mirroring pipe.
+    let result = eval("converge((x,y) => x + y,1)");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_converge_diverges_to_infinity() {
+    // x => x * 2 never settles and blows up past f64 range before maxiter.
+    let result = eval("converge(x => x * 2,1,1e-10,10000)");
+    assert!(result.is_err());
+}
+
+// ========================================================================
+// Currying / partial application
+// ========================================================================
+
+#[test]
+fn test_curried_lambda_returns_function() {
+    // add(10) with add = (x,y) => x+y is a partial application, not an error.
+    let result = eval("let add = (x,y) => x + y; add(10)").unwrap();
+    assert!(matches!(result, Value::Function(_)));
+}
+
+#[test]
+fn test_curried_lambda_full_application() {
+    // Applying the remaining args triggers the real call.
+    let result = eval("let add = (x,y) => x + y; add(10)(5)").unwrap();
+    assert_eq!(result, Value::Integer(15));
+}
+
+#[test]
+fn test_curried_lambda_satisfies_map_arity() {
+    // map(add(10), [1,2,3]) → [11,12,13]; add(10) is a unary callable by its
+    // *remaining* arity, even though `add` itself is binary.
+    let result = eval("let add = (x,y) => x + y; map(add(10),[1,2,3])").unwrap();
+    match result {
+        Value::Vector(v) => {
+            assert_eq!(v, vec![Value::Integer(11), Value::Integer(12), Value::Integer(13)]);
+        }
+        _ => panic!("Expected vector"),
+    }
+}
+
+#[test]
+fn test_curried_lambda_composes_with_pipe() {
+    // pipe(5, add(10)) → 15
+    let result = eval("let add = (x,y) => x + y; pipe(5,add(10))").unwrap();
+    assert_eq!(result, Value::Integer(15));
+}
+
+#[test]
+fn test_curried_builtin_full_application() {
+    // pow is a 2-arity builtin; partially applying it curries the same way.
+    let result = eval("let square = pow(2); square(3)").unwrap();
+    assert_eq!(result, Value::Number(8.0));
+}
+
+#[test]
+fn test_curried_lambda_too_many_args_errors() {
+    // Over-applying a partial is still an arity error.
+    let result = eval("let add = (x,y) => x + y; add(10)(1,2)");
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_curried_lambda_step_by_step() {
+    // Currying can be split across more than one call.
+    let result = eval("let add3 = (x,y,z) => x + y + z; let f = add3(1); let g = f(2); g(3)").unwrap();
+    assert_eq!(result, Value::Integer(6));
+}
@@ -120,7 +120,7 @@ fn test_matrix_functions_accessible() {
 
 #[test]
 fn test_vector_functions_accessible() {
-    let functions = vec!["map", "filter", "reduce", "pipe"];
+    let functions = vec!["map", "filter", "reduce", "scan", "pipe"];
 
     for func_name in functions {
         let code = format!("let f = {}; f", func_name);
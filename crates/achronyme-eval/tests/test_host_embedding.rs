@@ -0,0 +1,81 @@
+/// Tests for the host embedding API: pre-seeding variables with
+/// `with_variables` and registering Rust-backed callbacks with
+/// `register_native_fn`.
+
+use achronyme_eval::Evaluator;
+use achronyme_types::value::Value;
+use std::collections::HashMap;
+
+#[test]
+fn test_with_variables_resolves_in_expression() {
+    let mut vars = HashMap::new();
+    vars.insert("foo".to_string(), Value::Integer(10));
+    vars.insert("bar".to_string(), Value::Integer(32));
+
+    let mut evaluator = Evaluator::new().with_variables(vars);
+    let result = evaluator.eval_str("foo + bar").unwrap();
+    assert_eq!(result, Value::Integer(42));
+}
+
+#[test]
+fn test_register_native_fn_direct_call() {
+    let mut evaluator = Evaluator::new();
+    evaluator.register_native_fn("say_hello", 0, |_args| {
+        Ok(Value::String("hello".to_string()))
+    });
+
+    let result = evaluator.eval_str("say_hello()").unwrap();
+    assert_eq!(result, Value::String("hello".to_string()));
+}
+
+#[test]
+fn test_register_native_fn_enforces_arity() {
+    let mut evaluator = Evaluator::new();
+    evaluator.register_native_fn("double", 1, |args| match &args[0] {
+        Value::Integer(n) => Ok(Value::Integer(n * 2)),
+        Value::Number(n) => Ok(Value::Number(n * 2.0)),
+        _ => Err("double expects a number".to_string()),
+    });
+
+    assert!(evaluator.eval_str("double(5)").is_ok());
+    assert!(evaluator.eval_str("double(5, 6)").is_err());
+}
+
+#[test]
+fn test_native_fn_usable_as_first_class_value_in_hof() {
+    let mut evaluator = Evaluator::new();
+    evaluator.register_native_fn("double", 1, |args| match &args[0] {
+        Value::Integer(n) => Ok(Value::Integer(n * 2)),
+        _ => Err("double expects an integer".to_string()),
+    });
+
+    // map(my_native_fn, xs) should resolve and call the native function
+    // through the same path as a built-in.
+    let result = evaluator.eval_str("map(double, [1, 2, 3])").unwrap();
+    match result {
+        Value::Vector(v) => {
+            assert_eq!(v, vec![Value::Integer(2), Value::Integer(4), Value::Integer(6)]);
+        }
+        other => panic!("Expected vector, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_native_fn_can_capture_host_state() {
+    let lookup: HashMap<&str, i64> = [("alice", 30), ("bob", 25)].into_iter().collect();
+
+    let mut evaluator = Evaluator::new();
+    evaluator.register_native_fn("age_of", 1, move |args| match &args[0] {
+        Value::String(name) => lookup
+            .get(name.as_str())
+            .map(|&age| Value::Integer(age))
+            .ok_or_else(|| format!("unknown name: {}", name)),
+        _ => Err("age_of expects a string".to_string()),
+    });
+
+    let result = evaluator.eval_str("age_of(\"alice\")").unwrap();
+    assert_eq!(result, Value::Integer(30));
+
+    let err = evaluator.eval_str("age_of(\"carol\")");
+    assert!(err.is_err());
+}
@@ -120,7 +120,7 @@ fn test_all_real_numbers() {
 
     match result {
         Value::Vector(v) => {
-            assert_eq!(v, vec![Value::Number(1.0), Value::Number(2.0), Value::Number(3.0), Value::Number(4.0)]);
+            assert_eq!(v, vec![Value::Integer(1), Value::Integer(2), Value::Integer(3), Value::Integer(4)]);
         }
         _ => panic!("Expected Vector, got {:?}", result),
     }
@@ -186,7 +186,7 @@ fn test_single_element_real() {
 
     match result {
         Value::Vector(v) => {
-            assert_eq!(v, vec![Value::Number(42.0)]);
+            assert_eq!(v, vec![Value::Integer(42)]);
         }
         _ => panic!("Expected Vector, got {:?}", result),
     }
@@ -2,6 +2,7 @@
 //!
 //! Provides finite difference methods for calculating derivatives numerically.
 
+use achronyme_types::dual::Dual;
 use achronyme_types::function::Function;
 use achronyme_types::LambdaEvaluator;
 
@@ -158,6 +159,29 @@ where
     Ok(grad)
 }
 
+/// Exact derivative via automatic differentiation (dual numbers)
+///
+/// Evaluates `func` once at `x = (x, 1)` and returns the dual component,
+/// which is the exact derivative `f'(x)` (to floating-point precision) —
+/// no step size, and none of the truncation/round-off tradeoffs finite
+/// differences (`diff_central` et al.) have.
+///
+/// `func`'s body must reach its result purely through dual-aware arithmetic
+/// and elementary functions; a function that branches on `abs` or a
+/// comparison is only piecewise differentiable this way.
+///
+/// # Arguments
+/// * `evaluator` - Lambda evaluator
+/// * `func` - Function to differentiate
+/// * `x` - Point at which to calculate the derivative
+pub fn autodiff<E>(evaluator: &mut E, func: &Function, x: f64) -> Result<f64, String>
+where
+    E: LambdaEvaluator,
+{
+    let result = evaluator.eval_dual_at(func, Dual::variable(x))?;
+    Ok(result.dual)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -208,6 +232,13 @@ mod tests {
         // TODO: Refactor to use Evaluator + Function
     }
 
+    #[test]
+    #[ignore]
+    fn test_autodiff_square() {
+        // f(x) = x * x, autodiff(f, 3) = 6 exactly, no step size
+        // TODO: Refactor to use Evaluator + Function
+    }
+
     #[test]
     #[ignore]
     fn test_trig_functions() {
@@ -2,6 +2,9 @@
 //!
 //! Provides methods for numerical integration (quadrature).
 
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
 use achronyme_types::function::Function;
 use achronyme_types::LambdaEvaluator;
 
@@ -249,6 +252,179 @@ where
     }
 }
 
+/// Non-negative Kronrod nodes for the 15-point rule (the 8th node is 0,
+/// the shared midpoint, and is handled separately below).
+const GK15_NODES: [f64; 7] = [
+    0.991455371120813,
+    0.949107912342759,
+    0.864864423359769,
+    0.741531185599394,
+    0.586087235467691,
+    0.405845151377397,
+    0.207784955007898,
+];
+
+/// Kronrod weights, one per node above plus the midpoint (index 7).
+const GK15_WEIGHTS: [f64; 8] = [
+    0.022935322010529,
+    0.063092092629979,
+    0.104790010322250,
+    0.140653259715525,
+    0.169004726639267,
+    0.190350578064785,
+    0.204432940075298,
+    0.209482141084728,
+];
+
+/// Gauss weights for the embedded 7-point rule, which reuses the Kronrod
+/// nodes at indices 1, 3, 5 (and the shared midpoint).
+const G7_WEIGHTS: [f64; 4] = [
+    0.129484966168870,
+    0.279705391489277,
+    0.381830050505119,
+    0.417959183673469,
+];
+
+/// The Kronrod estimate, embedded Gauss estimate, and evaluation count for
+/// one `[a, b]` subinterval under the G7-K15 pair.
+struct GkEstimate {
+    kronrod: f64,
+    gauss: f64,
+    evaluations: usize,
+}
+
+fn gk15_estimate<E>(evaluator: &mut E, func: &Function, a: f64, b: f64) -> Result<GkEstimate, String>
+where
+    E: LambdaEvaluator,
+{
+    let center = 0.5 * (a + b);
+    let half_length = 0.5 * (b - a);
+
+    let f_center = evaluator.eval_at(func, center)?;
+    let mut kronrod = f_center * GK15_WEIGHTS[7];
+    let mut gauss = f_center * G7_WEIGHTS[3];
+    let mut evaluations = 1;
+
+    for (i, &node) in GK15_NODES.iter().enumerate() {
+        let dx = half_length * node;
+        let f_plus = evaluator.eval_at(func, center + dx)?;
+        let f_minus = evaluator.eval_at(func, center - dx)?;
+        evaluations += 2;
+
+        kronrod += GK15_WEIGHTS[i] * (f_plus + f_minus);
+        if i % 2 == 1 {
+            // Nodes at indices 1, 3, 5 are the shared 7-point Gauss nodes.
+            gauss += G7_WEIGHTS[i / 2] * (f_plus + f_minus);
+        }
+    }
+
+    Ok(GkEstimate {
+        kronrod: kronrod * half_length,
+        gauss: gauss * half_length,
+        evaluations,
+    })
+}
+
+/// Local error estimate for one subinterval: `(200|K - G|)^1.5`, clamped so
+/// it can never exceed the width of the subinterval it describes.
+fn gk_local_error(kronrod: f64, gauss: f64, width: f64) -> f64 {
+    (200.0 * (kronrod - gauss).abs()).powf(1.5).min(width.abs())
+}
+
+/// One leaf subinterval in the adaptive quadrature queue, ordered by its
+/// local error so the worst offender is always popped first.
+struct GkInterval {
+    a: f64,
+    b: f64,
+    value: f64,
+    error: f64,
+}
+
+impl PartialEq for GkInterval {
+    fn eq(&self, other: &Self) -> bool {
+        self.error == other.error
+    }
+}
+
+impl Eq for GkInterval {}
+
+impl PartialOrd for GkInterval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for GkInterval {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.error.partial_cmp(&other.error).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Maximum number of bisections the adaptive queue will perform before
+/// giving up on reaching `tol`.
+const GK_MAX_SUBDIVISIONS: usize = 200;
+
+/// Result of adaptive Gauss-Kronrod quadrature: the integral estimate, the
+/// estimated total error, and how many function evaluations it took.
+pub struct QuadGkResult {
+    pub value: f64,
+    pub error: f64,
+    pub evaluations: usize,
+}
+
+/// Adaptive Gauss-Kronrod quadrature (the G7-K15 pair)
+///
+/// Unlike `quad`/`romberg`, which only return a number, this drives a
+/// priority queue of subintervals keyed by local error: it repeatedly
+/// bisects the worst subinterval and re-evaluates both halves until the
+/// summed error estimate drops below `tol` (or the subdivision budget runs
+/// out), so callers can see the accuracy actually achieved.
+///
+/// # Arguments
+/// * `evaluator` - Lambda evaluator
+/// * `func` - Function to integrate
+/// * `a` - Lower limit of integration
+/// * `b` - Upper limit of integration
+/// * `tol` - Desired total error tolerance
+pub fn quad_gk<E>(evaluator: &mut E, func: &Function, a: f64, b: f64, tol: f64) -> Result<QuadGkResult, String>
+where
+    E: LambdaEvaluator,
+{
+    let root = gk15_estimate(evaluator, func, a, b)?;
+    let mut evaluations = root.evaluations;
+    let mut total_error = gk_local_error(root.kronrod, root.gauss, b - a);
+
+    let mut heap = BinaryHeap::new();
+    heap.push(GkInterval { a, b, value: root.kronrod, error: total_error });
+
+    let mut subdivisions = 0;
+    while total_error > tol && subdivisions < GK_MAX_SUBDIVISIONS {
+        let worst = match heap.pop() {
+            Some(interval) => interval,
+            None => break,
+        };
+        total_error -= worst.error;
+
+        let mid = 0.5 * (worst.a + worst.b);
+        let left = gk15_estimate(evaluator, func, worst.a, mid)?;
+        let right = gk15_estimate(evaluator, func, mid, worst.b)?;
+        evaluations += left.evaluations + right.evaluations;
+
+        let left_error = gk_local_error(left.kronrod, left.gauss, mid - worst.a);
+        let right_error = gk_local_error(right.kronrod, right.gauss, worst.b - mid);
+
+        heap.push(GkInterval { a: worst.a, b: mid, value: left.kronrod, error: left_error });
+        heap.push(GkInterval { a: mid, b: worst.b, value: right.kronrod, error: right_error });
+
+        total_error += left_error + right_error;
+        subdivisions += 1;
+    }
+
+    let value = heap.iter().map(|interval| interval.value).sum();
+
+    Ok(QuadGkResult { value, error: total_error, evaluations })
+}
+
 /// Integrate a discrete dataset using trapezoidal rule
 ///
 /// Useful when you have data points instead of a function.
@@ -349,4 +525,41 @@ mod tests {
         // Expected: approximately 1/3
         assert!((result - 1.0 / 3.0).abs() < 0.05);
     }
+
+    /// Minimal `LambdaEvaluator` that ignores `func` entirely and always
+    /// evaluates `sin`, just enough to exercise `quad_gk`'s adaptive loop
+    /// without depending on `achronyme-eval`'s real evaluator.
+    struct SinEvaluator;
+
+    impl LambdaEvaluator for SinEvaluator {
+        fn eval_at(&mut self, _func: &Function, x: f64) -> Result<f64, String> {
+            Ok(x.sin())
+        }
+
+        fn eval_vec_at(&mut self, _func: &Function, _point: &[f64]) -> Result<f64, String> {
+            unimplemented!("not exercised by test_quad_gk_trig")
+        }
+
+        fn eval_at_nd(&mut self, _func: &Function, _args: &[f64]) -> Result<f64, String> {
+            unimplemented!("not exercised by test_quad_gk_trig")
+        }
+
+        fn eval_dual_at(&mut self, _func: &Function, _x: achronyme_types::dual::Dual) -> Result<achronyme_types::dual::Dual, String> {
+            unimplemented!("not exercised by test_quad_gk_trig")
+        }
+
+        fn eval_ty_at(&mut self, _func: &Function, _t: f64, _y: &[f64]) -> Result<Vec<f64>, String> {
+            unimplemented!("not exercised by test_quad_gk_trig")
+        }
+    }
+
+    #[test]
+    fn test_quad_gk_trig() {
+        // ∫sin(x) dx from 0 to π = 2, with error well under 1e-10
+        let mut evaluator = SinEvaluator;
+        let func = Function::Builtin("sin".to_string());
+        let result = quad_gk(&mut evaluator, &func, 0.0, PI, 1e-10).unwrap();
+        assert!((result.value - 2.0).abs() < 1e-9);
+        assert!(result.error < 1e-9);
+    }
 }
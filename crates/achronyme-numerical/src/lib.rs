@@ -5,15 +5,18 @@
 //! # Modules
 //! - `differentiation` - Numerical derivatives (forward, backward, central differences)
 //! - `integration` - Numerical integration (trapezoid, Simpson, Romberg)
+//! - `ode` - Initial-value ODE integration (adaptive Runge-Kutta-Fehlberg)
 //! - `solvers` - Root finding and equation solvers (bisection, Newton, secant)
 
 pub mod differentiation;
 pub mod integration;
+pub mod ode;
 pub mod solvers;
 
 // Re-exports for convenience
 pub use differentiation::*;
 pub use integration::*;
+pub use ode::*;
 pub use solvers::*;
 
 #[cfg(test)]
@@ -5,6 +5,8 @@
 use achronyme_types::function::Function;
 use achronyme_types::LambdaEvaluator;
 
+use crate::differentiation::autodiff;
+
 /// Bisection method for root finding
 ///
 /// Finds a root of f(x) = 0 in the interval [a, b].
@@ -105,6 +107,46 @@ where
     Ok(x)
 }
 
+/// Newton-Raphson method using automatic differentiation for `f'`
+///
+/// Same iteration as `newton`, but obtains `f'(x_n)` from `autodiff` instead
+/// of a second, hand-coded derivative function — so callers only supply `f`.
+///
+/// # Arguments
+/// * `evaluator` - Lambda evaluator
+/// * `func` - Function for which to find the root
+/// * `x0` - Initial guess
+/// * `tol` - Tolerance (stop when |f(x)| < tol)
+/// * `max_iter` - Maximum number of iterations
+pub fn newton_autodiff<E>(
+    evaluator: &mut E,
+    func: &Function,
+    mut x: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Result<f64, String>
+where
+    E: LambdaEvaluator,
+{
+    for _ in 0..max_iter {
+        let fx = evaluator.eval_at(func, x)?;
+
+        if fx.abs() < tol {
+            return Ok(x);
+        }
+
+        let dfx = autodiff(evaluator, func, x)?;
+
+        if dfx.abs() < 1e-12 {
+            return Err("Newton (autodiff): derivative too small, cannot continue".to_string());
+        }
+
+        x = x - fx / dfx;
+    }
+
+    Ok(x)
+}
+
 /// Secant method for root finding
 ///
 /// Similar to Newton's method but doesn't require the derivative.
@@ -33,6 +33,10 @@ use crate::type_annotation::TypeAnnotation;
 #[derive(Debug, Clone, PartialEq)]
 pub enum AstNode {
     Number(f64),
+    /// A whole number literal (no decimal point/exponent in source), kept
+    /// distinct from `Number` so the evaluator can report the narrower
+    /// `Integer` type for arithmetic that stays closed over the integers.
+    Integer(i64),
     Boolean(bool),
     StringLiteral(String),
     /// Null literal (for optional types)
@@ -63,6 +67,13 @@ pub enum AstNode {
         callee: Box<AstNode>,  // Expression that evaluates to a function (for IIFE)
         args: Vec<AstNode>,
     },
+    // Pipe expression: left |> right
+    // Desugars to a call of `right` with `left` inserted as its first argument:
+    // `x |> g(a, b)` becomes `g(x, a, b)`; `x |> g` (bare callee) becomes `g(x)`.
+    Pipe {
+        left: Box<AstNode>,
+        right: Box<AstNode>,
+    },
     ComplexLiteral {
         re: f64,
         im: f64,
@@ -6,4 +6,4 @@ pub mod type_annotation;
 // Re-export commonly used items
 pub use pest_parser::parse;
 pub use ast::AstNode;
-pub use type_annotation::TypeAnnotation;
\ No newline at end of file
+pub use type_annotation::{ShapeDim, TypeAnnotation};
\ No newline at end of file
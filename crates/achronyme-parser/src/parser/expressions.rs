@@ -12,6 +12,7 @@ impl AstParser {
                     .ok_or("Empty expression")?;
                 self.build_ast_from_expr(inner)
             }
+            Rule::pipe_expr => self.build_pipe_expr(pair),
             Rule::logical_or => self.build_binary_op(pair),
             Rule::logical_and => self.build_binary_op(pair),
             Rule::comparison => self.build_comparison(pair),
@@ -74,6 +75,26 @@ impl AstParser {
         })
     }
 
+    // Pipe expression: logical_or ("|>" logical_or)*
+    // Binds looser than every operator it contains but tighter than assignment,
+    // so `x |> g(y)` parses as one call and `let z = x |> g` still assigns to z.
+    // Left-associative: `a |> f |> g` is `(a |> f) |> g`.
+    pub(super) fn build_pipe_expr(&mut self, pair: Pair<Rule>) -> Result<AstNode, String> {
+        let mut operands = pair.into_inner();
+        let first = operands.next().ok_or("Empty pipe expression")?;
+        let mut left = self.build_ast_from_expr(first)?;
+
+        for operand in operands {
+            let right = self.build_ast_from_expr(operand)?;
+            left = AstNode::Pipe {
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+
+        Ok(left)
+    }
+
     pub(super) fn build_comparison(&mut self, pair: Pair<Rule>) -> Result<AstNode, String> {
         let pairs: Vec<_> = pair.into_inner().collect();
 
@@ -23,7 +23,17 @@ impl AstParser {
                 Ok(AstNode::StringLiteral(processed))
             }
             Rule::number => {
-                let num = inner.as_str().parse::<f64>()
+                let text = inner.as_str();
+                // A plain digit string (no decimal point or exponent) is an
+                // Integer unless it overflows i64, in which case it falls
+                // back to Number instead of failing to parse.
+                let is_decimal = text.contains('.') || text.contains('e') || text.contains('E');
+                if !is_decimal {
+                    if let Ok(int_val) = text.parse::<i64>() {
+                        return Ok(AstNode::Integer(int_val));
+                    }
+                }
+                let num = text.parse::<f64>()
                     .map_err(|e| format!("Failed to parse number: {}", e))?;
                 Ok(AstNode::Number(num))
             }
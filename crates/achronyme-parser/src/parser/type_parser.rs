@@ -2,7 +2,7 @@
 //
 // This module handles parsing of type annotations from Pest pairs into TypeAnnotation AST nodes.
 // Supports:
-// - Simple types: Number, Boolean, String, Complex, Edge
+// - Simple types: Number, Integer, Boolean, String, Complex, Edge
 // - Union types: Number | String | null
 // - Tensor types: Tensor<Number>, Tensor<Complex, [2,3]>
 // - Record types: {name: String, mut age: Number}
@@ -11,7 +11,7 @@
 
 use pest::iterators::Pair;
 use crate::pest_parser::Rule;
-use crate::type_annotation::TypeAnnotation;
+use crate::type_annotation::{ShapeDim, TypeAnnotation};
 use crate::parser::AstParser;
 use std::collections::HashMap;
 
@@ -34,6 +34,7 @@ impl AstParser {
             }
             Rule::simple_type => self.parse_simple_type(pair),
             Rule::tensor_type => self.parse_tensor_type(pair),
+            Rule::generator_type => self.parse_generator_type(pair),
             Rule::vector_type => Ok(TypeAnnotation::Vector),
             Rule::record_type => self.parse_record_type(pair),
             Rule::function_type => self.parse_function_type(pair),
@@ -57,17 +58,23 @@ impl AstParser {
         }
     }
 
-    /// Parse simple types: Number, Boolean, String, Complex, Generator, Edge, Error
+    /// Parse simple types: Number, Integer, Boolean, String, Complex, Edge, Error
+    /// (`Generator` is handled by `parse_generator_type` since it takes an
+    /// optional `<T>` element type, like `Tensor`)
     fn parse_simple_type(&mut self, pair: Pair<Rule>) -> Result<TypeAnnotation, String> {
         let type_str = pair.as_str();
         match type_str {
             "Number" => Ok(TypeAnnotation::Number),
+            "Integer" => Ok(TypeAnnotation::Integer),
             "Boolean" => Ok(TypeAnnotation::Boolean),
             "String" => Ok(TypeAnnotation::String),
             "Complex" => Ok(TypeAnnotation::Complex),
-            "Generator" => Ok(TypeAnnotation::Generator),
             "Function" => Ok(TypeAnnotation::AnyFunction),
-            "Error" => Ok(TypeAnnotation::Error),
+            // A source-level `Error` annotation has no particular message in
+            // hand yet - it's whatever gets thrown at that point - so parse
+            // to the empty message; the type checker fills in a real one
+            // when it poisons an expression's type after a failed check.
+            "Error" => Ok(TypeAnnotation::Error(String::new())),
             "Edge" => Ok(TypeAnnotation::Edge),
             _ => Err(format!("Unknown simple type: {}", type_str))
         }
@@ -116,8 +123,19 @@ impl AstParser {
         })
     }
 
-    /// Parse shape specification: [2, 3] or [_, _]
-    fn parse_shape_spec(&mut self, pair: Pair<Rule>) -> Result<Vec<Option<usize>>, String> {
+    /// Parse generator types: `Generator` (bare, gradual element type) or
+    /// `Generator<Number>` (known yield type)
+    fn parse_generator_type(&mut self, pair: Pair<Rule>) -> Result<TypeAnnotation, String> {
+        let element_type = match pair.into_inner().next() {
+            Some(element_pair) => Box::new(self.parse_type_annotation(element_pair)?),
+            None => Box::new(TypeAnnotation::Any),
+        };
+
+        Ok(TypeAnnotation::Generator { element_type })
+    }
+
+    /// Parse shape specification: [2, 3], [_, _], or [N, M]
+    fn parse_shape_spec(&mut self, pair: Pair<Rule>) -> Result<Vec<ShapeDim>, String> {
         let mut dims = Vec::new();
 
         for dim_pair in pair.into_inner() {
@@ -128,17 +146,19 @@ impl AstParser {
         Ok(dims)
     }
 
-    /// Parse a single dimension: number or _
-    fn parse_dimension(&mut self, pair: Pair<Rule>) -> Result<Option<usize>, String> {
+    /// Parse a single dimension: a number, `_`, or a named dimension
+    /// variable (e.g. `N` in `Tensor<Number, [N, M]>`).
+    fn parse_dimension(&mut self, pair: Pair<Rule>) -> Result<ShapeDim, String> {
         let dim_str = pair.as_str();
 
         if dim_str == "_" {
-            Ok(None)  // Unknown dimension
-        } else {
-            // Parse as number
+            Ok(ShapeDim::Any)
+        } else if dim_str.chars().next().map_or(false, |c| c.is_ascii_digit()) {
             dim_str.parse::<usize>()
-                .map(Some)
+                .map(ShapeDim::Fixed)
                 .map_err(|e| format!("Invalid dimension '{}': {}", dim_str, e))
+        } else {
+            Ok(ShapeDim::Var(dim_str.to_string()))
         }
     }
 
@@ -309,10 +329,40 @@ mod tests {
         match ty {
             TypeAnnotation::Tensor { element_type, shape } => {
                 assert_eq!(*element_type, TypeAnnotation::Number);
-                assert_eq!(shape, Some(vec![Some(2), Some(3)]));
+                assert_eq!(shape, Some(vec![ShapeDim::Fixed(2), ShapeDim::Fixed(3)]));
             }
             _ => panic!("Expected Tensor type, got {:?}", ty)
         }
+
+        // Tensor<Number, [N, M]> - named dimension variables
+        let pairs = SOCParser::parse(Rule::type_annotation, "Tensor<Number, [N, M]>").unwrap();
+        let ty = parser.parse_type_annotation(pairs.into_iter().next().unwrap()).unwrap();
+
+        match ty {
+            TypeAnnotation::Tensor { element_type, shape } => {
+                assert_eq!(*element_type, TypeAnnotation::Number);
+                assert_eq!(
+                    shape,
+                    Some(vec![ShapeDim::Var("N".to_string()), ShapeDim::Var("M".to_string())])
+                );
+            }
+            _ => panic!("Expected Tensor type, got {:?}", ty)
+        }
+    }
+
+    #[test]
+    fn test_parse_generator_types() {
+        let mut parser = AstParser::new();
+
+        // Bare Generator - gradual element type
+        let pairs = SOCParser::parse(Rule::type_annotation, "Generator").unwrap();
+        let ty = parser.parse_type_annotation(pairs.into_iter().next().unwrap()).unwrap();
+        assert_eq!(ty, TypeAnnotation::Generator { element_type: Box::new(TypeAnnotation::Any) });
+
+        // Generator<Number> - known yield type
+        let pairs = SOCParser::parse(Rule::type_annotation, "Generator<Number>").unwrap();
+        let ty = parser.parse_type_annotation(pairs.into_iter().next().unwrap()).unwrap();
+        assert_eq!(ty, TypeAnnotation::Generator { element_type: Box::new(TypeAnnotation::Number) });
     }
 
     #[test]
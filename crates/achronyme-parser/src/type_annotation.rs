@@ -15,12 +15,44 @@
 
 use std::collections::HashMap;
 
+/// A single tensor dimension extent.
+///
+/// `Fixed` and `Any` are the two forms concrete shapes always had; `Var`
+/// names a dimension so shape-checking code can enforce that every
+/// occurrence of that name resolves to the same extent within a checking
+/// scope (e.g. `Tensor<Number, [N, M]>` paired with `Tensor<Number, [M, K]>`
+/// ties both tensors' middle dimension to one `M`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShapeDim {
+    /// Concrete, known extent.
+    Fixed(usize),
+    /// Unconstrained - matches any extent.
+    Any,
+    /// Named dimension variable, e.g. `N` in `Tensor<Number, [N, M]>`.
+    Var(String),
+}
+
+impl ShapeDim {
+    pub fn to_string(&self) -> String {
+        match self {
+            ShapeDim::Fixed(n) => n.to_string(),
+            ShapeDim::Any => "_".to_string(),
+            ShapeDim::Var(name) => name.clone(),
+        }
+    }
+}
+
 /// Type annotation for gradual typing system (AST representation)
 #[derive(Debug, Clone, PartialEq)]
 pub enum TypeAnnotation {
     /// Number type (f64)
     Number,
 
+    /// Integer type (i64), a subtype of Number - whole-number literals and
+    /// arithmetic that stays closed over the integers infer this narrower
+    /// type instead of the general Number.
+    Integer,
+
     /// Boolean type
     Boolean,
 
@@ -31,13 +63,14 @@ pub enum TypeAnnotation {
     Complex,
 
     /// Tensor type with optional element type and shape
-    /// shape: None = unknown rank, Some(vec) = known rank with optional dimensions
+    /// shape: None = unknown rank, Some(vec) = known rank with per-dimension extents
     /// Example: Tensor<Number> has shape=None
-    /// Example: Tensor<Number, [2, 3]> has shape=Some(vec![Some(2), Some(3)])
-    /// Example: Tensor<Number, [_, _]> has shape=Some(vec![None, None])
+    /// Example: Tensor<Number, [2, 3]> has shape=Some(vec![Fixed(2), Fixed(3)])
+    /// Example: Tensor<Number, [_, _]> has shape=Some(vec![Any, Any])
+    /// Example: Tensor<Number, [N, M]> has shape=Some(vec![Var("N"), Var("M")])
     Tensor {
         element_type: Box<TypeAnnotation>,
-        shape: Option<Vec<Option<usize>>>,
+        shape: Option<Vec<ShapeDim>>,
     },
 
     /// Vector type (heterogeneous array)
@@ -60,15 +93,23 @@ pub enum TypeAnnotation {
     /// Edge type (graph edges: A -> B, A <> B)
     Edge,
 
-    /// Generator type (opaque, does not track yield type)
-    /// Represents a resumable function that can yield values
-    /// Future: Generator<T> for typed generators
-    Generator,
+    /// Generator type: a resumable function that can yield values of
+    /// `element_type`. A bare `Generator` in source parses to
+    /// `element_type: Any`, so untyped generators still type-check
+    /// gradually instead of being rejected.
+    Generator {
+        element_type: Box<TypeAnnotation>,
+    },
 
-    /// Error type (opaque, represents any error value)
-    /// Used for try/catch/throw error handling
-    /// Error values have message, optional kind, and optional source
-    Error,
+    /// Error type, carrying a message.
+    /// Used for try/catch/throw error handling, where the message is
+    /// whatever the source program's `throw`/catch clause put there.
+    /// Also produced internally by the type checker to "poison" the type of
+    /// an expression that already failed to check, so that failure doesn't
+    /// cascade into spurious downstream errors - any check involving an
+    /// `Error` succeeds, carrying the original message through for
+    /// reporting rather than a fresh, less useful mismatch.
+    Error(String),
 
     /// Opaque function type (accepts any function without checking signature)
     /// Use when you need to accept any callable, regardless of params/return
@@ -88,6 +129,17 @@ pub enum TypeAnnotation {
     /// Accepts any value
     Any,
 
+    /// Bottom type (no value ever has this type)
+    /// Subtype of every other type, dual to `Any`; used for exhaustiveness
+    /// and as the natural result type of code that never returns normally
+    Never,
+
+    /// Unification variable produced during Hindley-Milner inference
+    /// (`achronyme_eval::type_checker`'s `UnificationTable`). Should not
+    /// survive past `zonk` - a leftover `TypeVar` means inference couldn't
+    /// pin it down and it was defaulted to `Any`.
+    TypeVar(u32),
+
     /// Type reference (alias to another type definition)
     /// Example: Point, Result, ApiResponse
     TypeReference(String),
@@ -98,16 +150,25 @@ impl TypeAnnotation {
     pub fn to_string(&self) -> String {
         match self {
             TypeAnnotation::Number => "Number".to_string(),
+            TypeAnnotation::Integer => "Integer".to_string(),
             TypeAnnotation::Boolean => "Boolean".to_string(),
             TypeAnnotation::String => "String".to_string(),
             TypeAnnotation::Complex => "Complex".to_string(),
             TypeAnnotation::Vector => "Vector".to_string(),
             TypeAnnotation::Edge => "Edge".to_string(),
-            TypeAnnotation::Generator => "Generator".to_string(),
-            TypeAnnotation::Error => "Error".to_string(),
+            TypeAnnotation::Error(_) => "Error".to_string(),
             TypeAnnotation::AnyFunction => "Function".to_string(),
             TypeAnnotation::Null => "null".to_string(),
             TypeAnnotation::Any => "Any".to_string(),
+            TypeAnnotation::Never => "Never".to_string(),
+            TypeAnnotation::TypeVar(id) => format!("'t{}", id),
+
+            TypeAnnotation::Generator { element_type } => {
+                match element_type.as_ref() {
+                    TypeAnnotation::Any => "Generator".to_string(),
+                    elem => format!("Generator<{}>", elem.to_string()),
+                }
+            }
 
             TypeAnnotation::Tensor { element_type, shape } => {
                 let elem_str = element_type.to_string();
@@ -115,7 +176,7 @@ impl TypeAnnotation {
                     None => format!("Tensor<{}>", elem_str),
                     Some(dims) => {
                         let dims_str = dims.iter()
-                            .map(|d| d.map_or("_".to_string(), |n| n.to_string()))
+                            .map(|d| d.to_string())
                             .collect::<Vec<_>>()
                             .join(", ");
                         format!("Tensor<{}, [{}]>", elem_str, dims_str)
@@ -172,6 +233,16 @@ impl TypeAnnotation {
             // Any accepts anything
             (TypeAnnotation::Any, _) | (_, TypeAnnotation::Any) => true,
 
+            // Never is the bottom type: assignable to anything
+            (_, TypeAnnotation::Never) => true,
+
+            // Error poisons a check silently rather than cascading a
+            // downstream type's own mismatch into an unrelated one.
+            (TypeAnnotation::Error(_), _) | (_, TypeAnnotation::Error(_)) => true,
+
+            // Integer is a subtype of Number (every whole number is a number).
+            (TypeAnnotation::Number, TypeAnnotation::Integer) => true,
+
             // Union type matching
             (TypeAnnotation::Union(types), other) => {
                 types.iter().any(|t| t.is_assignable_from(other))
@@ -180,6 +251,13 @@ impl TypeAnnotation {
                 other_types.iter().all(|ot| self_type.is_assignable_from(ot))
             }
 
+            // Generator element types are covariant: Generator<Number> is
+            // assignable from Generator<Number>, and an untyped Generator
+            // (element Any) accepts/offers any element type gradually.
+            (TypeAnnotation::Generator { element_type: self_elem }, TypeAnnotation::Generator { element_type: other_elem }) => {
+                self_elem.is_assignable_from(other_elem)
+            }
+
             // Record structural subtyping (simplified)
             (TypeAnnotation::Record { fields: self_fields }, TypeAnnotation::Record { fields: other_fields }) => {
                 self_fields.iter().all(|(field_name, (self_mut, self_type))| {
@@ -1,4 +1,4 @@
-use achronyme_parser::{parse, ast::*, type_annotation::TypeAnnotation};
+use achronyme_parser::{parse, ast::*, type_annotation::{ShapeDim, TypeAnnotation}};
 
 // ============================================================================
 // Variable Declaration Tests
@@ -242,7 +242,7 @@ fn test_let_with_tensor_type() {
             match type_annotation {
                 Some(TypeAnnotation::Tensor { element_type, shape }) => {
                     assert_eq!(**element_type, TypeAnnotation::Number);
-                    assert_eq!(shape, &Some(vec![Some(2), Some(3)]));
+                    assert_eq!(shape, &Some(vec![ShapeDim::Fixed(2), ShapeDim::Fixed(3)]));
                 }
                 _ => panic!("Expected Tensor type annotation"),
             }
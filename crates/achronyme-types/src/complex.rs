@@ -1,5 +1,6 @@
 use std::ops::{Add, Sub, Mul, Div, Neg};
 use std::fmt;
+use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Complex {
@@ -22,9 +23,17 @@ impl Complex {
         Self { re: 0.0, im }
     }
 
-    /// Calculate the magnitude (absolute value)
+    /// Calculate the magnitude (absolute value), scaled to avoid
+    /// overflow/underflow for components near `f64::MAX`/`f64::MIN_POSITIVE`.
     pub fn magnitude(&self) -> f64 {
-        (self.re * self.re + self.im * self.im).sqrt()
+        self.re.hypot(self.im)
+    }
+
+    /// Squared norm `re*re + im*im`, unscaled. Cheaper than `magnitude()`
+    /// and fine for callers that only compare magnitudes, but can
+    /// overflow/underflow where `magnitude()` would not.
+    pub fn norm_sqr(&self) -> f64 {
+        self.re * self.re + self.im * self.im
     }
 
     /// Calculate the phase (argument) in radians
@@ -65,6 +74,28 @@ impl Complex {
         self.pow(0.5)
     }
 
+    /// All `n`-th roots of a complex number via De Moivre's formula,
+    /// ordered by increasing `k` (principal root first). Zero returns `n`
+    /// copies of zero; `n == 0` is an error since there are no roots.
+    pub fn roots(&self, n: u32) -> Result<Vec<Complex>, String> {
+        if n == 0 {
+            return Err("roots(): n must be greater than 0".to_string());
+        }
+        if self.re == 0.0 && self.im == 0.0 {
+            return Ok(vec![Complex::new(0.0, 0.0); n as usize]);
+        }
+
+        let r = self.magnitude().powf(1.0 / n as f64);
+        let base = self.phase() / n as f64;
+        let step = 2.0 * std::f64::consts::PI / n as f64;
+        Ok((0..n)
+            .map(|k| {
+                let theta = base + step * k as f64;
+                Complex::new(r * theta.cos(), r * theta.sin())
+            })
+            .collect())
+    }
+
     /// Exponential function
     pub fn exp(&self) -> Self {
         let exp_re = self.re.exp();
@@ -102,6 +133,82 @@ impl Complex {
     pub fn tan(&self) -> Self {
         self.sin() / self.cos()
     }
+
+    /// Hyperbolic sine
+    pub fn sinh(&self) -> Self {
+        Self {
+            re: self.re.sinh() * self.im.cos(),
+            im: self.re.cosh() * self.im.sin(),
+        }
+    }
+
+    /// Hyperbolic cosine
+    pub fn cosh(&self) -> Self {
+        Self {
+            re: self.re.cosh() * self.im.cos(),
+            im: self.re.sinh() * self.im.sin(),
+        }
+    }
+
+    /// Hyperbolic tangent
+    pub fn tanh(&self) -> Self {
+        self.sinh() / self.cosh()
+    }
+
+    /// Inverse sine. `asin(z) = -i * ln(iz + sqrt(1 - z^2))`
+    pub fn asin(&self) -> Self {
+        let i = Complex::new(0.0, 1.0);
+        let inner = (Complex::from_real(1.0) - *self * *self).sqrt();
+        -i * (i * *self + inner).ln()
+    }
+
+    /// Inverse cosine. `acos(z) = -i * ln(z + i*sqrt(1 - z^2))`
+    pub fn acos(&self) -> Self {
+        let i = Complex::new(0.0, 1.0);
+        let inner = (Complex::from_real(1.0) - *self * *self).sqrt();
+        -i * (*self + i * inner).ln()
+    }
+
+    /// Inverse tangent. `atan(z) = (i/2) * ln((i+z)/(i-z))`
+    pub fn atan(&self) -> Self {
+        let i = Complex::new(0.0, 1.0);
+        let half_i = Complex::new(0.0, 0.5);
+        half_i * ((i + *self) / (i - *self)).ln()
+    }
+
+    /// Inverse hyperbolic sine. `asinh(z) = ln(z + sqrt(z^2+1))`
+    pub fn asinh(&self) -> Self {
+        let inner = (*self * *self + Complex::from_real(1.0)).sqrt();
+        (*self + inner).ln()
+    }
+
+    /// Inverse hyperbolic cosine. `acosh(z) = ln(z + sqrt(z^2-1))`
+    pub fn acosh(&self) -> Self {
+        let inner = (*self * *self - Complex::from_real(1.0)).sqrt();
+        (*self + inner).ln()
+    }
+
+    /// Inverse hyperbolic tangent. `atanh(z) = 0.5 * ln((1+z)/(1-z))`
+    pub fn atanh(&self) -> Self {
+        let one = Complex::from_real(1.0);
+        let half = Complex::from_real(0.5);
+        half * ((one + *self) / (one - *self)).ln()
+    }
+
+    /// True if either component is NaN.
+    pub fn is_nan(&self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+
+    /// True if either component is infinite and neither is NaN.
+    pub fn is_infinite(&self) -> bool {
+        !self.is_nan() && (self.re.is_infinite() || self.im.is_infinite())
+    }
+
+    /// True if both components are finite.
+    pub fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
 }
 
 // Addition
@@ -140,15 +247,26 @@ impl Mul for Complex {
     }
 }
 
-// Division
+// Division, using Smith's scaled algorithm so large/small components
+// don't overflow/underflow the way dividing by `re*re + im*im` would.
 impl Div for Complex {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
-        let denominator = rhs.re * rhs.re + rhs.im * rhs.im;
-        Self {
-            re: (self.re * rhs.re + self.im * rhs.im) / denominator,
-            im: (self.im * rhs.re - self.re * rhs.im) / denominator,
+        if rhs.re.abs() >= rhs.im.abs() {
+            let r = rhs.im / rhs.re;
+            let d = rhs.re + rhs.im * r;
+            Self {
+                re: (self.re + self.im * r) / d,
+                im: (self.im - self.re * r) / d,
+            }
+        } else {
+            let r = rhs.re / rhs.im;
+            let d = rhs.re * r + rhs.im;
+            Self {
+                re: (self.re * r + self.im) / d,
+                im: (self.im * r - self.re) / d,
+            }
         }
     }
 }
@@ -168,14 +286,97 @@ impl Neg for Complex {
 // Display formatting
 impl fmt::Display for Complex {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.im >= 0.0 {
-            write!(f, "{}+{}i", self.re, self.im)
+        // Pure real: collapse away a zero imaginary part, e.g. "3" not "3+0i".
+        if self.im == 0.0 {
+            return write!(f, "{}", self.re);
+        }
+
+        let im_negative = self.im.is_sign_negative();
+        let im_abs = self.im.abs();
+        let sign = if im_negative { "-" } else { "" };
+
+        // Pure imaginary: "3i", "-i" (a unit coefficient is omitted).
+        if self.re == 0.0 {
+            return if im_abs == 1.0 {
+                write!(f, "{}i", sign)
+            } else {
+                write!(f, "{}{}i", sign, im_abs)
+            };
+        }
+
+        let sign = if im_negative { "-" } else { "+" };
+        if im_abs == 1.0 {
+            write!(f, "{}{}i", self.re, sign)
+        } else {
+            write!(f, "{}{}{}i", self.re, sign, im_abs)
+        }
+    }
+}
+
+// Parsing, e.g. "2+3i", "-1.5-4i", "5", "3i", "-i", "1e3+2e-1i" - the
+// inverse of `Display` above, so complex values round-trip through string
+// concatenation.
+impl FromStr for Complex {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err("cannot parse complex number from an empty string".to_string());
+        }
+
+        let without_i = if s.ends_with('i') || s.ends_with('I') {
+            &s[..s.len() - 1]
         } else {
-            write!(f, "{}{}i", self.re, self.im)
+            // No imaginary unit at all: pure real, e.g. "5" or "-1.5".
+            return s
+                .parse::<f64>()
+                .map(Complex::from_real)
+                .map_err(|_| format!("invalid complex number literal '{}'", s));
+        };
+
+        match split_imaginary_sign(without_i) {
+            Some(split) => {
+                let (real_part, imag_part) = without_i.split_at(split);
+                let re = real_part
+                    .parse::<f64>()
+                    .map_err(|_| format!("invalid real part '{}' in complex literal '{}'", real_part, s))?;
+                let im = parse_signed_imag(imag_part)
+                    .map_err(|_| format!("invalid imaginary part '{}i' in complex literal '{}'", imag_part, s))?;
+                Ok(Complex::new(re, im))
+            }
+            // No real part: pure imaginary, e.g. "3i", "-i", "+i".
+            None => {
+                let im = parse_signed_imag(without_i)
+                    .map_err(|_| format!("invalid complex number literal '{}'", s))?;
+                Ok(Complex::new(0.0, im))
+            }
         }
     }
 }
 
+/// Parse the coefficient left of a trailing `i` once any real part has
+/// been split off: `""`/`"+"` mean `1.0` and `"-"` means `-1.0` (so `"i"`
+/// and `"-i"` parse as `±1i`), anything else parses as `f64`.
+fn parse_signed_imag(s: &str) -> Result<f64, ()> {
+    match s {
+        "" | "+" => Ok(1.0),
+        "-" => Ok(-1.0),
+        _ => s.parse::<f64>().map_err(|_| ()),
+    }
+}
+
+/// Find the index splitting `s` (the complex literal with its trailing `i`
+/// already removed) into a real part and a signed imaginary part - the
+/// last `+`/`-` that isn't the literal's own leading sign and isn't part
+/// of a scientific-notation exponent like the `-` in `1e-3`.
+fn split_imaginary_sign(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    (1..bytes.len())
+        .rev()
+        .find(|&i| matches!(bytes[i], b'+' | b'-') && !matches!(bytes[i - 1], b'e' | b'E'))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -208,4 +409,169 @@ mod tests {
         let b = a.conjugate();
         assert_eq!(b, Complex::new(3.0, -4.0));
     }
+
+    #[test]
+    fn test_from_str_parses_real_and_imaginary_parts() {
+        assert_eq!("2+3i".parse::<Complex>(), Ok(Complex::new(2.0, 3.0)));
+        assert_eq!("-1.5-4i".parse::<Complex>(), Ok(Complex::new(-1.5, -4.0)));
+        assert_eq!("5".parse::<Complex>(), Ok(Complex::new(5.0, 0.0)));
+        assert_eq!("3i".parse::<Complex>(), Ok(Complex::new(0.0, 3.0)));
+        assert_eq!("-i".parse::<Complex>(), Ok(Complex::new(0.0, -1.0)));
+        assert_eq!("+i".parse::<Complex>(), Ok(Complex::new(0.0, 1.0)));
+        assert_eq!("1e3+2e-1i".parse::<Complex>(), Ok(Complex::new(1000.0, 0.2)));
+    }
+
+    #[test]
+    fn test_from_str_rejects_malformed_input() {
+        assert!("".parse::<Complex>().is_err());
+        assert!("2+bi".parse::<Complex>().is_err());
+        assert!("not a number".parse::<Complex>().is_err());
+    }
+
+    #[test]
+    fn test_magnitude_avoids_overflow_for_large_components() {
+        let big = f64::MAX.sqrt();
+        let a = Complex::new(big, big);
+        assert!(a.magnitude().is_finite());
+        assert!((a.magnitude() - big * std::f64::consts::SQRT_2).abs() / a.magnitude() < 1e-9);
+    }
+
+    #[test]
+    fn test_division_avoids_overflow_for_large_components() {
+        let big = f64::MAX.sqrt();
+        let a = Complex::new(big, big);
+        let b = Complex::new(big, 0.0);
+        let c = a / b;
+        assert!(c.re.is_finite() && c.im.is_finite());
+        assert_eq!(c, Complex::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn test_norm_sqr() {
+        let a = Complex::new(3.0, 4.0);
+        assert_eq!(a.norm_sqr(), 25.0);
+    }
+
+    fn assert_close(a: Complex, b: Complex) {
+        assert!((a - b).magnitude() < 1e-9, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    fn test_hyperbolic_functions_agree_with_f64_on_real_axis() {
+        let x = Complex::from_real(0.7);
+        assert_close(x.sinh(), Complex::from_real(0.7_f64.sinh()));
+        assert_close(x.cosh(), Complex::from_real(0.7_f64.cosh()));
+        assert_close(x.tanh(), Complex::from_real(0.7_f64.tanh()));
+    }
+
+    #[test]
+    fn test_inverse_trig_functions_agree_with_f64_on_real_axis() {
+        let x = Complex::from_real(0.4);
+        assert_close(x.asin(), Complex::from_real(0.4_f64.asin()));
+        assert_close(x.acos(), Complex::from_real(0.4_f64.acos()));
+        assert_close(x.atan(), Complex::from_real(0.4_f64.atan()));
+    }
+
+    #[test]
+    fn test_inverse_hyperbolic_functions_agree_with_f64_on_real_axis() {
+        let x = Complex::from_real(0.4);
+        assert_close(x.asinh(), Complex::from_real(0.4_f64.asinh()));
+        assert_close(x.atanh(), Complex::from_real(0.4_f64.atanh()));
+
+        // acosh is only real-valued for |x| >= 1.
+        assert_close(Complex::from_real(1.5).acosh(), Complex::from_real(1.5_f64.acosh()));
+    }
+
+    #[test]
+    fn test_inverse_trig_round_trip() {
+        let z = Complex::new(0.5, 0.3);
+        assert_close(z.sin().asin(), z);
+        assert_close(z.cos().acos(), z);
+        assert_close(z.tan().atan(), z);
+    }
+
+    #[test]
+    fn test_inverse_hyperbolic_round_trip() {
+        let z = Complex::new(0.5, 0.3);
+        assert_close(z.sinh().asinh(), z);
+        assert_close(z.tanh().atanh(), z);
+    }
+
+    #[test]
+    fn test_roots_cube_roots_of_negative_eight() {
+        let roots = Complex::new(-8.0, 0.0).roots(3).unwrap();
+        assert_eq!(roots.len(), 3);
+        assert_close(roots[0], Complex::new(1.0, 3.0_f64.sqrt()));
+        assert_close(roots[1], Complex::new(-2.0, 0.0));
+        assert_close(roots[2], Complex::new(1.0, -3.0_f64.sqrt()));
+
+        for root in roots {
+            assert_close(root.pow(3.0), Complex::new(-8.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn test_roots_of_zero_returns_n_zeros() {
+        let roots = Complex::new(0.0, 0.0).roots(4).unwrap();
+        assert_eq!(roots, vec![Complex::new(0.0, 0.0); 4]);
+    }
+
+    #[test]
+    fn test_roots_rejects_n_zero() {
+        assert!(Complex::new(1.0, 0.0).roots(0).is_err());
+    }
+
+    #[test]
+    fn test_is_nan_is_infinite_is_finite() {
+        let nan = Complex::new(f64::NAN, 0.0);
+        let inf = Complex::new(f64::INFINITY, 0.0);
+        let finite = Complex::new(1.0, 2.0);
+
+        assert!(nan.is_nan());
+        assert!(!nan.is_infinite());
+        assert!(!nan.is_finite());
+
+        assert!(!inf.is_nan());
+        assert!(inf.is_infinite());
+        assert!(!inf.is_finite());
+
+        assert!(!finite.is_nan());
+        assert!(!finite.is_infinite());
+        assert!(finite.is_finite());
+    }
+
+    #[test]
+    fn test_display_collapses_zero_imaginary_part() {
+        assert_eq!(Complex::new(3.0, 0.0).to_string(), "3");
+        assert_eq!(Complex::new(-2.5, 0.0).to_string(), "-2.5");
+    }
+
+    #[test]
+    fn test_display_pure_imaginary() {
+        assert_eq!(Complex::new(0.0, 3.0).to_string(), "3i");
+        assert_eq!(Complex::new(0.0, 1.0).to_string(), "i");
+        assert_eq!(Complex::new(0.0, -1.0).to_string(), "-i");
+        assert_eq!(Complex::new(0.0, -3.0).to_string(), "-3i");
+    }
+
+    #[test]
+    fn test_display_general_case() {
+        assert_eq!(Complex::new(2.0, 3.0).to_string(), "2+3i");
+        assert_eq!(Complex::new(2.0, -3.0).to_string(), "2-3i");
+        assert_eq!(Complex::new(2.0, 1.0).to_string(), "2+i");
+        assert_eq!(Complex::new(2.0, -1.0).to_string(), "2-i");
+    }
+
+    #[test]
+    fn test_from_str_round_trips_through_display() {
+        for z in [
+            Complex::new(2.0, 3.0),
+            Complex::new(-1.5, -4.0),
+            Complex::new(5.0, 0.0),
+            Complex::new(0.0, -7.0),
+        ] {
+            let parsed: Complex = z.to_string().parse().unwrap();
+            assert_eq!(parsed, z);
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,144 @@
+//! Dual numbers for exact automatic differentiation.
+//!
+//! A dual number `a + bε` (with `ε² = 0`) carries a function's value
+//! alongside its derivative through ordinary arithmetic: addition adds both
+//! components, multiplication gives `(ac, ad + bc)`, division gives
+//! `(a/c, (bc - ad)/c²)`, and elementary functions propagate the chain rule
+//! (`sin(a + bε) = sin(a) + b·cos(a)·ε`, etc). Evaluating a function once
+//! over duals therefore yields both `f(x)` and the exact `f'(x)`, with no
+//! step size and none of the truncation/round-off tradeoffs finite
+//! differences have.
+//!
+//! Caveats: a function with `abs`/branches is only piecewise differentiable
+//! through a dual (the derivative is exact on each branch, but not at the
+//! kink), and this type only carries a first-order derivative — a second
+//! derivative needs a nested or truncated second-order dual, which this
+//! module does not provide.
+
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// `real + dual * ε`, with `ε² = 0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dual {
+    pub real: f64,
+    pub dual: f64,
+}
+
+impl Dual {
+    pub fn new(real: f64, dual: f64) -> Self {
+        Dual { real, dual }
+    }
+
+    /// A constant: zero derivative.
+    pub fn constant(real: f64) -> Self {
+        Dual::new(real, 0.0)
+    }
+
+    /// The differentiation variable: value `x`, derivative seed `1`.
+    pub fn variable(x: f64) -> Self {
+        Dual::new(x, 1.0)
+    }
+
+    pub fn sin(self) -> Self {
+        Dual::new(self.real.sin(), self.dual * self.real.cos())
+    }
+
+    pub fn cos(self) -> Self {
+        Dual::new(self.real.cos(), -self.dual * self.real.sin())
+    }
+
+    pub fn tan(self) -> Self {
+        let c = self.real.cos();
+        Dual::new(self.real.tan(), self.dual / (c * c))
+    }
+
+    pub fn exp(self) -> Self {
+        let e = self.real.exp();
+        Dual::new(e, self.dual * e)
+    }
+
+    pub fn ln(self) -> Self {
+        Dual::new(self.real.ln(), self.dual / self.real)
+    }
+
+    pub fn sqrt(self) -> Self {
+        let s = self.real.sqrt();
+        Dual::new(s, self.dual / (2.0 * s))
+    }
+
+    /// `self.powf(p)` for a constant exponent `p` (the exponent itself is
+    /// not differentiated — only `self` carries a derivative here).
+    pub fn powf(self, p: f64) -> Self {
+        Dual::new(self.real.powf(p), self.dual * p * self.real.powf(p - 1.0))
+    }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+    fn add(self, rhs: Dual) -> Dual {
+        Dual::new(self.real + rhs.real, self.dual + rhs.dual)
+    }
+}
+
+impl Sub for Dual {
+    type Output = Dual;
+    fn sub(self, rhs: Dual) -> Dual {
+        Dual::new(self.real - rhs.real, self.dual - rhs.dual)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+    fn mul(self, rhs: Dual) -> Dual {
+        Dual::new(self.real * rhs.real, self.dual * rhs.real + self.real * rhs.dual)
+    }
+}
+
+impl Div for Dual {
+    type Output = Dual;
+    fn div(self, rhs: Dual) -> Dual {
+        Dual::new(
+            self.real / rhs.real,
+            (self.dual * rhs.real - self.real * rhs.dual) / (rhs.real * rhs.real),
+        )
+    }
+}
+
+impl Neg for Dual {
+    type Output = Dual;
+    fn neg(self) -> Dual {
+        Dual::new(-self.real, -self.dual)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_derivative_of_square() {
+        // f(x) = x * x, f'(3) = 6
+        let x = Dual::variable(3.0);
+        let f = x * x;
+        assert_eq!(f.real, 9.0);
+        assert_eq!(f.dual, 6.0);
+    }
+
+    #[test]
+    fn test_derivative_of_sin() {
+        // f(x) = sin(x), f'(0) = cos(0) = 1
+        let x = Dual::variable(0.0);
+        let f = x.sin();
+        assert!((f.real - 0.0).abs() < 1e-12);
+        assert!((f.dual - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_derivative_of_quotient() {
+        // f(x) = 1/x, f'(2) = -1/4
+        let x = Dual::variable(2.0);
+        let f = Dual::constant(1.0) / x;
+        assert!((f.real - 0.5).abs() < 1e-12);
+        assert!((f.dual - (-0.25)).abs() < 1e-12);
+    }
+}
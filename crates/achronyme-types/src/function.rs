@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use std::rc::Rc;
 use std::cell::RefCell;
 use crate::environment::Environment;
+use crate::value::Value;
 
 /// Function representation - can be either a user-defined lambda or a built-in function
 ///
@@ -42,6 +43,18 @@ pub enum Function {
     },
     /// Built-in function by name
     Builtin(String),
+    /// A partially-applied callable: some leading positional args are
+    /// already bound, waiting on the rest before the real call happens.
+    ///
+    /// `total_arity` is captured at the point of partial application rather
+    /// than re-derived from `func` later, since `Function::Builtin`'s own
+    /// arity isn't known from the value alone (it's looked up in a registry
+    /// when the call actually happens).
+    Partial {
+        func: Box<Function>,
+        applied_args: Vec<Value>,
+        total_arity: usize,
+    },
 }
 
 impl Function {
@@ -108,12 +121,28 @@ impl Function {
         Function::Builtin(name)
     }
 
+    /// Bind `applied_args` as the leading arguments of `func`, whose full
+    /// arity is `total_arity`. Used by partial application: calling a
+    /// callable with fewer args than it takes produces one of these instead
+    /// of an arity error.
+    pub fn partial(func: Function, applied_args: Vec<Value>, total_arity: usize) -> Self {
+        Function::Partial {
+            func: Box::new(func),
+            applied_args,
+            total_arity,
+        }
+    }
+
     /// Get arity (number of parameters)
     /// Returns None for built-in functions (arity depends on the specific function)
     pub fn arity(&self) -> usize {
         match self {
             Function::UserDefined { params, .. } => params.len(),
             Function::Builtin(_) => 0, // Built-in functions handle their own arity checking
+            // Remaining args needed before the underlying callable is fully applied.
+            Function::Partial { applied_args, total_arity, .. } => {
+                total_arity.saturating_sub(applied_args.len())
+            }
         }
     }
 
@@ -139,6 +168,10 @@ impl PartialEq for Function {
                 p1 == p2 && pt1 == pt2 && rt1 == rt2 && Rc::ptr_eq(b1, b2) && Rc::ptr_eq(e1, e2)
             }
             (Function::Builtin(n1), Function::Builtin(n2)) => n1 == n2,
+            (Function::Partial { func: f1, applied_args: a1, total_arity: t1 },
+             Function::Partial { func: f2, applied_args: a2, total_arity: t2 }) => {
+                t1 == t2 && a1 == a2 && f1 == f2
+            }
             _ => false,
         }
     }
@@ -3,6 +3,7 @@
 //! Defines the interface for evaluating lambda functions at specific points.
 //! This trait is implemented by the evaluator and used by numerical calculus functions.
 
+use crate::dual::Dual;
 use crate::function::Function;
 
 /// Trait for evaluating lambda functions
@@ -61,4 +62,38 @@ pub trait LambdaEvaluator {
     /// // result = 25.0
     /// ```
     fn eval_at_nd(&mut self, func: &Function, args: &[f64]) -> Result<f64, String>;
+
+    /// Evaluate a lambda function at a single `Dual` point, for exact
+    /// automatic differentiation.
+    ///
+    /// The function is called with `Value::Dual(x)` as its only argument; its
+    /// body must reach the result purely through dual-aware arithmetic and
+    /// elementary functions (see `achronyme_types::dual::Dual`) for the
+    /// returned derivative to be exact. Functions that branch on `abs` or
+    /// comparisons are only piecewise differentiable this way.
+    ///
+    /// # Arguments
+    /// * `func` - The lambda function to evaluate
+    /// * `x` - The dual-number point at which to evaluate
+    ///
+    /// # Returns
+    /// `f(x)` as a `Dual` whose `.dual` field is the exact derivative `f'(x.real)`.
+    fn eval_dual_at(&mut self, func: &Function, x: Dual) -> Result<Dual, String>;
+
+    /// Evaluate a lambda function `f(t, y)` for ODE integration.
+    ///
+    /// `y` is passed as `Value::Number` when it holds a single component, or
+    /// `Value::Vector` for a system of equations — mirroring how `func` was
+    /// called from user code. The result is returned the same way it came
+    /// back: a scalar result becomes a one-element `Vec`, a vector result is
+    /// returned component-wise.
+    ///
+    /// # Arguments
+    /// * `func` - The lambda function to evaluate
+    /// * `t` - The independent variable
+    /// * `y` - The current state vector (length 1 for a scalar ODE)
+    ///
+    /// # Returns
+    /// `f(t, y)` as a `Vec<f64>` of the same length as `y`.
+    fn eval_ty_at(&mut self, func: &Function, t: f64, y: &[f64]) -> Result<Vec<f64>, String>;
 }
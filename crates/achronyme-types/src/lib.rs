@@ -1,9 +1,11 @@
 pub mod value;
 pub mod complex;
+pub mod dual;
 pub mod tensor;
 pub mod function;
 pub mod lambda_evaluator;
 pub mod environment;
+pub mod parallel;
 
 // Re-exports
 pub use lambda_evaluator::LambdaEvaluator;
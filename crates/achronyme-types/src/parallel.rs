@@ -0,0 +1,109 @@
+//! Data-parallel element-wise evaluation for large tensors.
+//!
+//! `map_elements`/`zip_elements` are the parallel-capable counterparts of a
+//! plain `.iter().map(...)`/`.iter().zip(...).map(...)` loop: below
+//! [`parallel_threshold`] elements they run serially (thread-pool dispatch
+//! costs more than the work saved), and at or above it - when built with the
+//! `parallel` feature - they fan the closure out across rayon's thread pool.
+//! On native targets that pool is the regular OS-thread pool; on wasm32 it
+//! requires `wasm-bindgen-rayon`'s `init_thread_pool` to have spun up the
+//! Web Worker pool rayon schedules onto first, otherwise (or on any target
+//! without the `parallel` feature enabled) the call is just the serial loop.
+//!
+//! The closures callers pass in (`Fn(f64) -> f64`, `Fn(T, T) -> T`) are pure,
+//! so splitting the input across threads never changes the result - only how
+//! long it takes to compute.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Below this many elements, a parallel split costs more than it saves.
+/// Tunable at runtime via [`set_parallel_threshold`].
+static PARALLEL_THRESHOLD: AtomicUsize = AtomicUsize::new(10_000);
+
+/// Set the element-count threshold at or above which `map_elements`/`zip_elements`
+/// switch from the serial loop to a parallel one.
+pub fn set_parallel_threshold(n: usize) {
+    PARALLEL_THRESHOLD.store(n, Ordering::Relaxed);
+}
+
+/// The current parallel-evaluation threshold, in elements.
+pub fn parallel_threshold() -> usize {
+    PARALLEL_THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Map `f` over `data`, in parallel once `data.len()` reaches
+/// [`parallel_threshold`] and the `parallel` feature is enabled.
+#[cfg(feature = "parallel")]
+pub fn map_elements<T, F>(data: &[T], f: F) -> Vec<T>
+where
+    T: Copy + Send + Sync,
+    F: Fn(T) -> T + Sync + Send,
+{
+    if data.len() >= parallel_threshold() {
+        use rayon::prelude::*;
+        return data.par_iter().copied().map(f).collect();
+    }
+    data.iter().copied().map(f).collect()
+}
+
+#[cfg(not(feature = "parallel"))]
+pub fn map_elements<T, F>(data: &[T], f: F) -> Vec<T>
+where
+    T: Copy,
+    F: Fn(T) -> T,
+{
+    data.iter().copied().map(f).collect()
+}
+
+/// Zip two equal-length slices through `f`, in parallel once `a.len()`
+/// reaches [`parallel_threshold`] and the `parallel` feature is enabled.
+/// Callers are responsible for having already checked `a.len() == b.len()`.
+#[cfg(feature = "parallel")]
+pub fn zip_elements<T, F>(a: &[T], b: &[T], f: F) -> Vec<T>
+where
+    T: Copy + Send + Sync,
+    F: Fn(T, T) -> T + Sync + Send,
+{
+    if a.len() >= parallel_threshold() {
+        use rayon::prelude::*;
+        return a.par_iter().zip(b.par_iter()).map(|(&x, &y)| f(x, y)).collect();
+    }
+    a.iter().zip(b.iter()).map(|(&x, &y)| f(x, y)).collect()
+}
+
+#[cfg(not(feature = "parallel"))]
+pub fn zip_elements<T, F>(a: &[T], b: &[T], f: F) -> Vec<T>
+where
+    T: Copy,
+    F: Fn(T, T) -> T,
+{
+    a.iter().zip(b.iter()).map(|(&x, &y)| f(x, y)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_elements_matches_serial_loop() {
+        let data: Vec<f64> = (0..64).map(|i| i as f64).collect();
+        let expected: Vec<f64> = data.iter().map(|x| x * 2.0).collect();
+        assert_eq!(map_elements(&data, |x| x * 2.0), expected);
+    }
+
+    #[test]
+    fn test_zip_elements_matches_serial_loop() {
+        let a: Vec<f64> = (0..64).map(|i| i as f64).collect();
+        let b: Vec<f64> = (0..64).map(|i| (i * 2) as f64).collect();
+        let expected: Vec<f64> = a.iter().zip(b.iter()).map(|(&x, &y)| x + y).collect();
+        assert_eq!(zip_elements(&a, &b, |x, y| x + y), expected);
+    }
+
+    #[test]
+    fn test_threshold_is_tunable() {
+        let original = parallel_threshold();
+        set_parallel_threshold(1);
+        assert_eq!(parallel_threshold(), 1);
+        set_parallel_threshold(original);
+    }
+}
@@ -879,6 +879,32 @@ impl RealTensor {
         let data: Vec<f64> = self.data.iter().map(|x| -x).collect();
         RealTensor::new(data, self.shape.clone()).unwrap()
     }
+
+    /// Apply an arbitrary element-wise binary function with NumPy-style
+    /// broadcasting, for callers that need more than the fixed add/sub/mul/div.
+    pub fn zip_with<F: Fn(f64, f64) -> f64 + Sync + Send>(&self, other: &RealTensor, f: F) -> Result<RealTensor, TensorError> {
+        if self.shape == other.shape {
+            let data = crate::parallel::zip_elements(&self.data, &other.data, f);
+            return RealTensor::new(data, self.shape.clone());
+        }
+
+        let result_shape = Tensor::<f64>::broadcast_shape(&self.shape, &other.shape)?;
+        let result_strides = Tensor::<f64>::compute_strides(&result_shape);
+        let result_size: usize = result_shape.iter().product();
+
+        let mut data = Vec::with_capacity(result_size);
+        for flat_idx in 0..result_size {
+            let result_indices = Tensor::<f64>::unravel_index(flat_idx, &result_shape, &result_strides);
+            let self_indices = Tensor::<f64>::broadcast_index(&result_indices, &self.shape, &result_shape);
+            let other_indices = Tensor::<f64>::broadcast_index(&result_indices, &other.shape, &result_shape);
+
+            let a = self.get(&self_indices)?;
+            let b = other.get(&other_indices)?;
+            data.push(f(*a, *b));
+        }
+
+        RealTensor::new(data, result_shape)
+    }
 }
 
 // ============================================================================
@@ -1043,6 +1069,32 @@ impl ComplexTensor {
         ComplexTensor::new(data, self.shape.clone()).unwrap()
     }
 
+    /// Apply an arbitrary element-wise binary function with NumPy-style
+    /// broadcasting, for callers that need more than the fixed add/sub/mul/div.
+    pub fn zip_with<F: Fn(Complex, Complex) -> Complex + Sync + Send>(&self, other: &ComplexTensor, f: F) -> Result<ComplexTensor, TensorError> {
+        if self.shape == other.shape {
+            let data = crate::parallel::zip_elements(&self.data, &other.data, f);
+            return ComplexTensor::new(data, self.shape.clone());
+        }
+
+        let result_shape = Tensor::<Complex>::broadcast_shape(&self.shape, &other.shape)?;
+        let result_strides = Tensor::<Complex>::compute_strides(&result_shape);
+        let result_size: usize = result_shape.iter().product();
+
+        let mut data = Vec::with_capacity(result_size);
+        for flat_idx in 0..result_size {
+            let result_indices = Tensor::<Complex>::unravel_index(flat_idx, &result_shape, &result_strides);
+            let self_indices = Tensor::<Complex>::broadcast_index(&result_indices, &self.shape, &result_shape);
+            let other_indices = Tensor::<Complex>::broadcast_index(&result_indices, &other.shape, &result_shape);
+
+            let a = self.get(&self_indices)?;
+            let b = other.get(&other_indices)?;
+            data.push(f(*a, *b));
+        }
+
+        ComplexTensor::new(data, result_shape)
+    }
+
     /// Convert to RealTensor (magnitude)
     pub fn abs(&self) -> RealTensor {
         let data: Vec<f64> = self.data.iter().map(|c| c.magnitude()).collect();
@@ -1904,6 +1956,24 @@ mod tests {
         assert_eq!(*result.get(&[0, 1]).unwrap(), Complex::new(22.0, 2.0));  // (2+2i) + 20
     }
 
+    #[test]
+    fn test_zip_with_broadcasts_arbitrary_op() {
+        // Matrix [2, 3] `max`-combined with Vector [3], broadcast to [2, 3]
+        let m = RealTensor::matrix(2, 3, vec![
+            1.0, 20.0, 3.0,
+            40.0, 5.0, 60.0,
+        ]).unwrap();
+        let v = RealTensor::vector(vec![10.0, 10.0, 10.0]);
+
+        let result = m.zip_with(&v, f64::max).unwrap();
+
+        assert_eq!(result.shape(), &[2, 3]);
+        assert_eq!(*result.get(&[0, 0]).unwrap(), 10.0);
+        assert_eq!(*result.get(&[0, 1]).unwrap(), 20.0);
+        assert_eq!(*result.get(&[1, 0]).unwrap(), 40.0);
+        assert_eq!(*result.get(&[1, 1]).unwrap(), 10.0);
+    }
+
     #[test]
     fn test_broadcast_incompatible_shapes() {
         // Test that incompatible shapes fail properly
@@ -1,8 +1,10 @@
 use crate::complex::Complex;
+use crate::dual::Dual;
 use crate::tensor::{RealTensor, ComplexTensor};
 use crate::function::Function;
 use crate::environment::Environment;
 use achronyme_parser::ast::AstNode;
+use achronyme_parser::type_annotation::TypeAnnotation;
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::cell::RefCell;
@@ -23,6 +25,11 @@ impl std::fmt::Display for TypeError {
 #[derive(Debug, Clone, PartialEq)]
 pub enum Value {
     Number(f64),
+    /// A whole number literal (no decimal point/exponent in source). Stays
+    /// distinct from `Number` through `+ - * ^` so arithmetic on integers
+    /// keeps reporting the narrower `Integer` type; `/` always promotes to
+    /// `Number` since division isn't closed over the integers.
+    Integer(i64),
     Boolean(bool),
     Complex(Complex),
     Vector(Vec<Value>),  // Generic vector - can hold any Value type (strings, records, etc.)
@@ -41,6 +48,10 @@ pub enum Value {
     /// Contains arguments for the next iteration of a tail-recursive function
     /// This variant should never be exposed to user code or returned from eval_str()
     TailCall(Vec<Value>),
+    /// Internal marker carrying a dual number (value + derivative) while a
+    /// `Function` is being evaluated for automatic differentiation.
+    /// This variant should never be exposed to user code or returned from eval_str()
+    Dual(Dual),
     /// Internal marker for early return from functions
     /// Contains the value to return from the current function
     /// This variant should never be exposed to user code or returned from eval_str()
@@ -93,6 +104,11 @@ pub struct GeneratorState {
 
     /// Current yield target (for resuming after nested yields)
     pub current_yield_target: usize,
+
+    /// Declared yield type, if the generator was created from a
+    /// `Generator<T>`-annotated context (None means untyped/gradual -
+    /// any yielded value is accepted).
+    pub declared_yield_type: Option<TypeAnnotation>,
 }
 
 // Conversiones automáticas con From/Into
@@ -104,15 +120,16 @@ impl From<f64> for Value {
 
 // Helper functions for vector operations
 impl Value {
-    /// Check if a vector is numeric (contains only Number or Complex values)
+    /// Check if a vector is numeric (contains only Number, Integer, or Complex values)
     pub fn is_numeric_vector(vec: &[Value]) -> bool {
-        vec.iter().all(|v| matches!(v, Value::Number(_) | Value::Complex(_)))
+        vec.iter().all(|v| matches!(v, Value::Number(_) | Value::Integer(_) | Value::Complex(_)))
     }
 
     /// Convert a generic vector to a RealTensor (rank 1)
     pub fn to_real_tensor(vec: &[Value]) -> Result<RealTensor, TypeError> {
         let nums: Result<Vec<f64>, _> = vec.iter().map(|v| match v {
             Value::Number(n) => Ok(*n),
+            Value::Integer(n) => Ok(*n as f64),
             _ => Err(TypeError::IncompatibleTypes),
         }).collect();
 
@@ -126,6 +143,7 @@ impl Value {
     pub fn to_complex_tensor(vec: &[Value]) -> Result<ComplexTensor, TypeError> {
         let complexes: Result<Vec<Complex>, _> = vec.iter().map(|v| match v {
             Value::Number(n) => Ok(Complex::new(*n, 0.0)),
+            Value::Integer(n) => Ok(Complex::new(*n as f64, 0.0)),
             Value::Complex(c) => Ok(*c),
             _ => Err(TypeError::IncompatibleTypes),
         }).collect();
@@ -216,6 +234,24 @@ impl GeneratorState {
             return_value: None,
             yield_count: 0,
             current_yield_target: 0,
+            declared_yield_type: None,
+        }
+    }
+
+    /// Create a new generator state with a declared yield type (e.g. from
+    /// an enclosing `fn range(n): Generator<Number>` annotation), so the
+    /// type checker can hold its yielded values to a known element type
+    /// instead of falling back to `Any`.
+    pub fn new_typed(env: Environment, statements: Vec<AstNode>, yield_type: TypeAnnotation) -> Self {
+        Self {
+            env,
+            position: 0,
+            statements,
+            done: false,
+            return_value: None,
+            yield_count: 0,
+            current_yield_target: 0,
+            declared_yield_type: Some(yield_type),
         }
     }
 
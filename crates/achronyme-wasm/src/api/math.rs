@@ -1,6 +1,6 @@
 use wasm_bindgen::prelude::*;
 use crate::state::{Handle, HANDLES};
-use crate::api::utils::{apply_unary_op, apply_binary_op};
+use crate::api::utils::{apply_unary_op, apply_binary_op, broadcast_binary_op};
 use achronyme_types::value::Value;
 
 // ============================================================================
@@ -152,6 +152,16 @@ fn get_f64_vector(value: &Value) -> Result<&Vec<Value>, JsValue> {
     }
 }
 
+/// Unwrap an already-validated all-`Value::Number` slice into plain `f64`s.
+fn to_f64_slice(v: &[Value]) -> Vec<f64> {
+    v.iter()
+        .map(|val| match val {
+            Value::Number(n) => *n,
+            _ => unreachable!("caller must validate via get_f64_vector first"),
+        })
+        .collect()
+}
+
 /// Vector addition: v1 + v2
 #[wasm_bindgen]
 pub fn vadd(handle1: Handle, handle2: Handle) -> Result<Handle, JsValue> {
@@ -165,19 +175,8 @@ pub fn vadd(handle1: Handle, handle2: Handle) -> Result<Handle, JsValue> {
             )));
         }
 
-        let result: Vec<Value> = vec1.iter()
-            .zip(vec2.iter())
-            .map(|(a, b)| {
-                if let (Value::Number(n1), Value::Number(n2)) = (a, b) {
-                    Value::Number(n1 + n2)
-                } else {
-                    // This case should ideally be caught by get_f64_vector
-                    unreachable!()
-                }
-            })
-            .collect();
-
-        Ok(Value::Vector(result))
+        let result = achronyme_types::parallel::zip_elements(&to_f64_slice(vec1), &to_f64_slice(vec2), |a, b| a + b);
+        Ok(Value::Vector(result.into_iter().map(Value::Number).collect()))
     })
 }
 
@@ -194,18 +193,8 @@ pub fn vsub(handle1: Handle, handle2: Handle) -> Result<Handle, JsValue> {
             )));
         }
 
-        let result: Vec<Value> = vec1.iter()
-            .zip(vec2.iter())
-            .map(|(a, b)| {
-                if let (Value::Number(n1), Value::Number(n2)) = (a, b) {
-                    Value::Number(n1 - n2)
-                } else {
-                    unreachable!()
-                }
-            })
-            .collect();
-
-        Ok(Value::Vector(result))
+        let result = achronyme_types::parallel::zip_elements(&to_f64_slice(vec1), &to_f64_slice(vec2), |a, b| a - b);
+        Ok(Value::Vector(result.into_iter().map(Value::Number).collect()))
     })
 }
 
@@ -222,18 +211,8 @@ pub fn vmul(handle1: Handle, handle2: Handle) -> Result<Handle, JsValue> {
             )));
         }
 
-        let result: Vec<Value> = vec1.iter()
-            .zip(vec2.iter())
-            .map(|(a, b)| {
-                if let (Value::Number(n1), Value::Number(n2)) = (a, b) {
-                    Value::Number(n1 * n2)
-                } else {
-                    unreachable!()
-                }
-            })
-            .collect();
-
-        Ok(Value::Vector(result))
+        let result = achronyme_types::parallel::zip_elements(&to_f64_slice(vec1), &to_f64_slice(vec2), |a, b| a * b);
+        Ok(Value::Vector(result.into_iter().map(Value::Number).collect()))
     })
 }
 
@@ -250,18 +229,8 @@ pub fn vdiv(handle1: Handle, handle2: Handle) -> Result<Handle, JsValue> {
             )));
         }
 
-        let result: Vec<Value> = vec1.iter()
-            .zip(vec2.iter())
-            .map(|(a, b)| {
-                if let (Value::Number(n1), Value::Number(n2)) = (a, b) {
-                    Value::Number(n1 / n2)
-                } else {
-                    unreachable!()
-                }
-            })
-            .collect();
-
-        Ok(Value::Vector(result))
+        let result = achronyme_types::parallel::zip_elements(&to_f64_slice(vec1), &to_f64_slice(vec2), |a, b| a / b);
+        Ok(Value::Vector(result.into_iter().map(Value::Number).collect()))
     })
 }
 
@@ -298,3 +267,32 @@ pub fn dot(handle1: Handle, handle2: Handle) -> Result<f64, JsValue> {
         Ok(result)
     })
 }
+
+// ============================================================================
+// Tensor Operations (NumPy-style broadcasting)
+// ============================================================================
+
+/// Element-wise tensor addition with NumPy-style broadcasting: operands may
+/// be scalars, vectors, or tensors of any compatible shape.
+#[wasm_bindgen(js_name = "tensorAdd")]
+pub fn tensor_add(handle1: Handle, handle2: Handle) -> Result<Handle, JsValue> {
+    broadcast_binary_op(handle1, handle2, |a, b| a + b)
+}
+
+/// Element-wise tensor subtraction with NumPy-style broadcasting.
+#[wasm_bindgen(js_name = "tensorSub")]
+pub fn tensor_sub(handle1: Handle, handle2: Handle) -> Result<Handle, JsValue> {
+    broadcast_binary_op(handle1, handle2, |a, b| a - b)
+}
+
+/// Element-wise tensor multiplication with NumPy-style broadcasting.
+#[wasm_bindgen(js_name = "tensorMul")]
+pub fn tensor_mul(handle1: Handle, handle2: Handle) -> Result<Handle, JsValue> {
+    broadcast_binary_op(handle1, handle2, |a, b| a * b)
+}
+
+/// Element-wise tensor division with NumPy-style broadcasting.
+#[wasm_bindgen(js_name = "tensorDiv")]
+pub fn tensor_div(handle1: Handle, handle2: Handle) -> Result<Handle, JsValue> {
+    broadcast_binary_op(handle1, handle2, |a, b| a / b)
+}
@@ -0,0 +1,685 @@
+use crate::state::{Handle, HANDLES};
+use achronyme_parser::ast::{
+    ArrayElement, AstNode, BinaryOp, MatchArm, Pattern, RecordFieldOrSpread, UnaryOp,
+    VectorPatternElement,
+};
+use achronyme_types::function::Function;
+use achronyme_types::value::Value;
+use achronyme_types::Environment;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// Beta-Normalization for `Value::Function`
+// ============================================================================
+//
+// `format_value` used to render every closure as the opaque `"x => <function>"`
+// placeholder. This module turns closures into inspectable, simplifiable
+// expressions: `normalize` substitutes closure captures into the lambda body
+// where they can be faithfully re-expressed as literals, inlines direct
+// applications of literal lambdas (beta-reduction of redexes), and leaves
+// everything else - free variables, builtin calls - untouched as an open term.
+// `format_function` then pretty-prints the resulting normal form.
+
+/// Normalize the function stored at `handle`, returning a new handle holding
+/// the reduced `Value::Function`. A non-function value is copied unchanged
+/// into the new handle (there is nothing to beta-reduce).
+#[wasm_bindgen]
+pub fn normalize(handle: Handle) -> Result<Handle, JsValue> {
+    let value = HANDLES.with(|h| h.borrow().get(handle).cloned())
+        .ok_or_else(|| JsValue::from_str("Invalid handle"))?;
+
+    let normalized = match &value {
+        Value::Function(func) => Value::Function(normalize_function(func)),
+        other => other.clone(),
+    };
+
+    Ok(HANDLES.with(|h| h.borrow_mut().create(normalized)))
+}
+
+/// A substitution context: bound names map to the (already-normalized) AST
+/// they stand for. A name with no entry is free - it is printed as-is.
+struct NormCtx {
+    subst: HashMap<String, AstNode>,
+    fresh_counter: u32,
+}
+
+impl NormCtx {
+    fn fresh_name(&mut self, base: &str) -> String {
+        self.fresh_counter += 1;
+        format!("{}${}", base, self.fresh_counter)
+    }
+
+    /// Is `name` free in some value currently being substituted in? If so, a
+    /// binder reintroducing `name` would wrongly capture that substitution's
+    /// reference and must be alpha-renamed first.
+    fn is_capture_risk(&self, name: &str) -> bool {
+        self.subst.values().any(|ast| ast_mentions(ast, name))
+    }
+
+    /// Bind `names` for the scope of `f` (e.g. a lambda's parameters, a
+    /// for-loop variable, a catch clause's error binding). Any name that
+    /// would capture a free variable of an active substitution is
+    /// alpha-renamed first; `f` receives the (possibly renamed) binder names
+    /// to use when reconstructing the node.
+    fn with_bound<T>(&mut self, names: &[String], f: impl FnOnce(&mut Self, &[String]) -> T) -> T {
+        let mut restore: Vec<(String, Option<AstNode>)> = Vec::with_capacity(names.len());
+        let mut final_names: Vec<String> = Vec::with_capacity(names.len());
+
+        for name in names {
+            if self.is_capture_risk(name) {
+                let fresh = self.fresh_name(name);
+                restore.push((name.clone(), self.subst.insert(name.clone(), AstNode::VariableRef(fresh.clone()))));
+                final_names.push(fresh);
+            } else {
+                restore.push((name.clone(), self.subst.remove(name)));
+                final_names.push(name.clone());
+            }
+        }
+
+        let result = f(self, &final_names);
+
+        for (n, old) in restore {
+            match old {
+                Some(old) => { self.subst.insert(n, old); }
+                None => { self.subst.remove(&n); }
+            }
+        }
+
+        result
+    }
+}
+
+/// Conservatively checks whether `name` appears as a variable or call
+/// reference anywhere in `node`. Used only to decide whether a binder needs
+/// alpha-renaming, so over-reporting (ignoring that an inner binder might
+/// already shadow `name`) is safe - it just causes a harmless extra rename.
+fn ast_mentions(node: &AstNode, name: &str) -> bool {
+    match node {
+        AstNode::Number(_) | AstNode::Integer(_) | AstNode::Boolean(_) | AstNode::StringLiteral(_)
+        | AstNode::Null | AstNode::ComplexLiteral { .. } | AstNode::SelfReference
+        | AstNode::RecReference | AstNode::Import { .. } | AstNode::Export { .. }
+        | AstNode::TypeAlias { .. } => false,
+
+        AstNode::VariableRef(n) => n == name,
+        AstNode::FunctionCall { name: n, args } => n == name || args.iter().any(|a| ast_mentions(a, name)),
+        AstNode::CallExpression { callee, args } => ast_mentions(callee, name) || args.iter().any(|a| ast_mentions(a, name)),
+        AstNode::BinaryOp { left, right, .. } => ast_mentions(left, name) || ast_mentions(right, name),
+        AstNode::UnaryOp { operand, .. } => ast_mentions(operand, name),
+        AstNode::If { condition, then_expr, else_expr } => {
+            ast_mentions(condition, name) || ast_mentions(then_expr, name) || ast_mentions(else_expr, name)
+        }
+        AstNode::Piecewise { cases, default } => {
+            cases.iter().any(|(c, e)| ast_mentions(c, name) || ast_mentions(e, name))
+                || default.as_ref().is_some_and(|d| ast_mentions(d, name))
+        }
+        AstNode::Pipe { left, right } => ast_mentions(left, name) || ast_mentions(right, name),
+        AstNode::ArrayLiteral(elements) => elements.iter().any(|e| match e {
+            ArrayElement::Single(expr) => ast_mentions(expr, name),
+            ArrayElement::Spread(expr) => ast_mentions(expr, name),
+        }),
+        AstNode::RecordLiteral(fields) => fields.iter().any(|f| match f {
+            RecordFieldOrSpread::Field { value, .. } => ast_mentions(value, name),
+            RecordFieldOrSpread::MutableField { value, .. } => ast_mentions(value, name),
+            RecordFieldOrSpread::Spread(expr) => ast_mentions(expr, name),
+        }),
+        AstNode::FieldAccess { record, .. } => ast_mentions(record, name),
+        AstNode::VariableDecl { initializer, .. } => ast_mentions(initializer, name),
+        AstNode::MutableDecl { initializer, .. } => ast_mentions(initializer, name),
+        AstNode::Assignment { target, value } => ast_mentions(target, name) || ast_mentions(value, name),
+        AstNode::Return { value } => ast_mentions(value, name),
+        AstNode::Lambda { body, .. } => ast_mentions(body, name),
+        AstNode::Edge { metadata, .. } => metadata.as_ref().is_some_and(|m| ast_mentions(m, name)),
+        AstNode::IndexAccess { object, .. } => ast_mentions(object, name),
+        AstNode::Sequence { statements } | AstNode::DoBlock { statements } | AstNode::GenerateBlock { statements } => {
+            statements.iter().any(|s| ast_mentions(s, name))
+        }
+        AstNode::WhileLoop { condition, body } => ast_mentions(condition, name) || ast_mentions(body, name),
+        AstNode::Yield { value } => ast_mentions(value, name),
+        AstNode::ForInLoop { iterable, body, .. } => ast_mentions(iterable, name) || ast_mentions(body, name),
+        AstNode::Throw { value } => ast_mentions(value, name),
+        AstNode::TryCatch { try_block, catch_block, .. } => ast_mentions(try_block, name) || ast_mentions(catch_block, name),
+        AstNode::Match { value, arms } => {
+            ast_mentions(value, name)
+                || arms.iter().any(|arm| {
+                    arm.guard.as_ref().is_some_and(|g| ast_mentions(g, name)) || ast_mentions(&arm.body, name)
+                })
+        }
+    }
+}
+
+pub(crate) fn normalize_function(func: &Function) -> Function {
+    match func {
+        Function::Builtin(_) => func.clone(),
+        Function::Partial { func: inner, applied_args, total_arity } => Function::Partial {
+            func: Box::new(normalize_function(inner)),
+            applied_args: applied_args.clone(),
+            total_arity: *total_arity,
+        },
+        Function::UserDefined { params, param_types, return_type, body, closure_env } => {
+            let mut ctx = NormCtx { subst: HashMap::new(), fresh_counter: 0 };
+
+            // Seed the context from the closure's captures. Only values we can
+            // faithfully re-express as an AST literal are inlined; anything
+            // else (tensors, records, other closures, ...) is left as a free
+            // variable reference, exactly as the open term it is.
+            for (name, value) in closure_env.borrow().snapshot() {
+                if let Some(literal) = value_to_ast_literal(&value) {
+                    ctx.subst.insert(name, literal);
+                }
+            }
+
+            let (final_params, normalized_body) = ctx.with_bound(params, |ctx, names| {
+                (names.to_vec(), trampoline_self_calls(names, body, ctx))
+            });
+
+            Function::UserDefined {
+                params: final_params,
+                param_types: param_types.clone(),
+                return_type: return_type.clone(),
+                body: Rc::new(normalized_body),
+                closure_env: Rc::new(RefCell::new(Environment::new())),
+            }
+        }
+    }
+}
+
+/// A `rec(...)` call in tail position is the AST-level counterpart of the
+/// `Value::TailCall` marker the evaluator's TCO loop (see
+/// `achronyme-eval/src/handlers/functions.rs`) produces instead of actually
+/// recursing. Mirror that loop here, statically: normalize the body, and if
+/// it comes out as a self-call with the right arity, rebind `params` to the
+/// (already-normalized) call arguments and normalize again, instead of
+/// leaving an opaque `rec(...)` redex in the printed result.
+///
+/// Bounded by `MAX_TRAMPOLINE_STEPS` since, unlike the runtime loop, this has
+/// no actual termination guarantee - a tail call whose arguments never
+/// collapse to literals (so `If` conditions never reduce) would otherwise
+/// spin forever. Hitting the cap just leaves the last `rec(...)` redex
+/// un-reduced rather than erroring.
+const MAX_TRAMPOLINE_STEPS: u32 = 256;
+
+fn trampoline_self_calls(params: &[String], body: &AstNode, ctx: &mut NormCtx) -> AstNode {
+    let mut tail_args: Option<Vec<AstNode>> = None;
+
+    for _ in 0..MAX_TRAMPOLINE_STEPS {
+        let reduced = match &tail_args {
+            None => normalize_ast(body, ctx),
+            Some(args) => {
+                let saved: Vec<(String, Option<AstNode>)> = params.iter()
+                    .map(|p| (p.clone(), ctx.subst.remove(p)))
+                    .collect();
+                for (p, a) in params.iter().zip(args.iter()) {
+                    ctx.subst.insert(p.clone(), a.clone());
+                }
+
+                let result = normalize_ast(body, ctx);
+
+                for (p, old) in saved {
+                    match old {
+                        Some(old) => { ctx.subst.insert(p, old); }
+                        None => { ctx.subst.remove(&p); }
+                    }
+                }
+
+                result
+            }
+        };
+
+        match &reduced {
+            AstNode::CallExpression { callee, args: call_args }
+                if matches!(**callee, AstNode::RecReference) && call_args.len() == params.len() =>
+            {
+                tail_args = Some(call_args.clone());
+            }
+            _ => return reduced,
+        }
+    }
+
+    // Cap exceeded: surface the last unresolved `rec(...)` redex, rebuilt
+    // from its already-normalized arguments.
+    match tail_args {
+        None => normalize_ast(body, ctx),
+        Some(args) => AstNode::CallExpression {
+            callee: Box::new(AstNode::RecReference),
+            args,
+        },
+    }
+}
+
+/// Re-express a runtime `Value` as an equivalent AST literal, if possible.
+fn value_to_ast_literal(value: &Value) -> Option<AstNode> {
+    match value {
+        Value::Number(n) => Some(AstNode::Number(*n)),
+        Value::Integer(n) => Some(AstNode::Integer(*n)),
+        Value::Boolean(b) => Some(AstNode::Boolean(*b)),
+        Value::String(s) => Some(AstNode::StringLiteral(s.clone())),
+        Value::Complex(c) => Some(AstNode::ComplexLiteral { re: c.re, im: c.im }),
+        Value::Null => Some(AstNode::Null),
+        Value::Vector(items) => {
+            let elements: Option<Vec<ArrayElement>> = items.iter()
+                .map(|v| value_to_ast_literal(v).map(ArrayElement::Single))
+                .collect();
+            elements.map(AstNode::ArrayLiteral)
+        }
+        _ => None,
+    }
+}
+
+/// Beta-reduce a direct application: `callee` is a literal `Lambda` node and
+/// `args` are the already-normalized argument expressions. Binds each formal
+/// parameter to its argument in a fresh context layer (shadowing any outer
+/// substitution with the same name) and normalizes the lambda body there.
+/// Falls back to `None` on arity mismatch, leaving the call un-reduced.
+fn beta_reduce(
+    params: &[(String, Option<achronyme_parser::type_annotation::TypeAnnotation>)],
+    lambda_body: &AstNode,
+    args: &[AstNode],
+    ctx: &mut NormCtx,
+) -> Option<AstNode> {
+    if params.len() != args.len() {
+        return None;
+    }
+
+    let names: Vec<String> = params.iter().map(|(n, _)| n.clone()).collect();
+
+    // Substitute each formal parameter with its (already-normalized)
+    // argument. The parameter is eliminated entirely by this substitution -
+    // there is no output binder to alpha-rename here. Capture of a bound
+    // variable *inside* `lambda_body` by a free variable of `arg` is instead
+    // caught when `normalize_ast` reaches that inner binder: it will see
+    // this substitution as an active `is_capture_risk` and rename itself.
+    let saved: Vec<(String, Option<AstNode>)> = names.iter()
+        .map(|n| (n.clone(), ctx.subst.remove(n)))
+        .collect();
+
+    for (name, arg) in names.iter().zip(args.iter()) {
+        ctx.subst.insert(name.clone(), arg.clone());
+    }
+
+    let result = normalize_ast(lambda_body, ctx);
+
+    for (n, old) in saved {
+        match old {
+            Some(old) => { ctx.subst.insert(n, old); }
+            None => { ctx.subst.remove(&n); }
+        }
+    }
+
+    Some(result)
+}
+
+fn normalize_many(nodes: &[AstNode], ctx: &mut NormCtx) -> Vec<AstNode> {
+    nodes.iter().map(|n| normalize_ast(n, ctx)).collect()
+}
+
+fn normalize_ast(node: &AstNode, ctx: &mut NormCtx) -> AstNode {
+    match node {
+        AstNode::Number(_) | AstNode::Integer(_) | AstNode::Boolean(_) | AstNode::StringLiteral(_)
+        | AstNode::Null | AstNode::ComplexLiteral { .. } | AstNode::SelfReference
+        | AstNode::RecReference => node.clone(),
+
+        AstNode::VariableRef(name) => ctx.subst.get(name).cloned().unwrap_or_else(|| node.clone()),
+
+        AstNode::BinaryOp { op, left, right } => AstNode::BinaryOp {
+            op: op.clone(),
+            left: Box::new(normalize_ast(left, ctx)),
+            right: Box::new(normalize_ast(right, ctx)),
+        },
+
+        AstNode::UnaryOp { op, operand } => AstNode::UnaryOp {
+            op: op.clone(),
+            operand: Box::new(normalize_ast(operand, ctx)),
+        },
+
+        AstNode::If { condition, then_expr, else_expr } => {
+            let condition = normalize_ast(condition, ctx);
+            // Reduce the redex when the condition collapses to a literal.
+            match &condition {
+                AstNode::Boolean(true) => return normalize_ast(then_expr, ctx),
+                AstNode::Boolean(false) => return normalize_ast(else_expr, ctx),
+                _ => {}
+            }
+            AstNode::If {
+                condition: Box::new(condition),
+                then_expr: Box::new(normalize_ast(then_expr, ctx)),
+                else_expr: Box::new(normalize_ast(else_expr, ctx)),
+            }
+        }
+
+        AstNode::Piecewise { cases, default } => AstNode::Piecewise {
+            cases: cases.iter()
+                .map(|(cond, expr)| (Box::new(normalize_ast(cond, ctx)), Box::new(normalize_ast(expr, ctx))))
+                .collect(),
+            default: default.as_ref().map(|d| Box::new(normalize_ast(d, ctx))),
+        },
+
+        AstNode::FunctionCall { name, args } => {
+            let args = normalize_many(args, ctx);
+            if let Some(AstNode::Lambda { params, body, .. }) = ctx.subst.get(name).cloned() {
+                if let Some(reduced) = beta_reduce(&params, &body, &args, ctx) {
+                    return reduced;
+                }
+            }
+            AstNode::FunctionCall { name: name.clone(), args }
+        }
+
+        AstNode::CallExpression { callee, args } => {
+            let callee = normalize_ast(callee, ctx);
+            let args = normalize_many(args, ctx);
+            if let AstNode::Lambda { params, body, .. } = &callee {
+                if let Some(reduced) = beta_reduce(params, body, &args, ctx) {
+                    return reduced;
+                }
+            }
+            AstNode::CallExpression { callee: Box::new(callee), args }
+        }
+
+        AstNode::Pipe { left, right } => AstNode::Pipe {
+            left: Box::new(normalize_ast(left, ctx)),
+            right: Box::new(normalize_ast(right, ctx)),
+        },
+
+        AstNode::ArrayLiteral(elements) => AstNode::ArrayLiteral(
+            elements.iter()
+                .map(|e| match e {
+                    ArrayElement::Single(expr) => ArrayElement::Single(normalize_ast(expr, ctx)),
+                    ArrayElement::Spread(expr) => ArrayElement::Spread(Box::new(normalize_ast(expr, ctx))),
+                })
+                .collect(),
+        ),
+
+        AstNode::RecordLiteral(fields) => AstNode::RecordLiteral(
+            fields.iter()
+                .map(|f| match f {
+                    RecordFieldOrSpread::Field { name, value } => RecordFieldOrSpread::Field {
+                        name: name.clone(),
+                        value: normalize_ast(value, ctx),
+                    },
+                    RecordFieldOrSpread::MutableField { name, value } => RecordFieldOrSpread::MutableField {
+                        name: name.clone(),
+                        value: normalize_ast(value, ctx),
+                    },
+                    RecordFieldOrSpread::Spread(expr) => RecordFieldOrSpread::Spread(Box::new(normalize_ast(expr, ctx))),
+                })
+                .collect(),
+        ),
+
+        AstNode::FieldAccess { record, field } => AstNode::FieldAccess {
+            record: Box::new(normalize_ast(record, ctx)),
+            field: field.clone(),
+        },
+
+        AstNode::VariableDecl { name, type_annotation, initializer } => {
+            let initializer = normalize_ast(initializer, ctx);
+            ctx.subst.remove(name);
+            AstNode::VariableDecl { name: name.clone(), type_annotation: type_annotation.clone(), initializer: Box::new(initializer) }
+        }
+
+        AstNode::MutableDecl { name, type_annotation, initializer } => {
+            let initializer = normalize_ast(initializer, ctx);
+            ctx.subst.remove(name);
+            AstNode::MutableDecl { name: name.clone(), type_annotation: type_annotation.clone(), initializer: Box::new(initializer) }
+        }
+
+        AstNode::Assignment { target, value } => AstNode::Assignment {
+            target: Box::new(normalize_ast(target, ctx)),
+            value: Box::new(normalize_ast(value, ctx)),
+        },
+
+        AstNode::Return { value } => AstNode::Return { value: Box::new(normalize_ast(value, ctx)) },
+
+        AstNode::Lambda { params, return_type, body } => {
+            let names: Vec<String> = params.iter().map(|(n, _)| n.clone()).collect();
+            let (final_names, body) = ctx.with_bound(&names, |ctx, names| (names.to_vec(), normalize_ast(body, ctx)));
+            let new_params = final_names.into_iter()
+                .zip(params.iter().map(|(_, t)| t.clone()))
+                .collect();
+            AstNode::Lambda { params: new_params, return_type: return_type.clone(), body: Box::new(body) }
+        }
+
+        AstNode::Edge { from, to, directed, metadata } => AstNode::Edge {
+            from: from.clone(),
+            to: to.clone(),
+            directed: *directed,
+            metadata: metadata.as_ref().map(|m| Box::new(normalize_ast(m, ctx))),
+        },
+
+        AstNode::IndexAccess { object, indices } => AstNode::IndexAccess {
+            object: Box::new(normalize_ast(object, ctx)),
+            indices: indices.clone(),
+        },
+
+        AstNode::Sequence { statements } => AstNode::Sequence { statements: normalize_many(statements, ctx) },
+        AstNode::DoBlock { statements } => AstNode::DoBlock { statements: normalize_many(statements, ctx) },
+
+        AstNode::WhileLoop { condition, body } => AstNode::WhileLoop {
+            condition: Box::new(normalize_ast(condition, ctx)),
+            body: Box::new(normalize_ast(body, ctx)),
+        },
+
+        AstNode::Import { .. } | AstNode::Export { .. } | AstNode::TypeAlias { .. } => node.clone(),
+
+        AstNode::Yield { value } => AstNode::Yield { value: Box::new(normalize_ast(value, ctx)) },
+        AstNode::GenerateBlock { statements } => AstNode::GenerateBlock { statements: normalize_many(statements, ctx) },
+
+        AstNode::ForInLoop { variable, iterable, body } => {
+            let iterable = normalize_ast(iterable, ctx);
+            let (final_names, body) = ctx.with_bound(&[variable.clone()], |ctx, names| (names.to_vec(), normalize_ast(body, ctx)));
+            AstNode::ForInLoop { variable: final_names[0].clone(), iterable: Box::new(iterable), body: Box::new(body) }
+        }
+
+        AstNode::Throw { value } => AstNode::Throw { value: Box::new(normalize_ast(value, ctx)) },
+
+        AstNode::TryCatch { try_block, error_param, catch_block } => {
+            let try_block = normalize_ast(try_block, ctx);
+            let (final_names, catch_block) = ctx.with_bound(&[error_param.clone()], |ctx, names| (names.to_vec(), normalize_ast(catch_block, ctx)));
+            AstNode::TryCatch { try_block: Box::new(try_block), error_param: final_names[0].clone(), catch_block: Box::new(catch_block) }
+        }
+
+        AstNode::Match { value, arms } => {
+            let value = normalize_ast(value, ctx);
+            // Pattern-bound names are shadowed (so outer substitutions don't
+            // leak into the arm) but not alpha-renamed: renaming would also
+            // require rewriting the `Pattern` itself, which match arms don't
+            // need here since patterns aren't re-emitted as source text.
+            let arms = arms.iter()
+                .map(|arm| {
+                    let bound = pattern_bound_names(&arm.pattern);
+                    let (guard, body) = ctx.with_bound(&bound, |ctx, _| {
+                        (
+                            arm.guard.as_ref().map(|g| Box::new(normalize_ast(g, ctx))),
+                            Box::new(normalize_ast(&arm.body, ctx)),
+                        )
+                    });
+                    MatchArm { pattern: arm.pattern.clone(), guard, body }
+                })
+                .collect();
+            AstNode::Match { value: Box::new(value), arms }
+        }
+    }
+}
+
+/// Names a pattern binds, so they can be shadowed while normalizing a match arm.
+fn pattern_bound_names(pattern: &Pattern) -> Vec<String> {
+    match pattern {
+        Pattern::Variable(name) => vec![name.clone()],
+        Pattern::Record { fields } => fields.iter().flat_map(|(_, p)| pattern_bound_names(p)).collect(),
+        Pattern::Vector { elements } => elements.iter()
+            .flat_map(|e| match e {
+                VectorPatternElement::Pattern(p) => pattern_bound_names(p),
+                VectorPatternElement::Rest(name) => vec![name.clone()],
+            })
+            .collect(),
+        Pattern::Literal(_) | Pattern::Wildcard | Pattern::Type(_) => Vec::new(),
+    }
+}
+
+// ============================================================================
+// Pretty-Printing the Normal Form
+// ============================================================================
+
+/// Pretty-print a function's normal form as `params => body`, in the same
+/// surface syntax a user would have written it in. Builtins, partial
+/// applications, index arguments, match arms, imports and type aliases
+/// print as placeholders rather than source text.
+pub fn format_function(func: &Function) -> String {
+    match func {
+        Function::Builtin(name) => format!("<builtin {}>", name),
+        Function::UserDefined { params, body, .. } => {
+            let params_str = match params.as_slice() {
+                [single] => single.clone(),
+                _ => format!("({})", params.join(", ")),
+            };
+            format!("{} => {}", params_str, format_ast(body))
+        }
+        Function::Partial { func, applied_args, total_arity } => {
+            format!(
+                "{}(<{} of {} args applied>)",
+                format_function(func),
+                applied_args.len(),
+                total_arity
+            )
+        }
+    }
+}
+
+fn format_ast(node: &AstNode) -> String {
+    match node {
+        AstNode::Number(n) => n.to_string(),
+        AstNode::Integer(n) => n.to_string(),
+        AstNode::Boolean(b) => b.to_string(),
+        AstNode::StringLiteral(s) => format!("\"{}\"", s),
+        AstNode::Null => "null".to_string(),
+        AstNode::ComplexLiteral { re, im } => format!("({}+{}i)", re, im),
+        AstNode::SelfReference => "self".to_string(),
+        AstNode::RecReference => "rec".to_string(),
+        AstNode::VariableRef(name) => name.clone(),
+
+        AstNode::BinaryOp { op, left, right } => {
+            format!("({} {} {})", format_ast(left), binary_op_symbol(op), format_ast(right))
+        }
+
+        AstNode::UnaryOp { op, operand } => {
+            let symbol = match op {
+                UnaryOp::Negate => "-",
+                UnaryOp::Not => "!",
+            };
+            format!("{}{}", symbol, format_ast(operand))
+        }
+
+        AstNode::If { condition, then_expr, else_expr } => {
+            format!("if ({}) {{ {} }} else {{ {} }}", format_ast(condition), format_ast(then_expr), format_ast(else_expr))
+        }
+
+        AstNode::Piecewise { cases, default } => {
+            let mut parts: Vec<String> = cases.iter()
+                .map(|(cond, expr)| format!("{}: {}", format_ast(cond), format_ast(expr)))
+                .collect();
+            if let Some(default) = default {
+                parts.push(format!("otherwise: {}", format_ast(default)));
+            }
+            format!("piecewise {{ {} }}", parts.join(", "))
+        }
+
+        AstNode::FunctionCall { name, args } => {
+            format!("{}({})", name, args.iter().map(format_ast).collect::<Vec<_>>().join(", "))
+        }
+
+        AstNode::CallExpression { callee, args } => {
+            format!("{}({})", format_ast(callee), args.iter().map(format_ast).collect::<Vec<_>>().join(", "))
+        }
+
+        AstNode::Pipe { left, right } => format!("{} |> {}", format_ast(left), format_ast(right)),
+
+        AstNode::ArrayLiteral(elements) => {
+            let items: Vec<String> = elements.iter()
+                .map(|e| match e {
+                    ArrayElement::Single(expr) => format_ast(expr),
+                    ArrayElement::Spread(expr) => format!("...{}", format_ast(expr)),
+                })
+                .collect();
+            format!("[{}]", items.join(", "))
+        }
+
+        AstNode::RecordLiteral(fields) => {
+            let items: Vec<String> = fields.iter()
+                .map(|f| match f {
+                    RecordFieldOrSpread::Field { name, value } => format!("{}: {}", name, format_ast(value)),
+                    RecordFieldOrSpread::MutableField { name, value } => format!("mut {}: {}", name, format_ast(value)),
+                    RecordFieldOrSpread::Spread(expr) => format!("...{}", format_ast(expr)),
+                })
+                .collect();
+            format!("{{ {} }}", items.join(", "))
+        }
+
+        AstNode::FieldAccess { record, field } => format!("{}.{}", format_ast(record), field),
+
+        AstNode::VariableDecl { name, initializer, .. } => format!("let {} = {}", name, format_ast(initializer)),
+        AstNode::MutableDecl { name, initializer, .. } => format!("mut {} = {}", name, format_ast(initializer)),
+        AstNode::Assignment { target, value } => format!("{} = {}", format_ast(target), format_ast(value)),
+        AstNode::Return { value } => format!("return {}", format_ast(value)),
+
+        AstNode::Lambda { params, body, .. } => {
+            let names: Vec<&str> = params.iter().map(|(n, _)| n.as_str()).collect();
+            let params_str = match names.as_slice() {
+                [single] => single.to_string(),
+                _ => format!("({})", names.join(", ")),
+            };
+            format!("{} => {}", params_str, format_ast(body))
+        }
+
+        AstNode::Edge { from, to, directed, .. } => {
+            format!("{} {} {}", from, if *directed { "->" } else { "<>" }, to)
+        }
+
+        AstNode::IndexAccess { object, indices } => format!("{}[{}]", format_ast(object), indices.len()),
+
+        AstNode::Sequence { statements } | AstNode::DoBlock { statements } | AstNode::GenerateBlock { statements } => {
+            format!("{{ {} }}", statements.iter().map(format_ast).collect::<Vec<_>>().join("; "))
+        }
+
+        AstNode::WhileLoop { condition, body } => format!("while ({}) {{ {} }}", format_ast(condition), format_ast(body)),
+
+        AstNode::Import { .. } => "<import>".to_string(),
+        AstNode::Export { .. } => "<export>".to_string(),
+        AstNode::TypeAlias { name, .. } => format!("type {} = <type>", name),
+
+        AstNode::Yield { value } => format!("yield {}", format_ast(value)),
+
+        AstNode::ForInLoop { variable, iterable, body } => {
+            format!("for ({} in {}) {{ {} }}", variable, format_ast(iterable), format_ast(body))
+        }
+
+        AstNode::Throw { value } => format!("throw {}", format_ast(value)),
+
+        AstNode::TryCatch { try_block, error_param, catch_block } => {
+            format!("try {{ {} }} catch({}) {{ {} }}", format_ast(try_block), error_param, format_ast(catch_block))
+        }
+
+        AstNode::Match { value, arms } => {
+            format!("match ({}) {{ {} arms }}", format_ast(value), arms.len())
+        }
+    }
+}
+
+fn binary_op_symbol(op: &BinaryOp) -> &'static str {
+    match op {
+        BinaryOp::Add => "+",
+        BinaryOp::Subtract => "-",
+        BinaryOp::Multiply => "*",
+        BinaryOp::Divide => "/",
+        BinaryOp::Power => "^",
+        BinaryOp::Modulo => "%",
+        BinaryOp::Gt => ">",
+        BinaryOp::Lt => "<",
+        BinaryOp::Gte => ">=",
+        BinaryOp::Lte => "<=",
+        BinaryOp::Eq => "==",
+        BinaryOp::Neq => "!=",
+        BinaryOp::And => "&&",
+        BinaryOp::Or => "||",
+    }
+}
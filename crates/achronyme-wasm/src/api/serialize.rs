@@ -0,0 +1,489 @@
+use crate::state::{Handle, HANDLES};
+use achronyme_types::complex::Complex;
+use achronyme_types::tensor::{ComplexTensor, RealTensor};
+use achronyme_types::value::Value;
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+// ============================================================================
+// Lossless JSON Serialization
+// ============================================================================
+//
+// `format_value` (see utils.rs) is display-only and lossy: it truncates
+// tensor entries to 6 decimals and collapses functions to a placeholder
+// string. `value_to_json`/`value_from_json` instead emit/parse a tagged
+// JSON representation that round-trips every serializable `Value` variant,
+// so JS callers can marshal a result out, store it, and rebuild a handle
+// from it later. A mutable reference is written as its current inner value
+// and comes back as that plain, immutable value.
+
+/// Serialize a handle's value to a tagged, lossless JSON string.
+#[wasm_bindgen(js_name = "valueToJson")]
+pub fn value_to_json(handle: Handle) -> Result<String, JsValue> {
+    let value = HANDLES.with(|h| h.borrow().get(handle).cloned())
+        .ok_or_else(|| JsValue::from_str("Invalid handle"))?;
+
+    value_to_json_string(&value).map_err(JsValue::from_str)
+}
+
+/// Reconstruct a handle from JSON produced by `value_to_json`.
+#[wasm_bindgen(js_name = "valueFromJson")]
+pub fn value_from_json(json: &str) -> Result<Handle, JsValue> {
+    let mut parser = JsonParser::new(json);
+    let parsed = parser.parse_value().map_err(JsValue::from_str)?;
+    parser.skip_whitespace();
+    if !parser.is_at_end() {
+        return Err(JsValue::from_str("Unexpected trailing characters after JSON value"));
+    }
+
+    let value = json_to_value(&parsed).map_err(JsValue::from_str)?;
+    Ok(HANDLES.with(|h| h.borrow_mut().create(value)))
+}
+
+fn value_to_json_string(value: &Value) -> Result<String, String> {
+    match value {
+        Value::Number(n) => Ok(format!(r#"{{"type":"number","value":{}}}"#, format_f64(*n))),
+        Value::Integer(n) => Ok(format!(r#"{{"type":"integer","value":{}}}"#, n)),
+        Value::Boolean(b) => Ok(format!(r#"{{"type":"boolean","value":{}}}"#, b)),
+        Value::String(s) => Ok(format!(r#"{{"type":"string","value":"{}"}}"#, escape_json(s))),
+
+        Value::Complex(c) => Ok(format!(
+            r#"{{"type":"complex","re":{},"im":{}}}"#,
+            format_f64(c.re), format_f64(c.im)
+        )),
+
+        Value::Vector(v) => {
+            let items: Result<Vec<String>, String> = v.iter().map(value_to_json_string).collect();
+            Ok(format!(r#"{{"type":"vector","items":[{}]}}"#, items?.join(",")))
+        }
+
+        Value::Tensor(t) => {
+            let shape = t.shape().iter().map(|d| d.to_string()).collect::<Vec<_>>().join(",");
+            let data = t.data().iter().map(|x| format_f64(*x)).collect::<Vec<_>>().join(",");
+            Ok(format!(r#"{{"type":"tensor","shape":[{}],"data":[{}]}}"#, shape, data))
+        }
+
+        Value::ComplexTensor(t) => {
+            let shape = t.shape().iter().map(|d| d.to_string()).collect::<Vec<_>>().join(",");
+            let data = t.data().iter()
+                .map(|c| format!(r#"{{"re":{},"im":{}}}"#, format_f64(c.re), format_f64(c.im)))
+                .collect::<Vec<_>>().join(",");
+            Ok(format!(r#"{{"type":"complexTensor","shape":[{}],"data":[{}]}}"#, shape, data))
+        }
+
+        Value::Record(map) => {
+            let fields: Result<Vec<String>, String> = map.iter()
+                .map(|(k, v)| Ok(format!(r#""{}":{}"#, escape_json(k), value_to_json_string(v)?)))
+                .collect();
+            Ok(format!(r#"{{"type":"record","fields":{{{}}}}}"#, fields?.join(",")))
+        }
+
+        Value::Edge { from, to, directed, properties } => {
+            let props: Result<Vec<String>, String> = properties.iter()
+                .map(|(k, v)| Ok(format!(r#""{}":{}"#, escape_json(k), value_to_json_string(v)?)))
+                .collect();
+            Ok(format!(
+                r#"{{"type":"edge","from":"{}","to":"{}","directed":{},"properties":{{{}}}}}"#,
+                escape_json(from), escape_json(to), directed, props?.join(",")
+            ))
+        }
+
+        Value::Function(func) => match func.builtin_name() {
+            Some(name) => Ok(format!(r#"{{"type":"builtinFunction","name":"{}"}}"#, escape_json(name))),
+            None => Err("cannot serialize a user-defined function to JSON".to_string()),
+        },
+
+        Value::MutableRef(rc) => match rc.try_borrow() {
+            Ok(inner) => value_to_json_string(&inner),
+            Err(_) => Err("cannot serialize a borrowed mutable reference to JSON".to_string()),
+        },
+
+        Value::Null => Ok(r#"{"type":"null"}"#.to_string()),
+
+        Value::TailCall(_) => Err("cannot serialize an internal tail-call marker to JSON".to_string()),
+        Value::Dual(_) => Err("cannot serialize an internal dual-number marker to JSON".to_string()),
+        Value::EarlyReturn(_) => Err("cannot serialize an internal early-return marker to JSON".to_string()),
+        Value::Generator(_) => Err("cannot serialize a generator to JSON".to_string()),
+        Value::GeneratorYield(_) => Err("cannot serialize an internal generator-yield marker to JSON".to_string()),
+        Value::Error { .. } => Err("cannot serialize an error value to JSON".to_string()),
+    }
+}
+
+fn json_to_value(json: &Json) -> Result<Value, String> {
+    let fields = match json {
+        Json::Object(fields) => fields,
+        _ => return Err("expected a JSON object with a \"type\" tag".to_string()),
+    };
+
+    let tag = json_str_field(fields, "type")?;
+    match tag {
+        "number" => Ok(Value::Number(json_f64_field(fields, "value")?)),
+        "integer" => Ok(Value::Integer(json_f64_field(fields, "value")? as i64)),
+        "boolean" => match json_field(fields, "value")? {
+            Json::Bool(b) => Ok(Value::Boolean(*b)),
+            _ => Err("\"value\" must be a boolean".to_string()),
+        },
+        "string" => Ok(Value::String(json_str_field(fields, "value")?.to_string())),
+
+        "complex" => Ok(Value::Complex(Complex::new(
+            json_f64_field(fields, "re")?,
+            json_f64_field(fields, "im")?,
+        ))),
+
+        "vector" => {
+            let items = match json_field(fields, "items")? {
+                Json::Array(items) => items,
+                _ => return Err("\"items\" must be an array".to_string()),
+            };
+            let values: Result<Vec<Value>, String> = items.iter().map(json_to_value).collect();
+            Ok(Value::Vector(values?))
+        }
+
+        "tensor" => {
+            let shape = json_usize_array_field(fields, "shape")?;
+            let data = json_f64_array_field(fields, "data")?;
+            RealTensor::new(data, shape).map(Value::Tensor).map_err(|e| e.to_string())
+        }
+
+        "complexTensor" => {
+            let shape = json_usize_array_field(fields, "shape")?;
+            let data = match json_field(fields, "data")? {
+                Json::Array(items) => items,
+                _ => return Err("\"data\" must be an array".to_string()),
+            };
+            let complex_data: Result<Vec<Complex>, String> = data.iter()
+                .map(|entry| match entry {
+                    Json::Object(entry_fields) => Ok(Complex::new(
+                        json_f64_field(entry_fields, "re")?,
+                        json_f64_field(entry_fields, "im")?,
+                    )),
+                    _ => Err("complexTensor data entries must be {re, im} objects".to_string()),
+                })
+                .collect();
+            ComplexTensor::new(complex_data?, shape).map(Value::ComplexTensor).map_err(|e| e.to_string())
+        }
+
+        "record" => {
+            let record_fields = match json_field(fields, "fields")? {
+                Json::Object(record_fields) => record_fields,
+                _ => return Err("\"fields\" must be an object".to_string()),
+            };
+            let mut map = HashMap::new();
+            for (k, v) in record_fields {
+                map.insert(k.clone(), json_to_value(v)?);
+            }
+            Ok(Value::Record(map))
+        }
+
+        "edge" => {
+            let properties = match json_field(fields, "properties")? {
+                Json::Object(prop_fields) => prop_fields,
+                _ => return Err("\"properties\" must be an object".to_string()),
+            };
+            let mut props = HashMap::new();
+            for (k, v) in properties {
+                props.insert(k.clone(), json_to_value(v)?);
+            }
+            Ok(Value::Edge {
+                from: json_str_field(fields, "from")?.to_string(),
+                to: json_str_field(fields, "to")?.to_string(),
+                directed: match json_field(fields, "directed")? {
+                    Json::Bool(b) => *b,
+                    _ => return Err("\"directed\" must be a boolean".to_string()),
+                },
+                properties: props,
+            })
+        }
+
+        "null" => Ok(Value::Null),
+
+        "builtinFunction" => Ok(Value::Function(achronyme_types::function::Function::Builtin(
+            json_str_field(fields, "name")?.to_string(),
+        ))),
+
+        other => Err(format!("unrecognized value type tag \"{}\"", other)),
+    }
+}
+
+fn format_f64(n: f64) -> String {
+    if n.is_nan() {
+        "\"NaN\"".to_string()
+    } else if n.is_infinite() {
+        if n > 0.0 { "\"Infinity\"".to_string() } else { "\"-Infinity\"".to_string() }
+    } else {
+        // Round-trip exact: Rust's default f64 Display is shortest-round-trip.
+        n.to_string()
+    }
+}
+
+fn escape_json(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn json_field<'a>(fields: &'a [(String, Json)], key: &str) -> Result<&'a Json, String> {
+    fields.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+        .ok_or_else(|| format!("missing required field \"{}\"", key))
+}
+
+fn json_str_field<'a>(fields: &'a [(String, Json)], key: &str) -> Result<&'a str, String> {
+    match json_field(fields, key)? {
+        Json::String(s) => Ok(s),
+        _ => Err(format!("field \"{}\" must be a string", key)),
+    }
+}
+
+fn json_f64_field(fields: &[(String, Json)], key: &str) -> Result<f64, String> {
+    match json_field(fields, key)? {
+        Json::Number(n) => Ok(*n),
+        Json::String(s) if s == "NaN" => Ok(f64::NAN),
+        Json::String(s) if s == "Infinity" => Ok(f64::INFINITY),
+        Json::String(s) if s == "-Infinity" => Ok(f64::NEG_INFINITY),
+        _ => Err(format!("field \"{}\" must be a number", key)),
+    }
+}
+
+fn json_usize_array_field(fields: &[(String, Json)], key: &str) -> Result<Vec<usize>, String> {
+    match json_field(fields, key)? {
+        Json::Array(items) => items.iter()
+            .map(|item| match item {
+                Json::Number(n) if *n >= 0.0 && n.fract() == 0.0 => Ok(*n as usize),
+                _ => Err(format!("field \"{}\" must contain non-negative integers", key)),
+            })
+            .collect(),
+        _ => Err(format!("field \"{}\" must be an array", key)),
+    }
+}
+
+fn json_f64_array_field(fields: &[(String, Json)], key: &str) -> Result<Vec<f64>, String> {
+    match json_field(fields, key)? {
+        Json::Array(items) => items.iter()
+            .map(|item| match item {
+                Json::Number(n) => Ok(*n),
+                // Non-finite entries are written by `format_f64` as strings.
+                Json::String(s) if s == "NaN" => Ok(f64::NAN),
+                Json::String(s) if s == "Infinity" => Ok(f64::INFINITY),
+                Json::String(s) if s == "-Infinity" => Ok(f64::NEG_INFINITY),
+                _ => Err(format!("field \"{}\" must contain numbers", key)),
+            })
+            .collect(),
+        _ => Err(format!("field \"{}\" must be an array", key)),
+    }
+}
+
+// ============================================================================
+// Minimal JSON Parser
+// ============================================================================
+//
+// achronyme-wasm has no JSON dependency, so this is a small self-contained
+// parser covering the subset of JSON produced by `value_to_json_string`
+// (and anything a JS caller would reasonably hand back).
+
+enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+struct JsonParser<'a> {
+    chars: Vec<char>,
+    pos: usize,
+    _source: &'a str,
+}
+
+impl<'a> JsonParser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self { chars: source.chars().collect(), pos: 0, _source: source }
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.pos >= self.chars.len()
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let c = self.peek();
+        if c.is_some() {
+            self.pos += 1;
+        }
+        c
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(c) if c.is_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        match self.advance() {
+            Some(found) if found == c => Ok(()),
+            Some(found) => Err(format!("expected '{}' but found '{}'", c, found)),
+            None => Err(format!("expected '{}' but reached end of input", c)),
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Json, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.parse_object(),
+            Some('[') => self.parse_array(),
+            Some('"') => self.parse_string().map(Json::String),
+            Some('t') | Some('f') => self.parse_bool(),
+            Some('n') => self.parse_null(),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.parse_number(),
+            Some(c) => Err(format!("unexpected character '{}'", c)),
+            None => Err("unexpected end of input".to_string()),
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<Json, String> {
+        self.expect('{')?;
+        let mut fields = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.advance();
+            return Ok(Json::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            let value = self.parse_value()?;
+            fields.push((key, value));
+            self.skip_whitespace();
+            match self.advance() {
+                Some(',') => continue,
+                Some('}') => break,
+                Some(c) => return Err(format!("expected ',' or '}}' but found '{}'", c)),
+                None => return Err("unexpected end of input in object".to_string()),
+            }
+        }
+        Ok(Json::Object(fields))
+    }
+
+    fn parse_array(&mut self) -> Result<Json, String> {
+        self.expect('[')?;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.advance();
+            return Ok(Json::Array(items));
+        }
+        loop {
+            items.push(self.parse_value()?);
+            self.skip_whitespace();
+            match self.advance() {
+                Some(',') => continue,
+                Some(']') => break,
+                Some(c) => return Err(format!("expected ',' or ']' but found '{}'", c)),
+                None => return Err("unexpected end of input in array".to_string()),
+            }
+        }
+        Ok(Json::Array(items))
+    }
+
+    fn parse_string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+        let mut out = String::new();
+        loop {
+            match self.advance() {
+                Some('"') => break,
+                Some('\\') => match self.advance() {
+                    Some('"') => out.push('"'),
+                    Some('\\') => out.push('\\'),
+                    Some('/') => out.push('/'),
+                    Some('n') => out.push('\n'),
+                    Some('r') => out.push('\r'),
+                    Some('t') => out.push('\t'),
+                    Some('b') => out.push('\u{8}'),
+                    Some('f') => out.push('\u{c}'),
+                    Some('u') => {
+                        let code = self.parse_hex4()?;
+                        out.push(char::from_u32(code).unwrap_or('\u{FFFD}'));
+                    }
+                    Some(c) => return Err(format!("invalid escape sequence '\\{}'", c)),
+                    None => return Err("unexpected end of input in string escape".to_string()),
+                },
+                Some(c) => out.push(c),
+                None => return Err("unterminated string literal".to_string()),
+            }
+        }
+        Ok(out)
+    }
+
+    fn parse_hex4(&mut self) -> Result<u32, String> {
+        let mut code = 0u32;
+        for _ in 0..4 {
+            let c = self.advance().ok_or("unexpected end of input in unicode escape")?;
+            code = code * 16 + c.to_digit(16).ok_or("invalid unicode escape digit")?;
+        }
+        Ok(code)
+    }
+
+    fn parse_bool(&mut self) -> Result<Json, String> {
+        if self.chars[self.pos..].starts_with(&['t', 'r', 'u', 'e']) {
+            self.pos += 4;
+            Ok(Json::Bool(true))
+        } else if self.chars[self.pos..].starts_with(&['f', 'a', 'l', 's', 'e']) {
+            self.pos += 5;
+            Ok(Json::Bool(false))
+        } else {
+            Err("invalid literal".to_string())
+        }
+    }
+
+    fn parse_null(&mut self) -> Result<Json, String> {
+        if self.chars[self.pos..].starts_with(&['n', 'u', 'l', 'l']) {
+            self.pos += 4;
+            Ok(Json::Null)
+        } else {
+            Err("invalid literal".to_string())
+        }
+    }
+
+    fn parse_number(&mut self) -> Result<Json, String> {
+        let start = self.pos;
+        if self.peek() == Some('-') {
+            self.advance();
+        }
+        while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+            self.advance();
+        }
+        if self.peek() == Some('.') {
+            self.advance();
+            while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+                self.advance();
+            }
+        }
+        if matches!(self.peek(), Some('e') | Some('E')) {
+            self.advance();
+            if matches!(self.peek(), Some('+') | Some('-')) {
+                self.advance();
+            }
+            while matches!(self.peek(), Some(c) if c.is_ascii_digit()) {
+                self.advance();
+            }
+        }
+        let text: String = self.chars[start..self.pos].iter().collect();
+        text.parse::<f64>().map(Json::Number).map_err(|_| format!("invalid number literal '{}'", text))
+    }
+}
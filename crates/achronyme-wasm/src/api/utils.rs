@@ -1,4 +1,6 @@
 use crate::state::{Handle, HANDLES};
+use achronyme_types::complex::Complex;
+use achronyme_types::tensor::{ComplexTensor, RealTensor};
 use achronyme_types::value::Value;
 use wasm_bindgen::prelude::*;
 
@@ -105,7 +107,10 @@ pub fn format_value(value: &Value) -> String {
                 format!("{} {} {}: {{ {} }}", from, arrow, to, props.join(", "))
             }
         }
-        Value::Function(_) => "x => <function>".to_string(),
+        Value::Function(func) => {
+            let normalized = crate::api::normalize::normalize_function(func);
+            crate::api::normalize::format_function(&normalized)
+        }
 
         Value::MutableRef(rc) => {
             let inner = rc.borrow();
@@ -113,7 +118,13 @@ pub fn format_value(value: &Value) -> String {
         }
 
         Value::TailCall(_) => {
-            // TailCall should never be visible to user code - it's an internal marker
+            // Unlike a `rec(...)` redex inside a function body - which
+            // `normalize`/`format_function` resolve by trampolining statically
+            // over the AST - a bare `Value::TailCall` reaching here has
+            // already escaped the evaluator's TCO loop and carries only its
+            // replacement arguments, not the function to apply them to. There
+            // is nothing to trampoline against, so it remains what it always
+            // was: an internal marker that should never be visible to user code.
             "<internal:TailCall>".to_string()
         }
     }
@@ -122,7 +133,7 @@ pub fn format_value(value: &Value) -> String {
 /// Generic helper for safe unary operations on handles.
 pub fn apply_unary_op<F>(handle: Handle, op_name: &str, f: F) -> Result<Handle, JsValue>
 where
-    F: Fn(f64) -> f64,
+    F: Fn(f64) -> f64 + Sync + Send,
 {
     // Step 1: Read and copy the data out. This is an immutable borrow.
     let data_copy = HANDLES.with(|h| {
@@ -143,9 +154,12 @@ where
         }
     })?;
 
-    // Step 2: Perform calculation. No borrows are held.
-    let result_data: Vec<Value> = data_copy.iter()
-        .map(|&x| Value::Number(f(x)))
+    // Step 2: Perform calculation. No borrows are held. On large vectors this
+    // fans out across achronyme_types::parallel's thread pool once the
+    // element count crosses its tunable threshold (see setParallelThreshold).
+    let result_data: Vec<Value> = achronyme_types::parallel::map_elements(&data_copy, f)
+        .into_iter()
+        .map(Value::Number)
         .collect();
 
     // Step 3: Create a new handle. This is a mutable borrow.
@@ -154,6 +168,17 @@ where
     }))
 }
 
+/// Tune the element-count threshold at or above which large tensor/vector
+/// operations (`apply_unary_op`, `broadcast_binary_op` and
+/// `broadcast_binary_op_complex`) switch from a serial loop to a parallel
+/// one. The broadcast ops run in parallel only when both operands already
+/// have the same shape; an actual broadcast stays serial. Has no
+/// observable effect on results - only on how long they take to compute.
+#[wasm_bindgen(js_name = "setParallelThreshold")]
+pub fn set_parallel_threshold(n: usize) {
+    achronyme_types::parallel::set_parallel_threshold(n);
+}
+
 /// Generic helper for safe binary operations on handles.
 pub fn apply_binary_op<F>(handle1: Handle, handle2: Handle, f: F) -> Result<Handle, JsValue>
 where
@@ -174,3 +199,82 @@ where
     // Step 2: Create new handle with the result
     Ok(HANDLES.with(|h| h.borrow_mut().create(result)))
 }
+
+/// Coerce a handle's value into a `RealTensor`, treating a bare `Number`
+/// as a 0-d scalar tensor so it broadcasts against any shape.
+fn value_to_real_tensor(value: &Value) -> Result<RealTensor, JsValue> {
+    match value {
+        Value::Number(n) => Ok(RealTensor::scalar(*n)),
+        Value::Tensor(t) => Ok(t.clone()),
+        Value::Vector(v) => {
+            let data: Result<Vec<f64>, JsValue> = v.iter()
+                .map(|val| match val {
+                    Value::Number(n) => Ok(*n),
+                    _ => Err(JsValue::from_str("broadcast_binary_op requires a numeric vector or tensor")),
+                })
+                .collect();
+            Ok(RealTensor::vector(data?))
+        }
+        _ => Err(JsValue::from_str("broadcast_binary_op requires a number, vector, or tensor handle")),
+    }
+}
+
+/// Coerce a handle's value into a `ComplexTensor`, treating a bare
+/// `Number`/`Complex` as a 0-d scalar tensor so it broadcasts against any shape.
+fn value_to_complex_tensor(value: &Value) -> Result<ComplexTensor, JsValue> {
+    match value {
+        Value::Number(n) => Ok(ComplexTensor::scalar(Complex::from_real(*n))),
+        Value::Complex(c) => Ok(ComplexTensor::scalar(*c)),
+        Value::Tensor(t) => Ok(t.to_complex()),
+        Value::ComplexTensor(ct) => Ok(ct.clone()),
+        Value::Vector(v) => {
+            let data: Result<Vec<Complex>, JsValue> = v.iter()
+                .map(|val| match val {
+                    Value::Number(n) => Ok(Complex::from_real(*n)),
+                    Value::Complex(c) => Ok(*c),
+                    _ => Err(JsValue::from_str("broadcast_binary_op requires a numeric vector or tensor")),
+                })
+                .collect();
+            Ok(ComplexTensor::vector(data?))
+        }
+        _ => Err(JsValue::from_str("broadcast_binary_op requires a number, complex number, vector, or tensor handle")),
+    }
+}
+
+/// NumPy-style broadcasting binary op over two handles: aligns the two
+/// shapes by their trailing dimensions (a missing leading dimension on the
+/// shorter shape counts as size 1), requires each aligned pair of
+/// dimensions to be equal or one of them to be 1, and repeats the operand
+/// with size 1 along that axis. Scalars (`Value::Number`) broadcast
+/// against any tensor. Returns a new `Tensor` handle.
+pub fn broadcast_binary_op<F>(handle1: Handle, handle2: Handle, f: F) -> Result<Handle, JsValue>
+where
+    F: Fn(f64, f64) -> f64 + Sync + Send,
+{
+    let (t1, t2) = HANDLES.with(|h| {
+        let handles = h.borrow();
+        let val1 = handles.get(handle1).ok_or_else(|| JsValue::from_str("Handle 1 is invalid"))?;
+        let val2 = handles.get(handle2).ok_or_else(|| JsValue::from_str("Handle 2 is invalid"))?;
+        Ok::<_, JsValue>((value_to_real_tensor(val1)?, value_to_real_tensor(val2)?))
+    })?;
+
+    let result = t1.zip_with(&t2, f).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(HANDLES.with(|h| h.borrow_mut().create(Value::Tensor(result))))
+}
+
+/// Complex-valued counterpart of [`broadcast_binary_op`]. Returns a new
+/// `ComplexTensor` handle.
+pub fn broadcast_binary_op_complex<F>(handle1: Handle, handle2: Handle, f: F) -> Result<Handle, JsValue>
+where
+    F: Fn(Complex, Complex) -> Complex + Sync + Send,
+{
+    let (t1, t2) = HANDLES.with(|h| {
+        let handles = h.borrow();
+        let val1 = handles.get(handle1).ok_or_else(|| JsValue::from_str("Handle 1 is invalid"))?;
+        let val2 = handles.get(handle2).ok_or_else(|| JsValue::from_str("Handle 2 is invalid"))?;
+        Ok::<_, JsValue>((value_to_complex_tensor(val1)?, value_to_complex_tensor(val2)?))
+    })?;
+
+    let result = t1.zip_with(&t2, f).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    Ok(HANDLES.with(|h| h.borrow_mut().create(Value::ComplexTensor(result))))
+}
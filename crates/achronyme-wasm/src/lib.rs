@@ -13,6 +13,9 @@ mod state;
 // Core API
 pub use api::core::{eval, reset, eval_to_handle};
 
+// Parallel Evaluation API
+pub use api::utils::set_parallel_threshold;
+
 // Memory API
 pub use api::memory::{
     create_vector_from_buffer,
@@ -27,6 +30,17 @@ pub use api::memory::{
     release_handle,
 };
 
+// Serialization API
+pub use api::serialize::{
+    value_to_json,
+    value_from_json,
+};
+
+// Normalization API
+pub use api::normalize::{
+    normalize,
+};
+
 // Math Operations
 pub use api::math::{
     math_sin,
@@ -57,6 +71,10 @@ pub use api::math::{
     vmul,
     vdiv,
     dot,
+    tensor_add,
+    tensor_sub,
+    tensor_mul,
+    tensor_div,
 };
 
 // Statistics Operations